@@ -0,0 +1,37 @@
+CREATE TABLE countries (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE blocs (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE bloc_members (
+    bloc_id TEXT NOT NULL REFERENCES blocs (id),
+    country_id TEXT NOT NULL REFERENCES countries (id),
+    PRIMARY KEY (bloc_id, country_id)
+);
+
+-- Requirements of a country or a bloc, JSON encoded `CountryRequirements`
+CREATE TABLE requirements (
+    subject_id TEXT PRIMARY KEY,
+    body TEXT NOT NULL
+);
+
+-- JSON encoded `Airline`
+CREATE TABLE airlines (
+    iata TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    body TEXT NOT NULL
+);
+
+CREATE TABLE sources (
+    id INTEGER PRIMARY KEY,
+    subject_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL
+);
+
+CREATE INDEX sources_subject ON sources (subject_id);
@@ -0,0 +1,12 @@
+-- Dated versions of the records in `requirements`, oldest first per subject.
+-- `body` is the JSON encoded `CountryRequirements` of the time, NULL for the
+-- latest version, which is the record in `requirements`.
+CREATE TABLE requirement_versions (
+    id INTEGER PRIMARY KEY,
+    subject_id TEXT NOT NULL,
+    effective TEXT NOT NULL,
+    summary TEXT NOT NULL,
+    body TEXT
+);
+
+CREATE INDEX requirement_versions_subject ON requirement_versions (subject_id, effective);
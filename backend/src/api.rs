@@ -0,0 +1,171 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{header, Method};
+use axum::middleware;
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{NaiveDate, Utc};
+use petabroad_core::api::{Carrier, Country, HistoryResponse, RequirementsResponse, TripResponse};
+use petabroad_core::data::airlines::{AirlineDatabase, RouteQuery};
+use petabroad_core::data::breeds::get_breed_registry;
+use petabroad_core::data::freshness::{FreshnessReport, DEFAULT_STALE_AFTER_DAYS};
+use petabroad_core::pet::profile::{PetProfile, Species};
+use serde::Deserialize;
+use std::sync::Arc;
+use tower_http::cors::{Any, CorsLayer};
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::etag::etag;
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[derive(Debug, Deserialize)]
+pub struct TripQuery {
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub species: Species,
+    #[serde(default)]
+    pub breed: String,
+    pub weight_kg: Option<f32>,
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequirementsQuery {
+    /// Requirements in effect on that day rather than the current ones
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FreshnessQuery {
+    pub stale_after_days: Option<u32>,
+}
+
+fn find_country(db: &Database, id: &str) -> Result<Country, ApiError> {
+    db.country(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Country {}", id)))
+}
+
+fn requirements_of(db: &Database, country: Country) -> Result<RequirementsResponse, ApiError> {
+    Ok(RequirementsResponse {
+        bloc: db.bloc_of(&country.id)?,
+        requirements: db.requirements_for(&country.id)?,
+        sources: db.sources_for(&country.id)?,
+        country,
+    })
+}
+
+async fn countries(State(db): State<Arc<Database>>) -> ApiResult<Vec<Country>> {
+    Ok(Json(db.countries()?))
+}
+
+async fn country_requirements(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    Query(query): Query<RequirementsQuery>,
+) -> ApiResult<RequirementsResponse> {
+    let country = find_country(&db, &id)?;
+    let mut response = requirements_of(&db, country)?;
+    if let Some(date) = query.date {
+        response.requirements = db.requirements_on(&id, date)?;
+    }
+    Ok(Json(response))
+}
+
+/// Dated changes of the requirements, the latest first
+async fn country_history(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+) -> ApiResult<HistoryResponse> {
+    let country = find_country(&db, &id)?;
+    Ok(Json(HistoryResponse {
+        revisions: db.revisions_for(&country.id)?,
+        country,
+    }))
+}
+
+async fn trip(
+    State(db): State<Arc<Database>>,
+    Query(query): Query<TripQuery>,
+) -> ApiResult<TripResponse> {
+    if query.from == query.to {
+        return Err(ApiError::BadRequest(
+            "Origin and destination are the same country".to_string(),
+        ));
+    }
+    let from = find_country(&db, &query.from)?;
+    let to = find_country(&db, &query.to)?;
+    let pet = PetProfile {
+        species: query.species,
+        breed: query.breed,
+        weight_kg: query.weight_kg,
+        ..PetProfile::default()
+    };
+    let route = RouteQuery {
+        from: &from.id,
+        to: &to.id,
+        date: query.date,
+    };
+    let airlines = AirlineDatabase::new(db.airlines()?);
+    let carriers = airlines
+        .carriers_for(&route, &pet)
+        .into_iter()
+        .map(|option| Carrier {
+            iata: option.airline.iata.clone(),
+            name: option.airline.name.clone(),
+            modes: option.modes,
+        })
+        .collect();
+    let breed_restrictions = get_breed_registry()
+        .restrictions_for(&to.id, &pet)
+        .into_iter()
+        .cloned()
+        .collect();
+    Ok(Json(TripResponse {
+        from,
+        to: requirements_of(&db, to)?,
+        breed_restrictions,
+        carriers,
+    }))
+}
+
+/// Verification age of every requirements record, most doubtful first
+async fn freshness(
+    State(db): State<Arc<Database>>,
+    Query(query): Query<FreshnessQuery>,
+) -> ApiResult<FreshnessReport> {
+    let records = db.requirement_records()?;
+    let report = FreshnessReport::new(
+        records
+            .iter()
+            .map(|(id, record, sources)| (id.as_str(), record, *sources)),
+        Utc::now().date_naive(),
+        query.stale_after_days.unwrap_or(DEFAULT_STALE_AFTER_DAYS),
+    );
+    Ok(Json(report))
+}
+
+/// Routes of the first API version, everything is mounted under `/api/v1`
+pub fn router(db: Arc<Database>) -> Router {
+    let v1 = Router::new()
+        .route("/countries", get(countries))
+        .route("/countries/:id/requirements", get(country_requirements))
+        .route("/countries/:id/history", get(country_history))
+        .route("/trip", get(trip))
+        .route("/freshness", get(freshness));
+    Router::new()
+        .nest("/api/v1", v1)
+        .with_state(db)
+        .layer(middleware::from_fn(etag))
+        .layer(cors())
+}
+
+/// The frontend is served from another origin, it revalidates with `If-None-Match`
+fn cors() -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([Method::GET])
+        .allow_headers([header::IF_NONE_MATCH])
+        .expose_headers([header::ETAG])
+}
@@ -0,0 +1,410 @@
+use chrono::NaiveDate;
+use petabroad_core::api::Country;
+use petabroad_core::data::airlines::{get_airline_database, Airline};
+use petabroad_core::data::history::{get_history, History, Revision, Version};
+use petabroad_core::data::requirements::{get_requirements_database, CountryRequirements, Source};
+use petabroad_core::map_data::get_countries_names;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::fmt;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+/// Applied in order, `PRAGMA user_version` holds the number of applied migrations
+const MIGRATIONS: [&str; 2] = [
+    include_str!("../migrations/0001_initial.sql"),
+    include_str!("../migrations/0002_requirement_versions.sql"),
+];
+
+/// Index of the migration adding `requirement_versions`
+const VERSIONS_MIGRATION: usize = 1;
+
+#[derive(Debug)]
+pub enum Error {
+    Sqlite(rusqlite::Error),
+    /// A stored JSON document does not match the core types
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Sqlite(e) => write!(f, "database error: {}", e),
+            Error::Json(e) => write!(f, "malformed stored document: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Error {
+        Error::Sqlite(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+struct CountryRecords {
+    own: Option<CountryRequirements>,
+    bloc_id: Option<String>,
+    bloc: Option<CountryRequirements>,
+    history: History,
+}
+
+/// SQLite store of countries, blocs, requirements, airlines and their sources.
+/// Queries are short, so a single connection behind a mutex is enough.
+pub struct Database {
+    conn: Mutex<Connection>,
+}
+
+impl Database {
+    pub fn open(path: impl AsRef<Path>) -> Result<Database> {
+        Database::from_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Database> {
+        Database::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(mut conn: Connection) -> Result<Database> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        migrate(&mut conn)?;
+        Ok(Database {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn schema_version(&self) -> Result<usize> {
+        let version: i64 = self
+            .conn()
+            .pragma_query_value(None, "user_version", |row| row.get(0))?;
+        Ok(version as usize)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        let count: i64 = self
+            .conn()
+            .query_row("SELECT COUNT(*) FROM countries", [], |row| row.get(0))?;
+        Ok(count == 0)
+    }
+
+    /// Fills the store with the datasets bundled into `petabroad-core`
+    pub fn seed_from_core(&self) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        for (id, name) in get_countries_names() {
+            tx.execute(
+                "INSERT INTO countries (id, name) VALUES (?1, ?2)",
+                params![id, name],
+            )?;
+        }
+        let requirements = get_requirements_database();
+        for (id, bloc) in requirements.blocs() {
+            tx.execute(
+                "INSERT INTO blocs (id, name) VALUES (?1, ?2)",
+                params![id, bloc.name],
+            )?;
+            for member in &bloc.members {
+                tx.execute(
+                    "INSERT INTO bloc_members (bloc_id, country_id) VALUES (?1, ?2)",
+                    params![id, member],
+                )?;
+            }
+            insert_requirements(&tx, id, &bloc.requirements)?;
+        }
+        for (id, country) in requirements.countries() {
+            insert_requirements(&tx, id, country)?;
+        }
+        let subjects: Vec<&str> = requirements
+            .blocs()
+            .map(|(id, _)| id)
+            .chain(requirements.countries().map(|(id, _)| id))
+            .collect();
+        for id in subjects {
+            for source in requirements.sources(id) {
+                tx.execute(
+                    "INSERT INTO sources (subject_id, title, url) VALUES (?1, ?2, ?3)",
+                    params![id, source.title, source.url],
+                )?;
+            }
+        }
+        insert_history(&tx)?;
+        for airline in get_airline_database().airlines() {
+            tx.execute(
+                "INSERT INTO airlines (iata, name, body) VALUES (?1, ?2, ?3)",
+                params![airline.iata, airline.name, serde_json::to_string(airline)?],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn countries(&self) -> Result<Vec<Country>> {
+        let conn = self.conn();
+        let mut statement = conn.prepare("SELECT id, name FROM countries ORDER BY name")?;
+        let countries = statement
+            .query_map([], |row| {
+                Ok(Country {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(countries)
+    }
+
+    pub fn country(&self, id: &str) -> Result<Option<Country>> {
+        let country = self
+            .conn()
+            .query_row(
+                "SELECT id, name FROM countries WHERE id = ?1",
+                [id],
+                |row| {
+                    Ok(Country {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(country)
+    }
+
+    pub fn bloc_of(&self, country_id: &str) -> Result<Option<Country>> {
+        let bloc = self
+            .conn()
+            .query_row(
+                "SELECT blocs.id, blocs.name FROM blocs \
+                 JOIN bloc_members ON bloc_members.bloc_id = blocs.id \
+                 WHERE bloc_members.country_id = ?1",
+                [country_id],
+                |row| {
+                    Ok(Country {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(bloc)
+    }
+
+    fn own_requirements(&self, subject_id: &str) -> Result<Option<CountryRequirements>> {
+        let body: Option<String> = self
+            .conn()
+            .query_row(
+                "SELECT body FROM requirements WHERE subject_id = ?1",
+                [subject_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(body.map(|b| serde_json::from_str(&b)).transpose()?)
+    }
+
+    /// Effective requirements, same semantics as `RequirementsDatabase::for_country`
+    pub fn requirements_for(&self, country_id: &str) -> Result<Option<CountryRequirements>> {
+        let own = self.own_requirements(country_id)?;
+        let bloc = match self.bloc_of(country_id)? {
+            Some(bloc) => self.own_requirements(&bloc.id)?,
+            None => None,
+        };
+        Ok(match (own, bloc) {
+            (Some(own), Some(bloc)) => Some(own.or(&bloc)),
+            (Some(own), None) => Some(own),
+            (None, Some(bloc)) => Some(bloc),
+            (None, None) => None,
+        })
+    }
+
+    /// Stored versions of the given countries and blocs
+    fn history(&self, subject_ids: &[&str]) -> Result<History> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(
+            "SELECT effective, summary, body FROM requirement_versions \
+             WHERE subject_id = ?1 ORDER BY effective",
+        )?;
+        let mut records = vec![];
+        for id in subject_ids {
+            let rows = statement
+                .query_map([id], |row| {
+                    Ok((
+                        row.get::<_, NaiveDate>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                    ))
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            let versions = rows
+                .into_iter()
+                .map(|(effective, summary, body)| {
+                    Ok(Version {
+                        effective,
+                        summary,
+                        requirements: body.map(|b| serde_json::from_str(&b)).transpose()?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            records.push((id.to_string(), versions));
+        }
+        Ok(records.into_iter().collect())
+    }
+
+    /// Current records of the country and of its bloc with their history
+    fn records_of(&self, country_id: &str) -> Result<CountryRecords> {
+        let bloc_id = self.bloc_of(country_id)?.map(|bloc| bloc.id);
+        let bloc = match &bloc_id {
+            Some(id) => self.own_requirements(id)?,
+            None => None,
+        };
+        let subjects: Vec<&str> = std::iter::once(country_id)
+            .chain(bloc_id.as_deref())
+            .collect();
+        Ok(CountryRecords {
+            own: self.own_requirements(country_id)?,
+            history: self.history(&subjects)?,
+            bloc_id,
+            bloc,
+        })
+    }
+
+    /// Effective requirements on a past `date`, the current ones for records
+    /// without a history
+    pub fn requirements_on(
+        &self,
+        country_id: &str,
+        date: NaiveDate,
+    ) -> Result<Option<CountryRequirements>> {
+        let records = self.records_of(country_id)?;
+        Ok(records.history.requirements_on(
+            country_id,
+            records.bloc_id.as_deref(),
+            date,
+            records.own.as_ref(),
+            records.bloc.as_ref(),
+        ))
+    }
+
+    /// Changes of the country's requirements, the latest first
+    pub fn revisions_for(&self, country_id: &str) -> Result<Vec<Revision>> {
+        let records = self.records_of(country_id)?;
+        Ok(records.history.timeline(
+            country_id,
+            records.bloc_id.as_deref(),
+            records.own.as_ref(),
+            records.bloc.as_ref(),
+        ))
+    }
+
+    /// Citations of the country followed by those of its bloc
+    pub fn sources_for(&self, country_id: &str) -> Result<Vec<Source>> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(
+            "SELECT title, url FROM sources WHERE subject_id = ?1 \
+             OR subject_id IN (SELECT bloc_id FROM bloc_members WHERE country_id = ?1) \
+             ORDER BY subject_id != ?1, id",
+        )?;
+        let sources = statement
+            .query_map([country_id], |row| {
+                Ok(Source {
+                    title: row.get(0)?,
+                    url: row.get(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(sources)
+    }
+
+    /// Every stored requirements record, countries and blocs alike, with the
+    /// number of its sources
+    pub fn requirement_records(&self) -> Result<Vec<(String, CountryRequirements, usize)>> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(
+            "SELECT subject_id, body, \
+             (SELECT COUNT(*) FROM sources WHERE sources.subject_id = requirements.subject_id) \
+             FROM requirements ORDER BY subject_id",
+        )?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        rows.into_iter()
+            .map(|(id, body, sources)| Ok((id, serde_json::from_str(&body)?, sources as usize)))
+            .collect()
+    }
+
+    pub fn airlines(&self) -> Result<Vec<Airline>> {
+        let conn = self.conn();
+        let mut statement = conn.prepare("SELECT body FROM airlines ORDER BY iata")?;
+        let bodies = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(bodies
+            .iter()
+            .map(|b| serde_json::from_str(b))
+            .collect::<serde_json::Result<_>>()?)
+    }
+}
+
+fn migrate(conn: &mut Connection) -> Result<()> {
+    let applied: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(applied as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration)?;
+        if i == VERSIONS_MIGRATION {
+            // stores seeded before the history existed get it with its table
+            let seeded: i64 =
+                tx.query_row("SELECT COUNT(*) FROM countries", [], |row| row.get(0))?;
+            if seeded > 0 {
+                insert_history(&tx)?;
+            }
+        }
+        tx.pragma_update(None, "user_version", (i + 1) as i64)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+fn insert_requirements(
+    conn: &Connection,
+    subject_id: &str,
+    requirements: &CountryRequirements,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO requirements (subject_id, body) VALUES (?1, ?2)",
+        params![subject_id, serde_json::to_string(requirements)?],
+    )?;
+    Ok(())
+}
+
+fn insert_history(conn: &Connection) -> Result<()> {
+    for (id, versions) in get_history().records() {
+        for version in versions {
+            let body = version
+                .requirements
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?;
+            conn.execute(
+                "INSERT INTO requirement_versions (subject_id, effective, summary, body) \
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![id, version.effective, version.summary, body],
+            )?;
+        }
+    }
+    Ok(())
+}
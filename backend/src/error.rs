@@ -0,0 +1,48 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use std::fmt;
+
+use crate::db;
+
+#[derive(Debug)]
+pub enum ApiError {
+    NotFound(String),
+    BadRequest(String),
+    Database(db::Error),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiError::NotFound(what) => write!(f, "{} not found", what),
+            ApiError::BadRequest(reason) => write!(f, "{}", reason),
+            ApiError::Database(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<db::Error> for ApiError {
+    fn from(e: db::Error) -> ApiError {
+        ApiError::Database(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Database(ref e) => {
+                eprintln!("{}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+        let message = match self {
+            ApiError::Database(_) => "internal error".to_string(),
+            _ => self.to_string(),
+        };
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
@@ -0,0 +1,49 @@
+use axum::body::{to_bytes, Body};
+use axum::extract::Request;
+use axum::http::{header, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+/// Responses are small JSON documents, buffering them to hash is cheap
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// FNV-1a, stable across builds unlike `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn matches(if_none_match: &HeaderValue, etag: &str) -> bool {
+    match if_none_match.to_str() {
+        Ok(value) => value
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == etag || tag == "*"),
+        Err(_) => false,
+    }
+}
+
+/// Adds a content based `ETag` to successful GET responses and answers
+/// `304 Not Modified` when it matches the request's `If-None-Match`
+pub async fn etag(request: Request, next: Next) -> Response {
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let is_get = request.method() == Method::GET;
+    let response = next.run(request).await;
+    if !is_get || response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    let etag = format!("\"{:016x}\"", fnv1a(&bytes));
+    let value = HeaderValue::from_str(&etag).expect("hex digits are a valid header value");
+    if if_none_match.is_some_and(|v| matches(&v, &etag)) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, value)]).into_response();
+    }
+    parts.headers.insert(header::ETAG, value);
+    Response::from_parts(parts, Body::from(bytes))
+}
@@ -0,0 +1,6 @@
+//! Local REST server publishing the petabroad datasets from a SQLite store
+
+pub mod api;
+pub mod db;
+pub mod error;
+pub mod etag;
@@ -0,0 +1,25 @@
+use petabroad_backend::api;
+use petabroad_backend::db::Database;
+use std::env;
+use std::error::Error;
+use std::sync::Arc;
+
+const DEFAULT_DB_PATH: &str = "petabroad.sqlite";
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let path = env::var("PETABROAD_DB").unwrap_or_else(|_| DEFAULT_DB_PATH.to_string());
+    let addr = env::var("PETABROAD_ADDR").unwrap_or_else(|_| DEFAULT_ADDR.to_string());
+
+    let db = Database::open(&path)?;
+    if db.is_empty()? {
+        println!("Seeding {} from the bundled datasets", path);
+        db.seed_from_core()?;
+    }
+
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("Serving on http://{}/api/v1", addr);
+    axum::serve(listener, api::router(Arc::new(db))).await?;
+    Ok(())
+}
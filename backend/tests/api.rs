@@ -0,0 +1,199 @@
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use petabroad_backend::api::router;
+use petabroad_backend::db::Database;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn app() -> Router {
+    let db = Database::open_in_memory().unwrap();
+    db.seed_from_core().unwrap();
+    router(Arc::new(db))
+}
+
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let response = app()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn lists_countries() {
+    let (status, body) = get("/api/v1/countries").await;
+    assert_eq!(status, StatusCode::OK);
+    let countries = body.as_array().unwrap();
+    assert!(countries
+        .iter()
+        .any(|c| c["id"] == "JP" && c["name"] == "Japan"));
+}
+
+#[tokio::test]
+async fn country_requirements_include_bloc_rules_and_sources() {
+    let (status, body) = get("/api/v1/countries/FR/requirements").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["country"]["name"], "France");
+    assert_eq!(body["bloc"]["id"], "EU");
+    assert_eq!(body["requirements"]["titer"]["lab_approval"], "EU");
+    assert_eq!(body["requirements"]["microchip"]["iso_required"], false);
+    assert!(body["sources"][0]["url"]
+        .as_str()
+        .unwrap()
+        .starts_with("https://eur-lex.europa.eu/"));
+}
+
+#[tokio::test]
+async fn country_own_requirements() {
+    let (_, body) = get("/api/v1/countries/JP/requirements").await;
+    assert_eq!(body["bloc"], Value::Null);
+    assert_eq!(body["requirements"]["titer"]["wait_days"], 180);
+    assert_eq!(body["requirements"]["titer"]["validity_days"], 730);
+}
+
+#[tokio::test]
+async fn country_without_data_has_null_requirements() {
+    let (status, body) = get("/api/v1/countries/BR/requirements").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["requirements"], Value::Null);
+    assert_eq!(body["sources"], Value::Array(vec![]));
+}
+
+#[tokio::test]
+async fn requirements_on_a_past_date() {
+    let (status, body) = get("/api/v1/countries/GB/requirements?date=2005-06-01").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["requirements"]["titer"]["wait_days"], 180);
+    assert_eq!(
+        body["requirements"]["titer"]["exempt_origins"],
+        Value::Array(vec![])
+    );
+    let (_, body) = get("/api/v1/countries/GB/requirements?date=1999-01-01").await;
+    assert_eq!(body["requirements"], Value::Null);
+    let (_, body) = get("/api/v1/countries/FR/requirements?date=1999-01-01").await;
+    assert_eq!(body["requirements"]["titer"]["lab_approval"], "EU");
+}
+
+#[tokio::test]
+async fn country_history_lists_latest_change_first() {
+    let (status, body) = get("/api/v1/countries/GB/history").await;
+    assert_eq!(status, StatusCode::OK);
+    let revisions = body["revisions"].as_array().unwrap();
+    assert_eq!(revisions[0]["effective"], "2012-01-01");
+    assert!(revisions[0]["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|c| c["field"] == "titer.wait_days" && c["old"] == 180 && c["new"] == 90));
+    let (_, body) = get("/api/v1/countries/JP/history").await;
+    assert_eq!(body["revisions"], Value::Array(vec![]));
+    let (status, _) = get("/api/v1/countries/XX/history").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn freshness_report_covers_every_record() {
+    let (status, body) = get("/api/v1/freshness?stale_after_days=30").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["stale_after_days"], 30);
+    let entries = body["entries"].as_array().unwrap();
+    assert!(entries.iter().any(|e| e["id"] == "EU"));
+    let jp = entries.iter().find(|e| e["id"] == "JP").unwrap();
+    assert_eq!(jp["verified"]["verifier"], "petabroad maintainers");
+    assert!(jp["sources"].as_u64().unwrap() > 0);
+    let (_, body) = get("/api/v1/freshness").await;
+    assert_eq!(body["stale_after_days"], 180);
+}
+
+#[tokio::test]
+async fn unknown_country_is_not_found() {
+    let (status, body) = get("/api/v1/countries/XX/requirements").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "Country XX not found");
+}
+
+#[tokio::test]
+async fn trip_lists_carriers_and_destination_requirements() {
+    let (status, body) = get("/api/v1/trip?from=DE&to=JP&species=cat&weight_kg=4").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["from"]["id"], "DE");
+    assert_eq!(body["to"]["country"]["id"], "JP");
+    assert_eq!(body["to"]["requirements"]["titer"]["lab_approval"], "JP");
+    let carriers = body["carriers"].as_array().unwrap();
+    let lufthansa = carriers.iter().find(|c| c["iata"] == "LH").unwrap();
+    assert_eq!(lufthansa["modes"][0], "cabin");
+}
+
+#[tokio::test]
+async fn trip_to_cargo_only_destination() {
+    let (_, body) = get("/api/v1/trip?from=FR&to=GB&species=dog&weight_kg=30").await;
+    for carrier in body["carriers"].as_array().unwrap() {
+        assert_eq!(carrier["modes"], serde_json::json!(["cargo"]));
+    }
+}
+
+#[tokio::test]
+async fn trip_reports_breed_restrictions() {
+    let (_, body) = get("/api/v1/trip?from=FR&to=GB&species=dog&breed=Tosa").await;
+    let restrictions = body["breed_restrictions"].as_array().unwrap();
+    assert_eq!(restrictions[0]["breed"], "tosa");
+    assert_eq!(restrictions[0]["level"], "banned");
+}
+
+#[tokio::test]
+async fn trip_rejects_invalid_queries() {
+    let (status, _) = get("/api/v1/trip?from=DE&to=DE").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = get("/api/v1/trip?from=DE&to=XX").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let response = app()
+        .oneshot(
+            Request::get("/api/v1/trip?from=DE")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn answers_not_modified_for_matching_etag() {
+    let app = app();
+    let uri = "/api/v1/countries/JP/requirements";
+    let response = app
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let etag = response.headers()[header::ETAG].clone();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::get(uri)
+                .header(header::IF_NONE_MATCH, etag.clone())
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag);
+
+    let response = app
+        .oneshot(
+            Request::get(uri)
+                .header(header::IF_NONE_MATCH, "\"outdated\"")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,193 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use petabroad_backend::api::router;
+use petabroad_backend::db::Database;
+use petabroad_core::api::RequirementsResponse;
+use petabroad_core::client::{
+    CachedResponse, DataClient, FetchError, HttpResponse, Origin, ResponseCache, Transport,
+};
+use petabroad_core::travel::trip::Trip;
+use petabroad_core::travel::watchlist::Watchlist;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// Native counterpart of the browser `fetch` transport, records response statuses
+#[derive(Default)]
+struct ReqwestTransport {
+    client: reqwest::Client,
+    statuses: Mutex<Vec<u16>>,
+}
+
+impl Transport for &ReqwestTransport {
+    async fn get(&self, url: &str, if_none_match: Option<&str>) -> Result<HttpResponse, String> {
+        let mut request = self.client.get(url);
+        if let Some(etag) = if_none_match {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        let status = response.status().as_u16();
+        self.statuses.lock().unwrap().push(status);
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        Ok(HttpResponse { status, etag, body })
+    }
+}
+
+#[derive(Default)]
+struct MemoryCache(Mutex<HashMap<String, CachedResponse>>);
+
+impl ResponseCache for &MemoryCache {
+    async fn load(&self, url: &str) -> Option<CachedResponse> {
+        self.0.lock().unwrap().get(url).cloned()
+    }
+
+    async fn store(&self, url: &str, response: &CachedResponse) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(url.to_string(), response.clone());
+    }
+}
+
+async fn serve(app: Router) -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (url, server)
+}
+
+async fn serve_backend() -> (String, JoinHandle<()>) {
+    let db = Database::open_in_memory().unwrap();
+    db.seed_from_core().unwrap();
+    serve(router(Arc::new(db))).await
+}
+
+#[tokio::test]
+async fn revalidates_with_etag() {
+    let (url, server) = serve_backend().await;
+    let transport = ReqwestTransport::default();
+    let cache = MemoryCache::default();
+    let client = DataClient::new(&url, &transport, &cache);
+
+    let first = client.requirements("JP").await.unwrap();
+    assert_eq!(first.origin, Origin::Server);
+    assert_eq!(first.data.country.name, "Japan");
+    let second = client.requirements("JP").await.unwrap();
+    assert_eq!(second.origin, Origin::Server);
+    assert_eq!(second.data, first.data);
+    assert_eq!(*transport.statuses.lock().unwrap(), vec![200, 304]);
+    server.abort();
+}
+
+#[tokio::test]
+async fn serves_cache_when_server_goes_away() {
+    let (url, server) = serve_backend().await;
+    let transport = ReqwestTransport::default();
+    let cache = MemoryCache::default();
+    let client = DataClient::new(&url, &transport, &cache);
+    let online = client.requirements("FR").await.unwrap();
+
+    server.abort();
+    let _ = server.await;
+    // a new connection pool, the old one may still hold a live connection
+    let transport = ReqwestTransport::default();
+    let client = DataClient::new(&url, &transport, &cache);
+    let offline = client.requirements("FR").await.unwrap();
+    assert_eq!(offline.origin, Origin::Cache);
+    assert_eq!(offline.fetched_at, online.fetched_at);
+    assert_eq!(offline.data, online.data);
+}
+
+#[tokio::test]
+async fn serves_snapshot_on_server_errors() {
+    let app = Router::new().route(
+        "/api/v1/countries/:id/requirements",
+        get(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+    );
+    let (url, server) = serve(app).await;
+    let transport = ReqwestTransport::default();
+    let cache = MemoryCache::default();
+    let client = DataClient::new(&url, &transport, &cache);
+
+    let fetched = client.requirements("GB").await.unwrap();
+    assert_eq!(fetched.origin, Origin::Snapshot);
+    assert_eq!(fetched.data.country.id, "GB");
+    assert!(fetched.data.requirements.is_some());
+    server.abort();
+}
+
+#[tokio::test]
+async fn unknown_country_is_not_found() {
+    let (url, server) = serve_backend().await;
+    let transport = ReqwestTransport::default();
+    let cache = MemoryCache::default();
+    let client = DataClient::new(&url, &transport, &cache);
+    assert!(matches!(
+        client.requirements("XX").await,
+        Err(FetchError::NotFound)
+    ));
+    server.abort();
+}
+
+#[tokio::test]
+async fn watchlist_notices_changed_requirements() {
+    let data = Arc::new(Mutex::new(
+        RequirementsResponse::from_snapshot("JP").unwrap(),
+    ));
+    let app = Router::new()
+        .route(
+            "/api/v1/countries/:id/requirements",
+            get(
+                |State(data): State<Arc<Mutex<RequirementsResponse>>>| async move {
+                    Json(data.lock().unwrap().clone())
+                },
+            ),
+        )
+        .with_state(data.clone());
+    let (url, server) = serve(app).await;
+    let transport = ReqwestTransport::default();
+    let cache = MemoryCache::default();
+    let client = DataClient::new(&url, &transport, &cache);
+    let mut watchlist = Watchlist::default();
+    watchlist.toggle_trip(&Trip {
+        destination: Some("JP".to_string()),
+        ..Trip::default()
+    });
+
+    // the first refresh only remembers what the user sees
+    let current = watchlist.fetch(&client).await;
+    assert!(watchlist.compare(current).is_empty());
+    assert!(watchlist.seen.contains_key("JP"));
+
+    data.lock()
+        .unwrap()
+        .requirements
+        .as_mut()
+        .unwrap()
+        .quarantine_days = Some(180);
+    let current = watchlist.fetch(&client).await;
+    let notices = watchlist.compare(current);
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].changes[0].field, "quarantine_days");
+    watchlist.dismiss(&notices[0]);
+    let current = watchlist.fetch(&client).await;
+    assert!(watchlist.compare(current).is_empty());
+
+    // cached data is older than what the user saw, it is not compared
+    server.abort();
+    let _ = server.await;
+    let transport = ReqwestTransport::default();
+    let client = DataClient::new(&url, &transport, &cache);
+    assert!(watchlist.fetch(&client).await.is_empty());
+}
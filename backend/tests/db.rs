@@ -0,0 +1,94 @@
+use chrono::NaiveDate;
+use petabroad_backend::db::Database;
+use petabroad_core::api::{HistoryResponse, RequirementsResponse};
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_db_path(name: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("petabroad-{}-{}.sqlite", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn migrations_are_applied_once() {
+    let path = temp_db_path("migrations");
+    {
+        let db = Database::open(&path).unwrap();
+        assert_eq!(db.schema_version().unwrap(), 2);
+        assert!(db.is_empty().unwrap());
+        db.seed_from_core().unwrap();
+    }
+    let db = Database::open(&path).unwrap();
+    assert_eq!(db.schema_version().unwrap(), 2);
+    assert!(!db.is_empty().unwrap());
+    drop(db);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn seed_matches_core_datasets() {
+    let db = Database::open_in_memory().unwrap();
+    db.seed_from_core().unwrap();
+    assert_eq!(
+        db.airlines().unwrap().len(),
+        petabroad_core::data::airlines::get_airline_database()
+            .airlines()
+            .len()
+    );
+    let core = petabroad_core::data::requirements::get_requirements_database();
+    for id in ["FR", "DE", "GB", "JP", "AU", "NZ", "SG", "US"] {
+        assert_eq!(
+            db.requirements_for(id).unwrap(),
+            core.for_country(id),
+            "{}",
+            id
+        );
+        let sources: Vec<_> = core.sources_for(id).into_iter().cloned().collect();
+        assert_eq!(db.sources_for(id).unwrap(), sources, "{}", id);
+    }
+}
+
+#[test]
+fn stored_history_matches_core() {
+    let db = Database::open_in_memory().unwrap();
+    db.seed_from_core().unwrap();
+    let date = NaiveDate::from_ymd_opt(2005, 1, 1).unwrap();
+    for id in ["FR", "GB", "JP"] {
+        assert_eq!(
+            db.requirements_on(id, date).unwrap(),
+            RequirementsResponse::from_snapshot_on(id, date)
+                .unwrap()
+                .requirements,
+            "{}",
+            id
+        );
+        assert_eq!(
+            db.revisions_for(id).unwrap(),
+            HistoryResponse::from_snapshot(id).unwrap().revisions,
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn history_is_filled_in_stores_seeded_before_it() {
+    let path = temp_db_path("backfill");
+    Database::open(&path).unwrap().seed_from_core().unwrap();
+    {
+        // as left by a build without the second migration
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch("DROP TABLE requirement_versions; PRAGMA user_version = 1;")
+            .unwrap();
+    }
+    let db = Database::open(&path).unwrap();
+    assert_eq!(db.schema_version().unwrap(), 2);
+    assert_eq!(
+        db.revisions_for("GB").unwrap(),
+        HistoryResponse::from_snapshot("GB").unwrap().revisions
+    );
+    drop(db);
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,344 @@
+## Formats, chrono strftime patterns
+
+date-format = %Y/%m/%d
+date-time-format = %Y/%m/%d، %H:%M %Z
+list-or-separator = {" أو "}
+
+## Main window
+
+language = اللغة
+menu-button = الحيوان الأليف والرحلة
+view-list = عرض القائمة
+map-label = خريطة العالم. استخدم مفاتيح الأسهم للتنقل بين الدول ومفتاح Enter لفتح إحداها.
+map-edge = لا توجد دول أخرى في هذا الاتجاه
+map-country-prohibited = { $country }، لا يُسمح بدخول حيوانك الأليف
+map-country-matched = { $country }، تطابق عوامل التصفية
+map-country-changed = { $country }، تغيرت الشروط منذ التاريخ المختار
+map-date = القواعد السارية في
+map-date-today = اليوم
+map-date-changed = عدد الدول التي غيرت شروطها منذ { $date }: { $count }
+map-date-cleared = تظهر الشروط الحالية
+print-page = طباعة
+
+## Pet profile
+
+profile-title = ملف الحيوان الأليف
+profile-name = الاسم
+profile-species = النوع
+profile-breed = السلالة
+profile-birth-date = تاريخ الميلاد
+profile-weight = الوزن، كغ
+profile-microchip = رقم الشريحة الإلكترونية
+profile-microchip-implanted = تاريخ زرع الشريحة
+profile-vaccination = لقاح داء الكلب
+profile-add-vaccination = إضافة لقاح داء الكلب
+profile-titer-date = تاريخ سحب عينة الدم لاختبار الأجسام المضادة
+profile-titer-value = مستوى الأجسام المضادة، وحدة دولية/مل
+profile-laboratory = المختبر
+profile-laboratory-none = لم يتم الاختيار
+
+species-dog = كلب
+species-cat = قطة
+species-ferret = نمس
+
+## Trip
+
+trip-title = الرحلة
+trip-from = من: { $country }
+trip-via = عبر: { $countries }
+trip-to = إلى: { $country }
+trip-pick-country = اختر دولة على الخريطة
+trip-departure = المغادرة
+trip-airline = شركة الطيران
+trip-airline-none = لم يتم الاختيار بعد
+
+transport-mode-cabin = المقصورة
+transport-mode-hold = عنبر الأمتعة
+transport-mode-cargo = الشحن الجوي
+
+## Travel crate
+
+crate-title = قفص السفر
+crate-length = الطول من الأنف إلى قاعدة الذيل (A)
+crate-elbow-height = من الأرض إلى المرفق (B)
+crate-width = العرض عند الكتفين (C)
+crate-height = من الأرض إلى أطراف الأذنين (D)
+crate-dimensions = { $length } × { $width } × { $height } سم
+crate-minimum = الحد الأدنى للحجم الداخلي: { $size }
+crate-series = قفص من الفئة { $series }، الأبعاد الخارجية { $size }
+crate-custom = أكبر من الفئة 700، يلزم قفص مصنوع حسب الطلب
+crate-airline-accepts = تقبل { $airline } هذا القفص في: { $modes }
+crate-airline-rejects = لا تقبل { $airline } قفصًا من الفئة { $series }
+
+## Country view
+
+country-fly-from = السفر من هنا
+country-fly-to = السفر إلى هنا
+country-stop-over = توقف مؤقت
+country-watch = متابعة التغييرات
+country-view-close = إغلاق
+country-view-opened = شروط الدخول إلى { $country }
+country-view-closed = العودة إلى الخريطة
+
+breed-blocked = لا يُسمح بدخول { $breed } إلى { $country }
+breed-banned = سلالة { $breed } محظورة في { $place }
+breed-restricted = سلالة { $breed } مقيدة في { $place }
+
+carriers-title = شركات الطيران من { $country }
+carriers-none = لا توجد شركة طيران في قاعدة بياناتنا تقبل حيوانك الأليف على هذا المسار.
+
+requirements-title = شروط الدخول
+requirements-loading = جارٍ تحميل شروط الدخول
+requirements-failed = تعذر تحميل شروط الدخول: { $reason }
+requirements-missing = لا تتوفر لدينا شروط الدخول لهذه الدولة بعد.
+requirements-saved = غير متصل: عرض الشروط المحفوظة بتاريخ { $date }
+requirements-bundled = غير متصل: عرض الشروط المضمنة في التطبيق
+
+microchip-title = الشريحة الإلكترونية
+microchip-iso-required = يلزم وجود شريحة إلكترونية وفق معيار ISO 11784/11785.
+microchip-iso-expected = يُتوقع وجود شريحة وفق معيار ISO 11784/11785، وتُقبل الشرائح الأخرى إذا أحضرت قارئًا لها.
+microchip-before-vaccination = يجب زرعها قبل لقاح داء الكلب.
+
+titer-title = اختبار الأجسام المضادة لداء الكلب
+titer-summary = ما لا يقل عن { $min } وحدة دولية/مل بطريقة { $methods }، مع سحب عينة الدم قبل الوصول بـ { $days } يومًا على الأقل وفحصها في مختبر معتمد من { $approval }.
+
+tapeworm-title = علاج الدودة الشريطية لدخول { $country }
+tapeworm-rule = يجب أن يعالج طبيب بيطري الكلاب ضد Echinococcus multilocularis قبل الوصول بما لا يقل عن 24 ساعة ولا يزيد عن 120 ساعة.
+tapeworm-arrival = موعد الوصول المقرر
+tapeworm-arrival-time-zone = المنطقة الزمنية للوصول
+tapeworm-vet-time-zone = المنطقة الزمنية للطبيب البيطري
+tapeworm-window = زر الطبيب البيطري بين { $earliest } و{ $latest }، وتأكد من أنه يسجل تاريخ العلاج ووقته.
+tapeworm-nonexistent-time = هذا الوقت المحلي غير موجود في المنطقة الزمنية للوصول بسبب تغيير التوقيت.
+tapeworm-untimed = وقت الوصول إلى الوجهة النهائية وحده معروف، خطط لزيارة الطبيب البيطري وفق موعد وصولك إلى { $country }.
+
+steps-title = خطوات لحيوانك الأليف
+steps-may-apply = قد ينطبق، بحسب تفاصيل لم تُدخل بعد
+step-entry-refused = لا يُسمح لحيوانك الأليف بالدخول.
+step-minimum-age = يجب ألا يقل عمر الحيوان عند الوصول عن { $weeks } أسبوعًا.
+step-microchip = قم بزرع شريحة إلكترونية.
+step-microchip-iso = قم بزرع شريحة إلكترونية وفق معيار ISO 11784/11785.
+step-rabies-vaccination = قم بالتطعيم ضد داء الكلب بدءًا من عمر { $weeks } أسبوعًا، عدد الجرعات { $doses }، آخرها قبل الوصول بـ { $days } يومًا على الأقل.
+step-titer-test = أجرِ فحص الأجسام المضادة لداء الكلب بمستوى لا يقل عن { $min } وحدة دولية/مل، مع سحب عينة الدم قبل الوصول بـ { $days } يومًا على الأقل.
+step-tapeworm-treatment = اطلب من طبيب بيطري علاج الكلب من الديدان الشريطية قبل الوصول بمدة تتراوح بين 24 و120 ساعة.
+step-health-certificate = احصل على شهادة صحية يوقعها طبيب بيطري قبل الوصول بما لا يزيد عن { $days } يومًا.
+step-pet-passport = أحضر جواز سفر الحيوانات الأليفة الأوروبي.
+step-import-permit = قدّم طلبًا للحصول على تصريح استيراد.
+step-advance-notice = أبلغ سلطات الحدود قبل الوصول بـ { $days } يومًا على الأقل.
+step-transit-declaration = وقّع إقرارًا بأن الحيوان بقي في المطار أو على متن الطائرة أثناء التوقفات.
+step-quarantine = حجر صحي لمدة { $days } يومًا بعد الوصول.
+step-quarantine-none = لا يوجد حجر صحي عند الوصول.
+
+steps-not-required = غير مطلوب لحيوانك الأليف
+
+explanation-toggle = لماذا؟
+explanation-applies = ينطبق على حيوانك الأليف.
+explanation-may-apply = قد ينطبق، بعض التفاصيل لم تُدخل بعد.
+explanation-not-met = لا ينطبق على حيوانك الأليف.
+explanation-met = متحقق
+explanation-failed = غير متحقق
+explanation-unknown = غير معروف بعد
+explanation-source = المصدر:
+explanation-verified = آخر تحقق في { $date }
+explanation-no-source = لا يوجد مصدر مسجل لهذه القاعدة.
+
+condition-always = ينطبق على كل حيوان
+condition-all = كل ما يلي
+condition-any = أي مما يلي
+condition-not = ليس صحيحًا أن
+condition-origin-in = السفر من { $countries }
+condition-origin-in-bloc = السفر من دولة عضو في { $bloc }
+condition-origin-rabies-status = وضع داء الكلب في بلد المغادرة: { $status }
+condition-titer-exempt-origin = تعفي { $country } القادمين من بلد المغادرة من فحص الأجسام المضادة
+condition-species = النوع: { $species }
+condition-age-under-weeks = أصغر من { $weeks } أسبوعًا عند الوصول
+condition-vaccinated = يوجد تطعيم مسجل ضد داء الكلب
+condition-transit = يتضمن المسار توقفات
+condition-transit-through = توقف في { $countries }
+
+freshness-verified = تم التحقق في { $date } بواسطة { $verifier }
+freshness-unverified = لم يتم التحقق بعد
+freshness-stale = تم التحقق من هذه الشروط آخر مرة قبل { $days } يومًا وربما تغيرت منذ ذلك الحين. راجع المصادر الرسمية قبل السفر.
+
+## Requirement history
+
+history-title = سجل التغييرات
+history-loading = جارٍ تحميل سجل التغييرات
+history-failed = تعذر تحميل سجل التغييرات: { $reason }
+history-none = لا توجد تغييرات مسجلة على هذه الشروط.
+history-show = عرض كل التغييرات
+history-effective = سارية منذ { $date }
+history-since = تغيرت منذ { $date }
+history-unchanged-since = لم تتغير منذ { $date }
+history-as-of = تظهر الشروط التي كانت سارية في { $date }
+history-field = الشرط
+history-before = قبل
+history-after = بعد
+history-yes = نعم
+history-no = لا
+history-not-set = غير محدد
+history-field-import_permit = تصريح الاستيراد
+history-field-microchip-before_vaccination = الشريحة قبل التطعيم
+history-field-microchip-iso_required = شريحة ISO
+history-field-quarantine_days = الحجر الصحي، بالأيام
+history-field-rabies_status = وضع داء الكلب
+history-field-titer-exempt_origins = لا يلزم اختبار الأجسام المضادة من
+history-field-titer-lab_approval = المختبر معتمد من
+history-field-titer-methods = طرق اختبار الأجسام المضادة
+history-field-titer-min_iu_per_ml = الحد الأدنى للأجسام المضادة، وحدة دولية/مل
+history-field-titer-validity_days = صلاحية نتيجة الاختبار، بالأيام
+history-field-titer-wait_days = الأيام من الاختبار حتى الوصول
+
+## Watchlist
+
+watchlist-title = قائمة المتابعة
+watchlist-empty = تابع دولة أو رحلتك لتعرف عندما تتغير شروطها.
+watchlist-watch-trip = متابعة هذه الرحلة
+watchlist-trip = { $countries }
+watchlist-trip-departure = { $countries }، المغادرة في { $date }
+watchlist-remove = إيقاف متابعة { $country }
+watchlist-alerts = إظهار إشعارات المتصفح أيضًا
+watchlist-alerts-blocked = هذا المتصفح لا يسمح بالإشعارات من petabroad.io.
+watch-notice-title = عدد الدول المتابعة التي تغيرت شروطها: { $count }
+watch-notice-trip = في رحلتك { $countries }
+watch-notice-open = فتح { $country }
+watch-notice-dismiss = حسنًا
+
+## Checklist export
+
+checklist-title = قائمة التحقق لسفر الحيوان الأليف: { $country }
+checklist-generated = أُعدت في { $date } باستخدام petabroad.io. تحقق من المصادر الرسمية قبل السفر.
+checklist-pet = الحيوان الأليف
+checklist-origin = من
+checklist-stopovers = عبر
+checklist-destination = إلى
+checklist-arrival = الوصول
+checklist-steps = الخطوات
+checklist-no-steps = لم تُسجل قواعد دخول لهذه الدولة بعد.
+checklist-done = تم
+checklist-step = الخطوة
+checklist-due = متى
+checklist-due-by = قبل { $date }
+checklist-due-from = ليس قبل { $date }
+checklist-source = المصدر
+checklist-verified = تم التحقق في { $date }
+checklist-findings = فحص المستندات
+checklist-status-satisfied = سليم
+checklist-status-pending = مطلوب
+checklist-status-violated = مشكلة
+checklist-sources = المصادر الرسمية
+checklist-export-title = خذها معك
+checklist-export-print = قائمة قابلة للطباعة
+checklist-export-markdown = تنزيل بصيغة Markdown
+
+## Certificates
+
+owner-title = المالك
+owner-hint = تُطبع في الشهادات وطلبات تصاريح الاستيراد. تُحفظ في هذا المتصفح فقط.
+owner-name = الاسم الكامل
+owner-address = العنوان
+owner-phone = الهاتف
+owner-email = البريد الإلكتروني
+certificate-export-title = الشهادات والتصاريح
+certificate-export-hint = معبأة مسبقًا ببيانات حيوانك ورحلتك والمالك، جاهزة لأخذها إلى الطبيب البيطري.
+certificate-form = النموذج الرسمي
+certificate-prepared = أُعدّت في { $date } باستخدام petabroad.io لدخول { $country }. تساعدك هذه النسخة على تعبئة النموذج الرسمي، ولا يصدره إلا الطبيب البيطري الرسمي أو الجهة المختصة.
+certificate-progress = تمت تعبئة { $count } من { $total } حقلًا
+certificate-blanks = ما زال يجب تعبئته يدويًا:
+certificate-complete = تمت تعبئة كل ما يمكنك تعبئته.
+certificate-blank = للتعبئة
+certificate-veterinarian = يعبئه الطبيب البيطري الرسمي
+
+## Backup
+
+backup-title = النسخة الاحتياطية
+backup-hint = تُحفظ بيانات حيوانك والمالك ورحلتك وقائمة المتابعة والإعدادات في هذا المتصفح فقط. احفظها في ملف للاحتفاظ بها أو لنقلها إلى جهاز آخر.
+backup-export = حفظ في ملف
+backup-import = الاستعادة من ملف
+backup-found = نسخة احتياطية بتاريخ { $date }
+backup-merge = الدمج مع البيانات الحالية
+backup-replace = استبدال البيانات الحالية
+backup-cancel = إلغاء
+backup-merged = تم دمج النسخة الاحتياطية مع البيانات الحالية.
+backup-replaced = استبدلت النسخة الاحتياطية البيانات الحالية.
+backup-kept-pet = تم الإبقاء على حيوانك الحالي، فالنسخة الاحتياطية تحتوي على حيوان مختلف.
+backup-kept-owner = تم الإبقاء على بيانات المالك الحالية، فالنسخة الاحتياطية تحتوي على بيانات مختلفة.
+backup-kept-trip = تم الإبقاء على رحلتك الحالية، فالنسخة الاحتياطية تحتوي على رحلة مختلفة.
+backup-error-read = تعذرت قراءة الملف.
+backup-error-malformed = هذا الملف ليس نسخة احتياطية من petabroad.
+backup-error-newer = أُنشئت هذه النسخة الاحتياطية بإصدار أحدث من petabroad. حدّث التطبيق لاستعادتها.
+backup-error-invalid = تشير هذه النسخة الاحتياطية إلى بيانات لا يعرفها هذا الإصدار من petabroad:
+backup-problem-country = رمز الدولة { $code }
+backup-problem-airline = شركة الطيران { $airline }
+backup-problem-laboratory = المختبر { $laboratory }
+backup-problem-locale = اللغة { $code }
+
+## Filters
+
+filter-title = إلى أين يمكننا السفر؟
+filter-no-quarantine = بدون حجر صحي
+filter-no-titer-test = بدون اختبار الأجسام المضادة
+filter-accepts-breed = تقبل سلالة حيواني
+filter-cabin-from-origin = يمكن الوصول إليها في المقصورة من بلدي
+filter-eu-pet-passport = جواز سفر الحيوانات الأليفة الأوروبي صالح
+filter-needs-origin = حدد مكان المغادرة أولًا
+filter-matches = عدد الدول المطابقة: { $count }
+filter-clear = مسح عوامل التصفية
+filter-cleared = تم مسح عوامل التصفية، وتظهر جميع الدول
+
+## Country list
+
+table-caption = عدد الدول: { $count }
+table-filter = تصفية الدول
+table-country = الدولة
+table-quarantine = الحجر الصحي
+table-titer = اختبار الأجسام المضادة
+table-permit = تصريح الاستيراد
+table-rabies = حالة داء الكلب
+table-yes = مطلوب
+table-no = غير مطلوب
+table-unknown = لا توجد بيانات
+table-quarantine-none = لا يوجد
+table-quarantine-days = { $days } يومًا
+
+rabies-free = خالية من داء الكلب
+rabies-controlled = تحت السيطرة
+rabies-high-incidence = انتشار مرتفع
+
+## Microchip descriptions and parse errors
+
+chip-iso-country = شريحة ISO 11784/11785، رمز الدولة { $code } ({ $country })
+chip-iso-country-unknown = شريحة ISO 11784/11785، رمز الدولة { $code }
+chip-iso-manufacturer = شريحة ISO 11784/11785، رمز الشركة المصنعة { $code } ({ $manufacturer })
+chip-iso-manufacturer-unknown = شريحة ISO 11784/11785، رمز الشركة المصنعة { $code }
+chip-avid = شريحة AVID من 9 أرقام، غير مطابقة لمعيار ISO
+chip-fecava = شريحة FECAVA من 10 رموز، غير مطابقة لمعيار ISO
+
+chip-error-empty = رقم الشريحة فارغ
+chip-error-character = الرمز '{ $character }' غير مسموح به
+chip-error-length = عدد الرموز { $length } لا يطابق تنسيقات ISO (15) أو AVID (9) أو FECAVA (10)
+chip-error-reserved = الرمز { $code } محجوز ولا يُصدر أبدًا
+
+## Compliance findings
+
+finding-chip-number-missing = أدخل رقم الشريحة الإلكترونية
+finding-chip-iso-required = { $chip }: لا تُقبل إلا شرائح ISO 11784/11785، ويجب زرع شريحة ISO ثانية
+finding-chip-reader = { $chip }: أحضر قارئًا يستطيع قراءتها، فقد لا يتوفر لدى موظفي الحدود
+finding-chip-invalid = { $number } ليس رقم شريحة صالحًا: { $error }
+finding-chip-implanted-missing = أدخل تاريخ زرع الشريحة الإلكترونية
+finding-vaccination-missing = سجّل لقاح داء الكلب، ويجب إعطاؤه بعد زرع الشريحة
+finding-vaccination-after-chip = أُعطي لقاح داء الكلب بتاريخ { $vaccination } بعد زرع الشريحة بتاريخ { $implanted }
+finding-vaccination-before-chip = أُعطيت جميع لقاحات داء الكلب قبل زرع الشريحة بتاريخ { $implanted }، ويجب تطعيم الحيوان مرة أخرى
+
+finding-titer-not-needed = لا يلزم اختبار الأجسام المضادة عند السفر من بلد المنشأ الخاص بك
+finding-titer-missing = يلزم مستوى أجسام مضادة لداء الكلب لا يقل عن { $min } وحدة دولية/مل، مع سحب عينة الدم قبل الوصول بـ { $days } يومًا على الأقل
+finding-titer-below = مستوى { $value } وحدة دولية/مل أقل من المطلوب { $min } وحدة دولية/مل
+finding-titer-meets = مستوى { $value } وحدة دولية/مل يستوفي المطلوب { $min } وحدة دولية/مل
+finding-lab-missing = اختر المختبر الذي فحص العينة، ويجب أن يكون معتمدًا من { $approval }
+finding-lab-not-approved = { $laboratory } غير معتمد من { $approval }، ويجب إعادة الاختبار في مختبر معتمد
+finding-lab-method = لا يجري { $laboratory } الاختبار بطريقة معتمدة ({ $methods })
+finding-lab-approved = { $laboratory } مختبر معتمد
+finding-arrival-missing = حدد تاريخ الوصول: يُسمح بالدخول اعتبارًا من { $date }
+finding-wait-pending = تنتهي فترة الانتظار البالغة { $days } يومًا بعد سحب عينة الدم في { $date }، أي بعد موعد الوصول المخطط
+finding-wait-over = انتهت فترة الانتظار البالغة { $days } يومًا في { $date }
+finding-titer-expired = انتهت صلاحية نتيجة الاختبار في { $date }
+finding-titer-valid = نتيجة الاختبار صالحة حتى { $date }
@@ -0,0 +1,362 @@
+## Formats, chrono strftime patterns
+
+date-format = %d.%m.%Y
+date-time-format = %d.%m.%Y, %H:%M %Z
+list-or-separator = {" oder "}
+
+## Main window
+
+language = Sprache
+menu-button = Haustier und Reise
+view-list = Listenansicht
+map-label = Weltkarte. Mit den Pfeiltasten zwischen Ländern wechseln, mit der Eingabetaste eines öffnen.
+map-edge = In dieser Richtung gibt es keine weiteren Länder
+map-country-prohibited = { $country }, Einreise für Ihr Haustier nicht erlaubt
+map-country-matched = { $country }, passt zu Ihren Filtern
+map-country-changed = { $country }, Einreisebestimmungen seit dem gewählten Datum geändert
+map-date = Bestimmungen am
+map-date-today = Heute
+map-date-changed = { $count ->
+        [one] { $count } Land hat seine Bestimmungen seit dem { $date } geändert
+       *[other] { $count } Länder haben ihre Bestimmungen seit dem { $date } geändert
+    }
+map-date-cleared = Aktuelle Bestimmungen werden angezeigt
+print-page = Drucken
+
+## Pet profile
+
+profile-title = Tierprofil
+profile-name = Name
+profile-species = Tierart
+profile-breed = Rasse
+profile-birth-date = Geburtsdatum
+profile-weight = Gewicht, kg
+profile-microchip = Mikrochipnummer
+profile-microchip-implanted = Mikrochip implantiert am
+profile-vaccination = Tollwutimpfung
+profile-add-vaccination = Tollwutimpfung hinzufügen
+profile-titer-date = Datum der Blutprobe für den Titertest
+profile-titer-value = Titer, IE/ml
+profile-laboratory = Labor
+profile-laboratory-none = Nicht ausgewählt
+
+species-dog = Hund
+species-cat = Katze
+species-ferret = Frettchen
+
+## Trip
+
+trip-title = Reise
+trip-from = Von: { $country }
+trip-via = Über: { $countries }
+trip-to = Nach: { $country }
+trip-pick-country = Land auf der Karte wählen
+trip-departure = Abflug
+trip-airline = Fluggesellschaft
+trip-airline-none = Noch nicht gewählt
+
+transport-mode-cabin = Kabine
+transport-mode-hold = Frachtraum
+transport-mode-cargo = Luftfracht
+
+## Travel crate
+
+crate-title = Transportbox
+crate-length = Länge, Nase bis Schwanzansatz (A)
+crate-elbow-height = Boden bis Ellbogen (B)
+crate-width = Breite über die Schultern (C)
+crate-height = Boden bis Ohrspitzen (D)
+crate-dimensions = { $length } × { $width } × { $height } cm
+crate-minimum = Mindestinnenmaß: { $size }
+crate-series = Box der Serie { $series }, Außenmaß { $size }
+crate-custom = Größer als Serie 700, eine Sonderanfertigung ist nötig
+crate-airline-accepts = { $airline } akzeptiert diese Box in: { $modes }
+crate-airline-rejects = { $airline } akzeptiert keine Box der Serie { $series }
+
+## Country view
+
+country-fly-from = Von hier fliegen
+country-fly-to = Hierher fliegen
+country-stop-over = Zwischenstopp
+country-watch = Auf Änderungen beobachten
+country-view-close = Schließen
+country-view-opened = Einreisebestimmungen für { $country }
+country-view-closed = Zurück zur Karte
+
+breed-blocked = { $breed } darf nicht nach { $country } einreisen
+breed-banned = { $breed } ist in { $place } verboten
+breed-restricted = { $breed } unterliegt in { $place } Beschränkungen
+
+carriers-title = Fluggesellschaften ab { $country }
+carriers-none = Keine Fluggesellschaft in unserer Datenbank befördert Ihr Tier auf dieser Strecke.
+
+requirements-title = Einreisebestimmungen
+requirements-loading = Einreisebestimmungen werden geladen
+requirements-failed = Die Einreisebestimmungen konnten nicht geladen werden: { $reason }
+requirements-missing = Für dieses Land liegen uns noch keine Einreisebestimmungen vor.
+requirements-saved = Offline: Einreisebestimmungen mit Stand vom { $date }
+requirements-bundled = Offline: Einreisebestimmungen aus der App
+
+microchip-title = Mikrochip
+microchip-iso-required = Ein Mikrochip nach ISO 11784/11785 ist vorgeschrieben.
+microchip-iso-expected = Ein Mikrochip nach ISO 11784/11785 wird erwartet, andere Chips werden akzeptiert, wenn Sie ein Lesegerät mitbringen.
+microchip-before-vaccination = Er muss vor der Tollwutimpfung implantiert werden.
+
+titer-title = Tollwut-Antikörpertest
+titer-summary = Mindestens { $min } IE/ml mittels { $methods }, Blutprobe mindestens { $days } Tage vor der Einreise, untersucht von einem durch { $approval } zugelassenen Labor.
+
+tapeworm-title = Bandwurmbehandlung für { $country }
+tapeworm-rule = Hunde müssen frühestens 120 und spätestens 24 Stunden vor der Einreise von einem Tierarzt gegen Echinococcus multilocularis behandelt werden.
+tapeworm-arrival = Geplante Ankunft
+tapeworm-arrival-time-zone = Zeitzone der Ankunft
+tapeworm-vet-time-zone = Zeitzone des Tierarztes
+tapeworm-window = Besuchen Sie den Tierarzt zwischen { $earliest } und { $latest } und achten Sie darauf, dass Datum und Uhrzeit der Behandlung eingetragen werden.
+tapeworm-nonexistent-time = Diese Ortszeit gibt es wegen einer Zeitumstellung in der Zeitzone der Ankunft nicht.
+tapeworm-untimed = Bekannt ist nur die Ankunftszeit am Reiseziel, planen Sie den Tierarztbesuch anhand Ihrer Ankunftszeit in { $country }.
+
+steps-title = Schritte für Ihr Tier
+steps-may-apply = Gilt möglicherweise, je nach noch fehlenden Angaben
+step-entry-refused = Ihr Tier darf nicht einreisen.
+step-minimum-age = Das Tier muss bei der Einreise mindestens { $weeks } Wochen alt sein.
+step-microchip = Lassen Sie einen Mikrochip implantieren.
+step-microchip-iso = Lassen Sie einen Mikrochip nach ISO 11784/11785 implantieren.
+step-rabies-vaccination = Impfen Sie ab einem Alter von { $weeks } Wochen gegen Tollwut, { $doses ->
+        [one] { $doses } Dosis
+       *[other] { $doses } Dosen
+    }, die letzte mindestens { $days } Tage vor der Einreise.
+step-titer-test = Lassen Sie einen Tollwut-Antikörpertest mit mindestens { $min } IE/ml machen, Blutprobe mindestens { $days } Tage vor der Einreise.
+step-tapeworm-treatment = Lassen Sie den Hund 24 bis 120 Stunden vor der Einreise tierärztlich gegen Bandwürmer behandeln.
+step-health-certificate = Besorgen Sie eine Gesundheitsbescheinigung, höchstens { $days } Tage vor der Einreise tierärztlich unterschrieben.
+step-pet-passport = Nehmen Sie den EU-Heimtierausweis mit.
+step-import-permit = Beantragen Sie eine Einfuhrgenehmigung.
+step-advance-notice = Melden Sie die Einreise mindestens { $days } Tage vorher bei den Grenzbehörden an.
+step-transit-declaration = Unterschreiben Sie eine Erklärung, dass das Tier bei Zwischenstopps im Flughafen oder an Bord blieb.
+step-quarantine = Quarantäne für { $days } Tage nach der Einreise.
+step-quarantine-none = Keine Quarantäne bei der Einreise.
+
+steps-not-required = Für Ihr Tier nicht erforderlich
+
+explanation-toggle = Warum?
+explanation-applies = Gilt für Ihr Tier.
+explanation-may-apply = Gilt möglicherweise, einige Angaben fehlen noch.
+explanation-not-met = Gilt nicht für Ihr Tier.
+explanation-met = erfüllt
+explanation-failed = nicht erfüllt
+explanation-unknown = noch unbekannt
+explanation-source = Quelle:
+explanation-verified = Zuletzt geprüft am { $date }
+explanation-no-source = Für diese Regel ist keine Quelle hinterlegt.
+
+condition-always = Gilt für jedes Tier
+condition-all = Alle folgenden
+condition-any = Eine der folgenden
+condition-not = Nicht der Fall
+condition-origin-in = Reise aus { $countries }
+condition-origin-in-bloc = Reise aus einem Mitgliedsstaat der { $bloc }
+condition-origin-rabies-status = Tollwutstatus des Herkunftslandes: { $status }
+condition-titer-exempt-origin = { $country } verzichtet beim Herkunftsland auf den Titertest
+condition-species = Tierart: { $species }
+condition-age-under-weeks = Bei der Einreise jünger als { $weeks } Wochen
+condition-vaccinated = Eine Tollwutimpfung ist eingetragen
+condition-transit = Die Route hat Zwischenstopps
+condition-transit-through = Zwischenstopp in { $countries }
+
+freshness-verified = Geprüft am { $date } von { $verifier }
+freshness-unverified = Noch nicht geprüft
+freshness-stale = Diese Bestimmungen wurden zuletzt vor { $days } Tagen geprüft und könnten sich inzwischen geändert haben. Prüfen Sie vor der Reise die offiziellen Quellen.
+
+## Requirement history
+
+history-title = Änderungsverlauf
+history-loading = Änderungsverlauf wird geladen
+history-failed = Der Änderungsverlauf konnte nicht geladen werden: { $reason }
+history-none = Zu diesen Bestimmungen sind keine Änderungen erfasst.
+history-show = Alle Änderungen anzeigen
+history-effective = Gültig ab { $date }
+history-since = Geändert seit dem { $date }
+history-unchanged-since = Unverändert seit dem { $date }
+history-as-of = Es werden die am { $date } gültigen Bestimmungen angezeigt
+history-field = Anforderung
+history-before = Vorher
+history-after = Nachher
+history-yes = Ja
+history-no = Nein
+history-not-set = Keine Angabe
+history-field-import_permit = Einfuhrgenehmigung
+history-field-microchip-before_vaccination = Mikrochip vor der Impfung
+history-field-microchip-iso_required = ISO-Mikrochip
+history-field-quarantine_days = Quarantäne, Tage
+history-field-rabies_status = Tollwutstatus
+history-field-titer-exempt_origins = Kein Titertest aus
+history-field-titer-lab_approval = Labor zugelassen von
+history-field-titer-methods = Methoden des Titertests
+history-field-titer-min_iu_per_ml = Mindesttiter, IE/ml
+history-field-titer-validity_days = Titerergebnis gültig, Tage
+history-field-titer-wait_days = Tage vom Titertest bis zur Ankunft
+
+## Watchlist
+
+watchlist-title = Beobachtungsliste
+watchlist-empty = Beobachten Sie ein Land oder Ihre Reise, um bei geänderten Bestimmungen benachrichtigt zu werden.
+watchlist-watch-trip = Diese Reise beobachten
+watchlist-trip = { $countries }
+watchlist-trip-departure = { $countries }, Abreise am { $date }
+watchlist-remove = { $country } nicht mehr beobachten
+watchlist-alerts = Auch Browser-Benachrichtigungen anzeigen
+watchlist-alerts-blocked = Dieser Browser erlaubt keine Benachrichtigungen von petabroad.io.
+watch-notice-title = { $count ->
+        [one] Die Bestimmungen eines beobachteten Landes haben sich geändert
+       *[other] Die Bestimmungen von { $count } beobachteten Ländern haben sich geändert
+    }
+watch-notice-trip = Auf Ihrer Reise { $countries }
+watch-notice-open = { $country } öffnen
+watch-notice-dismiss = Verstanden
+
+## Checklist export
+
+checklist-title = Reisecheckliste für Haustiere: { $country }
+checklist-generated = Erstellt am { $date } mit petabroad.io. Prüfen Sie vor der Reise die offiziellen Quellen.
+checklist-pet = Haustier
+checklist-origin = Von
+checklist-stopovers = Über
+checklist-destination = Nach
+checklist-arrival = Ankunft
+checklist-steps = Schritte
+checklist-no-steps = Für dieses Land sind noch keine Einreiseregeln erfasst.
+checklist-done = Erledigt
+checklist-step = Schritt
+checklist-due = Wann
+checklist-due-by = Bis { $date }
+checklist-due-from = Nicht vor dem { $date }
+checklist-source = Quelle
+checklist-verified = geprüft am { $date }
+checklist-findings = Geprüfte Unterlagen
+checklist-status-satisfied = In Ordnung
+checklist-status-pending = Offen
+checklist-status-violated = Problem
+checklist-sources = Offizielle Quellen
+checklist-export-title = Zum Mitnehmen
+checklist-export-print = Checkliste zum Drucken
+checklist-export-markdown = Als Markdown herunterladen
+
+## Certificates
+
+owner-title = Halter
+owner-hint = Wird auf Gesundheitszeugnisse und Einfuhranträge gedruckt. Nur in diesem Browser gespeichert.
+owner-name = Vollständiger Name
+owner-address = Anschrift
+owner-phone = Telefon
+owner-email = E-Mail
+certificate-export-title = Zeugnisse und Genehmigungen
+certificate-export-hint = Vorausgefüllt mit Ihrem Tier, Ihrer Reise und den Halterangaben, bereit für den Tierarzt.
+certificate-form = Amtliches Formular
+certificate-prepared = Erstellt am { $date } mit petabroad.io für die Einreise nach { $country }. Diese Kopie hilft beim Ausfüllen des amtlichen Formulars, ausstellen kann es nur der Amtstierarzt oder die Behörde.
+certificate-progress = { $count } von { $total } Feldern ausgefüllt
+certificate-blanks = Noch von Hand auszufüllen:
+certificate-complete = Alles, was Sie ausfüllen können, ist erledigt.
+certificate-blank = Auszufüllen
+certificate-veterinarian = Für den Amtstierarzt
+
+## Backup
+
+backup-title = Sicherung
+backup-hint = Ihr Tier, die Halterangaben, Ihre Reise, die Beobachtungsliste und die Einstellungen werden nur in diesem Browser gespeichert. Sichern Sie sie in einer Datei, um sie aufzubewahren oder auf ein anderes Gerät zu übertragen.
+backup-export = In Datei sichern
+backup-import = Aus Datei wiederherstellen
+backup-found = Sicherung vom { $date }
+backup-merge = Mit den aktuellen Daten zusammenführen
+backup-replace = Aktuelle Daten ersetzen
+backup-cancel = Abbrechen
+backup-merged = Die Sicherung wurde mit den aktuellen Daten zusammengeführt.
+backup-replaced = Die Sicherung hat die aktuellen Daten ersetzt.
+backup-kept-pet = Ihr aktuelles Tier wurde beibehalten, die Sicherung enthält ein anderes.
+backup-kept-owner = Ihre aktuellen Halterangaben wurden beibehalten, die Sicherung enthält andere.
+backup-kept-trip = Ihre aktuelle Reise wurde beibehalten, die Sicherung enthält eine andere.
+backup-error-read = Die Datei konnte nicht gelesen werden.
+backup-error-malformed = Diese Datei ist keine petabroad-Sicherung.
+backup-error-newer = Diese Sicherung stammt aus einer neueren Version von petabroad. Aktualisieren Sie die App, um sie wiederherzustellen.
+backup-error-invalid = Diese Sicherung verweist auf Daten, die diese Version von petabroad nicht kennt:
+backup-problem-country = Ländercode { $code }
+backup-problem-airline = Fluggesellschaft { $airline }
+backup-problem-laboratory = Labor { $laboratory }
+backup-problem-locale = Sprache { $code }
+
+## Filters
+
+filter-title = Wohin können wir reisen?
+filter-no-quarantine = Keine Quarantäne
+filter-no-titer-test = Kein Titertest
+filter-accepts-breed = Erlaubt meine Rasse
+filter-cabin-from-origin = In der Kabine von zu Hause erreichbar
+filter-eu-pet-passport = EU-Heimtierausweis gültig
+filter-needs-origin = Legen Sie zuerst den Abflugort fest
+filter-matches = { $count ->
+        [one] { $count } Land passt
+       *[other] { $count } Länder passen
+    }
+filter-clear = Filter zurücksetzen
+filter-cleared = Filter zurückgesetzt, alle Länder werden angezeigt
+
+## Country list
+
+table-caption = { $count ->
+        [one] { $count } Land
+       *[other] { $count } Länder
+    }
+table-filter = Länder filtern
+table-country = Land
+table-quarantine = Quarantäne
+table-titer = Titertest
+table-permit = Einfuhrgenehmigung
+table-rabies = Tollwutstatus
+table-yes = Erforderlich
+table-no = Nicht erforderlich
+table-unknown = Keine Daten
+table-quarantine-none = Keine
+table-quarantine-days = { $days ->
+        [one] { $days } Tag
+       *[other] { $days } Tage
+    }
+
+rabies-free = Tollwutfrei
+rabies-controlled = Unter Kontrolle
+rabies-high-incidence = Hohes Vorkommen
+
+## Microchip descriptions and parse errors
+
+chip-iso-country = Chip nach ISO 11784/11785, Ländercode { $code } ({ $country })
+chip-iso-country-unknown = Chip nach ISO 11784/11785, Ländercode { $code }
+chip-iso-manufacturer = Chip nach ISO 11784/11785, Herstellercode { $code } ({ $manufacturer })
+chip-iso-manufacturer-unknown = Chip nach ISO 11784/11785, Herstellercode { $code }
+chip-avid = 9-stelliger AVID-Chip, nicht ISO-konform
+chip-fecava = 10-stelliger FECAVA-Chip, nicht ISO-konform
+
+chip-error-empty = die Chipnummer ist leer
+chip-error-character = '{ $character }' ist nicht erlaubt
+chip-error-length = { $length } Zeichen passen weder zum ISO- (15), AVID- (9) noch zum FECAVA-Format (10)
+chip-error-reserved = der Code { $code } ist reserviert und wird nie vergeben
+
+## Compliance findings
+
+finding-chip-number-missing = Geben Sie die Mikrochipnummer ein
+finding-chip-iso-required = { $chip }: Es werden nur Chips nach ISO 11784/11785 akzeptiert, ein zweiter ISO-Chip muss implantiert werden
+finding-chip-reader = { $chip }: Bringen Sie ein passendes Lesegerät mit, an der Grenze ist eventuell keines vorhanden
+finding-chip-invalid = { $number } ist keine gültige Chipnummer: { $error }
+finding-chip-implanted-missing = Geben Sie das Datum der Implantation des Mikrochips ein
+finding-vaccination-missing = Tragen Sie die Tollwutimpfung ein, sie muss nach der Implantation des Chips erfolgen
+finding-vaccination-after-chip = Die Tollwutimpfung vom { $vaccination } erfolgte nach der Implantation des Chips am { $implanted }
+finding-vaccination-before-chip = Alle Tollwutimpfungen erfolgten vor der Implantation des Chips am { $implanted }, das Tier muss erneut geimpft werden
+
+finding-titer-not-needed = Bei Einreise aus Ihrem Herkunftsland ist kein Titertest nötig
+finding-titer-missing = Ein Tollwut-Antikörpertiter von mindestens { $min } IE/ml ist vorgeschrieben, die Blutprobe muss mindestens { $days } Tage vor der Einreise entnommen werden
+finding-titer-below = Ein Titer von { $value } IE/ml liegt unter den geforderten { $min } IE/ml
+finding-titer-meets = Ein Titer von { $value } IE/ml erfüllt die geforderten { $min } IE/ml
+finding-lab-missing = Wählen Sie das Labor, das die Probe untersucht hat, es muss durch { $approval } zugelassen sein
+finding-lab-not-approved = { $laboratory } ist nicht durch { $approval } zugelassen, der Test muss in einem zugelassenen Labor wiederholt werden
+finding-lab-method = { $laboratory } bietet keine anerkannte Testmethode an ({ $methods })
+finding-lab-approved = { $laboratory } ist ein zugelassenes Labor
+finding-arrival-missing = Geben Sie das Ankunftsdatum ein: Die Einreise ist ab dem { $date } möglich
+finding-wait-pending = Die Wartezeit von { $days } Tagen nach der Blutprobe endet am { $date }, also nach der geplanten Ankunft
+finding-wait-over = Die Wartezeit von { $days } Tagen endete am { $date }
+finding-titer-expired = Das Testergebnis ist am { $date } abgelaufen
+finding-titer-valid = Das Testergebnis ist bis zum { $date } gültig
@@ -0,0 +1,362 @@
+## Formats, chrono strftime patterns
+
+date-format = %d %b %Y
+date-time-format = %a %d %b %Y, %H:%M %Z
+list-or-separator = {" or "}
+
+## Main window
+
+language = Language
+menu-button = Pet and trip
+view-list = List view
+map-label = World map. Use the arrow keys to move between countries and Enter to open one.
+map-edge = There are no more countries in this direction
+map-country-prohibited = { $country }, your pet can not enter
+map-country-matched = { $country }, matches your filters
+map-country-changed = { $country }, requirements changed since the chosen date
+map-date = Rules in effect on
+map-date-today = Today
+map-date-changed = { $count ->
+        [one] { $count } country has changed its requirements since { $date }
+       *[other] { $count } countries have changed their requirements since { $date }
+    }
+map-date-cleared = Showing the current requirements
+print-page = Print
+
+## Pet profile
+
+profile-title = Pet profile
+profile-name = Name
+profile-species = Species
+profile-breed = Breed
+profile-birth-date = Date of birth
+profile-weight = Weight, kg
+profile-microchip = Microchip number
+profile-microchip-implanted = Microchip implanted
+profile-vaccination = Rabies vaccination
+profile-add-vaccination = Add rabies vaccination
+profile-titer-date = Titer test sample date
+profile-titer-value = Titer, IU/ml
+profile-laboratory = Laboratory
+profile-laboratory-none = Not selected
+
+species-dog = Dog
+species-cat = Cat
+species-ferret = Ferret
+
+## Trip
+
+trip-title = Trip
+trip-from = From: { $country }
+trip-via = Via: { $countries }
+trip-to = To: { $country }
+trip-pick-country = pick a country on the map
+trip-departure = Departure
+trip-airline = Airline
+trip-airline-none = Not chosen yet
+
+transport-mode-cabin = cabin
+transport-mode-hold = hold
+transport-mode-cargo = cargo
+
+## Travel crate
+
+crate-title = Travel crate
+crate-length = Length, nose to tail root (A)
+crate-elbow-height = Floor to elbow (B)
+crate-width = Width across shoulders (C)
+crate-height = Floor to ear tips (D)
+crate-dimensions = { $length } × { $width } × { $height } cm
+crate-minimum = Minimum internal size: { $size }
+crate-series = Series { $series } crate, external { $size }
+crate-custom = Larger than series 700, a custom crate is needed
+crate-airline-accepts = { $airline } accepts this crate in: { $modes }
+crate-airline-rejects = { $airline } does not accept a series { $series } crate
+
+## Country view
+
+country-fly-from = Fly from here
+country-fly-to = Fly here
+country-stop-over = Stop over
+country-watch = Watch for changes
+country-view-close = Close
+country-view-opened = Entry requirements for { $country }
+country-view-closed = Back to the map
+
+breed-blocked = { $breed } can not enter { $country }
+breed-banned = { $breed } is banned in { $place }
+breed-restricted = { $breed } is restricted in { $place }
+
+carriers-title = Airlines from { $country }
+carriers-none = No airline in our database accepts your pet on this route.
+
+requirements-title = Entry requirements
+requirements-loading = Loading entry requirements
+requirements-failed = Entry requirements could not be loaded: { $reason }
+requirements-missing = We do not have entry requirements for this country yet.
+requirements-saved = Offline: showing requirements saved on { $date }
+requirements-bundled = Offline: showing requirements bundled with the app
+
+microchip-title = Microchip
+microchip-iso-required = An ISO 11784/11785 microchip is required.
+microchip-iso-expected = An ISO 11784/11785 microchip is expected, other chips are accepted if you bring a reader.
+microchip-before-vaccination = It has to be implanted before the rabies vaccination.
+
+titer-title = Rabies antibody titer test
+titer-summary = At least { $min } IU/ml by { $methods }, blood sample taken at least { $days } days before arrival at a laboratory approved by { $approval }.
+
+tapeworm-title = Tapeworm treatment for { $country }
+tapeworm-rule = Dogs must be treated against Echinococcus multilocularis by a vet no less than 24 and no more than 120 hours before arrival.
+tapeworm-arrival = Scheduled arrival
+tapeworm-arrival-time-zone = Arrival time zone
+tapeworm-vet-time-zone = Vet time zone
+tapeworm-window = Visit the vet between { $earliest } and { $latest }, and make sure the vet records both the date and the time of the treatment.
+tapeworm-nonexistent-time = This local time does not exist in the arrival time zone because of a clock change.
+tapeworm-untimed = Only the arrival time at the final destination is known, plan the vet visit from your arrival time in { $country }.
+
+steps-title = Steps for your pet
+steps-may-apply = May apply, depending on details not entered yet
+step-entry-refused = Your pet is not allowed to enter.
+step-minimum-age = The pet has to be at least { $weeks } weeks old on arrival.
+step-microchip = Have a microchip implanted.
+step-microchip-iso = Have an ISO 11784/11785 microchip implanted.
+step-rabies-vaccination = Vaccinate against rabies from { $weeks } weeks of age, { $doses ->
+        [one] { $doses } dose
+       *[other] { $doses } doses
+    }, the last at least { $days } days before arrival.
+step-titer-test = Take a rabies antibody titer test with at least { $min } IU/ml, blood sample at least { $days } days before arrival.
+step-tapeworm-treatment = Have a vet treat the dog against tapeworm 24 to 120 hours before arrival.
+step-health-certificate = Get a health certificate signed by a vet no more than { $days } days before arrival.
+step-pet-passport = Bring the EU pet passport.
+step-import-permit = Apply for an import permit.
+step-advance-notice = Notify the border authorities at least { $days } days before arrival.
+step-transit-declaration = Sign a declaration that the pet stayed in the airport or on board during stopovers.
+step-quarantine = Quarantine for { $days } days after arrival.
+step-quarantine-none = No quarantine on arrival.
+
+steps-not-required = Not required for your pet
+
+explanation-toggle = Why?
+explanation-applies = This applies to your pet.
+explanation-may-apply = This may apply, some details are not entered yet.
+explanation-not-met = This does not apply to your pet.
+explanation-met = met
+explanation-failed = not met
+explanation-unknown = not known yet
+explanation-source = Source:
+explanation-verified = Last verified on { $date }
+explanation-no-source = No source is recorded for this rule.
+
+condition-always = Applies to every pet
+condition-all = All of these
+condition-any = Any of these
+condition-not = Not the case that
+condition-origin-in = Travelling from { $countries }
+condition-origin-in-bloc = Travelling from a member of the { $bloc }
+condition-origin-rabies-status = Rabies status of the origin is { $status }
+condition-titer-exempt-origin = { $country } waives the titer test for the origin
+condition-species = Species is { $species }
+condition-age-under-weeks = Younger than { $weeks } weeks on arrival
+condition-vaccinated = A rabies vaccination is on record
+condition-transit = The route has stopovers
+condition-transit-through = A stopover in { $countries }
+
+freshness-verified = Verified on { $date } by { $verifier }
+freshness-unverified = Not verified yet
+freshness-stale = These requirements were last verified { $days } days ago and may have changed since. Check the official sources before you travel.
+
+## Requirement history
+
+history-title = Change history
+history-loading = Loading the change history
+history-failed = The change history could not be loaded: { $reason }
+history-none = No changes to these requirements are recorded.
+history-show = Show every change
+history-effective = In effect from { $date }
+history-since = Changed since { $date }
+history-unchanged-since = Unchanged since { $date }
+history-as-of = Showing the requirements in effect on { $date }
+history-field = Requirement
+history-before = Before
+history-after = After
+history-yes = Yes
+history-no = No
+history-not-set = Not set
+history-field-import_permit = Import permit
+history-field-microchip-before_vaccination = Microchip before the vaccination
+history-field-microchip-iso_required = ISO microchip
+history-field-quarantine_days = Quarantine, days
+history-field-rabies_status = Rabies status
+history-field-titer-exempt_origins = No titer test from
+history-field-titer-lab_approval = Laboratory approved by
+history-field-titer-methods = Titer test methods
+history-field-titer-min_iu_per_ml = Minimum titer, IU/ml
+history-field-titer-validity_days = Titer result valid for, days
+history-field-titer-wait_days = Days from titer test to arrival
+
+## Watchlist
+
+watchlist-title = Watchlist
+watchlist-empty = Watch a country or your trip to be told when its requirements change.
+watchlist-watch-trip = Watch this trip
+watchlist-trip = { $countries }
+watchlist-trip-departure = { $countries }, departing { $date }
+watchlist-remove = Stop watching { $country }
+watchlist-alerts = Also show browser notifications
+watchlist-alerts-blocked = This browser does not allow notifications from petabroad.io.
+watch-notice-title = { $count ->
+        [one] Requirements changed for a country you watch
+       *[other] Requirements changed for { $count } countries you watch
+    }
+watch-notice-trip = On your trip { $countries }
+watch-notice-open = Open { $country }
+watch-notice-dismiss = Got it
+
+## Checklist export
+
+checklist-title = Pet travel checklist: { $country }
+checklist-generated = Made on { $date } with petabroad.io. Check the official sources before you travel.
+checklist-pet = Pet
+checklist-origin = From
+checklist-stopovers = Via
+checklist-destination = To
+checklist-arrival = Arrival
+checklist-steps = Steps
+checklist-no-steps = No entry rules are recorded for this country yet.
+checklist-done = Done
+checklist-step = Step
+checklist-due = When
+checklist-due-by = By { $date }
+checklist-due-from = Not before { $date }
+checklist-source = Source
+checklist-verified = verified on { $date }
+checklist-findings = Documents checked
+checklist-status-satisfied = OK
+checklist-status-pending = To do
+checklist-status-violated = Problem
+checklist-sources = Official sources
+checklist-export-title = Take it with you
+checklist-export-print = Printable checklist
+checklist-export-markdown = Download as Markdown
+
+## Certificates
+
+owner-title = Owner
+owner-hint = Printed on certificates and permit applications. Only kept in this browser.
+owner-name = Full name
+owner-address = Address
+owner-phone = Telephone
+owner-email = Email
+certificate-export-title = Certificates and permits
+certificate-export-hint = Pre-filled from your pet, your trip and the owner details, ready to take to the vet.
+certificate-form = Official form
+certificate-prepared = Prepared on { $date } with petabroad.io for entering { $country }. This copy helps you fill in the official form, only the official veterinarian or the authority can issue it.
+certificate-progress = { $count } of { $total } fields filled in
+certificate-blanks = Still to fill in by hand:
+certificate-complete = Everything you can fill in is done.
+certificate-blank = To fill in
+certificate-veterinarian = For the official veterinarian
+
+## Backup
+
+backup-title = Backup
+backup-hint = Your pet, owner details, trip, watchlist and settings are only kept in this browser. Save them to a file to keep them safe or to move them to another device.
+backup-export = Save to a file
+backup-import = Restore from a file
+backup-found = Backup of { $date }
+backup-merge = Merge with the current data
+backup-replace = Replace the current data
+backup-cancel = Cancel
+backup-merged = The backup was merged with the current data.
+backup-replaced = The backup replaced the current data.
+backup-kept-pet = Your current pet was kept, the backup has a different one.
+backup-kept-owner = Your current owner details were kept, the backup has different ones.
+backup-kept-trip = Your current trip was kept, the backup has a different one.
+backup-error-read = The file could not be read.
+backup-error-malformed = This file is not a petabroad backup.
+backup-error-newer = This backup was made by a newer version of petabroad. Update the app to restore it.
+backup-error-invalid = This backup refers to data this version of petabroad does not know:
+backup-problem-country = country code { $code }
+backup-problem-airline = airline { $airline }
+backup-problem-laboratory = laboratory { $laboratory }
+backup-problem-locale = language { $code }
+
+## Filters
+
+filter-title = Where can we go?
+filter-no-quarantine = No quarantine
+filter-no-titer-test = No titer test
+filter-accepts-breed = Accepts my breed
+filter-cabin-from-origin = Reachable in the cabin from home
+filter-eu-pet-passport = EU pet passport valid
+filter-needs-origin = Set where you fly from first
+filter-matches = { $count ->
+        [one] { $count } country matches
+       *[other] { $count } countries match
+    }
+filter-clear = Clear filters
+filter-cleared = Filters cleared, all countries shown
+
+## Country list
+
+table-caption = { $count ->
+        [one] { $count } country
+       *[other] { $count } countries
+    }
+table-filter = Filter countries
+table-country = Country
+table-quarantine = Quarantine
+table-titer = Titer test
+table-permit = Import permit
+table-rabies = Rabies status
+table-yes = Required
+table-no = Not required
+table-unknown = No data
+table-quarantine-none = None
+table-quarantine-days = { $days ->
+        [one] { $days } day
+       *[other] { $days } days
+    }
+
+rabies-free = Rabies-free
+rabies-controlled = Controlled
+rabies-high-incidence = High incidence
+
+## Microchip descriptions and parse errors
+
+chip-iso-country = ISO 11784/11785 chip, country code { $code } ({ $country })
+chip-iso-country-unknown = ISO 11784/11785 chip, country code { $code }
+chip-iso-manufacturer = ISO 11784/11785 chip, manufacturer code { $code } ({ $manufacturer })
+chip-iso-manufacturer-unknown = ISO 11784/11785 chip, manufacturer code { $code }
+chip-avid = 9-digit AVID chip, not ISO compliant
+chip-fecava = 10-character FECAVA chip, not ISO compliant
+
+chip-error-empty = the chip number is empty
+chip-error-character = '{ $character }' is not allowed
+chip-error-length = { $length } characters do not match ISO (15), AVID (9) or FECAVA (10) formats
+chip-error-reserved = code { $code } is reserved and never issued
+
+## Compliance findings
+
+finding-chip-number-missing = Enter the microchip number
+finding-chip-iso-required = { $chip }: only ISO 11784/11785 chips are accepted, a second ISO chip has to be implanted
+finding-chip-reader = { $chip }: bring a reader able to scan it, border officials may not have one
+finding-chip-invalid = { $number } is not a valid chip number: { $error }
+finding-chip-implanted-missing = Enter the date the microchip was implanted
+finding-vaccination-missing = Record the rabies vaccination, it has to be given after the chip is implanted
+finding-vaccination-after-chip = Rabies vaccination on { $vaccination } was given after the chip was implanted on { $implanted }
+finding-vaccination-before-chip = All rabies vaccinations were given before the chip was implanted on { $implanted }, the pet has to be vaccinated again
+
+finding-titer-not-needed = No titer test is needed when travelling from your origin
+finding-titer-missing = A rabies antibody titer of at least { $min } IU/ml is required, with the blood sample taken at least { $days } days before arrival
+finding-titer-below = A titer of { $value } IU/ml is below the required { $min } IU/ml
+finding-titer-meets = A titer of { $value } IU/ml meets the required { $min } IU/ml
+finding-lab-missing = Select the laboratory which tested the sample, it has to be approved by { $approval }
+finding-lab-not-approved = { $laboratory } is not approved by { $approval }, the test has to be repeated at an approved laboratory
+finding-lab-method = { $laboratory } does not perform an accepted test method ({ $methods })
+finding-lab-approved = { $laboratory } is an approved laboratory
+finding-arrival-missing = Set the arrival date: entry is allowed from { $date }
+finding-wait-pending = The { $days } day waiting period after the blood sample ends on { $date }, after the planned arrival
+finding-wait-over = The { $days } day waiting period ended on { $date }
+finding-titer-expired = The test result expired on { $date }
+finding-titer-valid = The test result is valid until { $date }
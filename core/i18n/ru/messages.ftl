@@ -0,0 +1,420 @@
+## Formats, chrono strftime patterns
+
+date-format = %d.%m.%Y
+date-time-format = %d.%m.%Y, %H:%M %Z
+list-or-separator = {" или "}
+
+## Main window
+
+language = Язык
+menu-button = Питомец и поездка
+view-list = Списком
+map-label = Карта мира. Стрелки переходят между странами, Enter открывает выбранную.
+map-edge = В этом направлении стран больше нет
+map-country-prohibited = { $country }: въезд для вашего питомца запрещён
+map-country-matched = { $country }: подходит под фильтры
+map-country-changed = { $country }, требования изменились после выбранной даты
+map-date = Правила на дату
+map-date-today = Сегодня
+map-date-changed = { $count ->
+        [one] { $count } страна изменила требования после { $date }
+        [few] { $count } страны изменили требования после { $date }
+       *[many] { $count } стран изменили требования после { $date }
+    }
+map-date-cleared = Показаны текущие требования
+print-page = Печать
+
+## Pet profile
+
+profile-title = Профиль питомца
+profile-name = Кличка
+profile-species = Вид
+profile-breed = Порода
+profile-birth-date = Дата рождения
+profile-weight = Вес, кг
+profile-microchip = Номер микрочипа
+profile-microchip-implanted = Дата чипирования
+profile-vaccination = Прививка от бешенства
+profile-add-vaccination = Добавить прививку от бешенства
+profile-titer-date = Дата забора крови на титр
+profile-titer-value = Титр, МЕ/мл
+profile-laboratory = Лаборатория
+profile-laboratory-none = Не выбрана
+
+species-dog = Собака
+species-cat = Кошка
+species-ferret = Хорёк
+
+## Trip
+
+trip-title = Поездка
+trip-from = Откуда: { $country }
+trip-via = Через: { $countries }
+trip-to = Куда: { $country }
+trip-pick-country = выберите страну на карте
+trip-departure = Вылет
+trip-airline = Авиакомпания
+trip-airline-none = Ещё не выбрана
+
+transport-mode-cabin = салон
+transport-mode-hold = багажный отсек
+transport-mode-cargo = карго
+
+## Travel crate
+
+crate-title = Переноска
+crate-length = Длина от носа до основания хвоста (A)
+crate-elbow-height = От пола до локтя (B)
+crate-width = Ширина в плечах (C)
+crate-height = От пола до кончиков ушей (D)
+crate-dimensions = { $length } × { $width } × { $height } см
+crate-minimum = Минимальный внутренний размер: { $size }
+crate-series = Переноска серии { $series }, внешний размер { $size }
+crate-custom = Больше серии 700, нужна переноска на заказ
+crate-airline-accepts = { $airline } принимает такую переноску: { $modes }
+crate-airline-rejects = { $airline } не принимает переноски серии { $series }
+
+## Country view
+
+country-fly-from = Лететь отсюда
+country-fly-to = Лететь сюда
+country-stop-over = Пересадка
+country-watch = Следить за изменениями
+country-view-close = Закрыть
+country-view-opened = Условия въезда: { $country }
+country-view-closed = Снова на карте
+
+breed-blocked = { $breed }: въезд в страну { $country } запрещён
+breed-banned = { $breed }: порода запрещена в регионе { $place }
+breed-restricted = { $breed }: порода ограничена в регионе { $place }
+
+carriers-title = Авиакомпании из страны { $country }
+carriers-none = Ни одна авиакомпания из нашей базы не перевозит вашего питомца по этому маршруту.
+
+requirements-title = Условия въезда
+requirements-loading = Загружаем условия въезда
+requirements-failed = Не удалось загрузить условия въезда: { $reason }
+requirements-missing = У нас пока нет условий въезда для этой страны.
+requirements-saved = Нет сети: показаны условия, сохранённые { $date }
+requirements-bundled = Нет сети: показаны условия, встроенные в приложение
+
+microchip-title = Микрочип
+microchip-iso-required = Обязателен микрочип стандарта ISO 11784/11785.
+microchip-iso-expected = Ожидается микрочип стандарта ISO 11784/11785, другие чипы принимают, если у вас есть свой сканер.
+microchip-before-vaccination = Чип должен быть установлен до прививки от бешенства.
+
+titer-title = Тест на антитела к бешенству
+titer-summary = Не менее { $min } МЕ/мл методом { $methods }, кровь сдана не позднее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда в лабораторию, одобренную { $approval }.
+
+tapeworm-title = Обработка от ленточных червей для страны { $country }
+tapeworm-rule = Собак должен обработать от Echinococcus multilocularis ветеринар не раньше чем за 120 и не позже чем за 24 часа до въезда.
+tapeworm-arrival = Запланированный прилёт
+tapeworm-arrival-time-zone = Часовой пояс прилёта
+tapeworm-vet-time-zone = Часовой пояс ветеринара
+tapeworm-window = Посетите ветеринара с { $earliest } до { $latest } и убедитесь, что он записал и дату, и время обработки.
+tapeworm-nonexistent-time = Такого местного времени в часовом поясе прилёта нет из-за перевода часов.
+tapeworm-untimed = Известно только время прилёта в конечный пункт, планируйте визит к ветеринару по времени прибытия в страну { $country }.
+
+steps-title = Шаги для вашего питомца
+steps-may-apply = Может потребоваться, зависит от ещё не указанных данных
+step-entry-refused = Въезд вашего питомца запрещён.
+step-minimum-age = На момент въезда питомцу должно быть не менее { $weeks } { $weeks ->
+        [one] недели
+       *[other] недель
+    }.
+step-microchip = Установите микрочип.
+step-microchip-iso = Установите микрочип стандарта ISO 11784/11785.
+step-rabies-vaccination = Сделайте прививку от бешенства в возрасте от { $weeks } { $weeks ->
+        [one] недели
+       *[other] недель
+    }, { $doses } { $doses ->
+        [one] доза
+        [few] дозы
+       *[many] доз
+    }, последняя не позднее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда.
+step-titer-test = Сдайте тест на антитела к бешенству, не менее { $min } МЕ/мл, кровь не позднее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда.
+step-tapeworm-treatment = Обработайте собаку от ленточных червей у ветеринара за 24–120 часов до въезда.
+step-health-certificate = Получите ветеринарный сертификат, подписанный не ранее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда.
+step-pet-passport = Возьмите ветеринарный паспорт ЕС.
+step-import-permit = Оформите разрешение на ввоз.
+step-advance-notice = Уведомите пограничные службы не позднее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда.
+step-transit-declaration = Подпишите декларацию о том, что при пересадках питомец оставался в аэропорту или на борту.
+step-quarantine = Карантин { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } после въезда.
+step-quarantine-none = Карантина при въезде нет.
+
+steps-not-required = Не требуется для вашего питомца
+
+explanation-toggle = Почему?
+explanation-applies = Относится к вашему питомцу.
+explanation-may-apply = Может относиться, не все данные указаны.
+explanation-not-met = Не относится к вашему питомцу.
+explanation-met = выполнено
+explanation-failed = не выполнено
+explanation-unknown = пока неизвестно
+explanation-source = Источник:
+explanation-verified = Последняя проверка: { $date }
+explanation-no-source = Для этого правила источник не указан.
+
+condition-always = Относится к любому питомцу
+condition-all = Все условия
+condition-any = Любое из условий
+condition-not = Неверно, что
+condition-origin-in = Поездка из страны: { $countries }
+condition-origin-in-bloc = Поездка из страны — члена { $bloc }
+condition-origin-rabies-status = Статус страны отправления по бешенству: { $status }
+condition-titer-exempt-origin = { $country } не требует теста на антитела для страны отправления
+condition-species = Вид: { $species }
+condition-age-under-weeks = Младше { $weeks } { $weeks ->
+        [one] недели
+       *[other] недель
+    } на момент въезда
+condition-vaccinated = Есть запись о прививке от бешенства
+condition-transit = В маршруте есть пересадки
+condition-transit-through = Пересадка в стране: { $countries }
+
+freshness-verified = Проверено { $date }, проверил: { $verifier }
+freshness-unverified = Ещё не проверено
+freshness-stale = Эти условия последний раз проверялись { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } назад и могли измениться. Перед поездкой сверьтесь с официальными источниками.
+
+## Requirement history
+
+history-title = История изменений
+history-loading = Загружаем историю изменений
+history-failed = Не удалось загрузить историю изменений: { $reason }
+history-none = Изменения этих требований не записаны.
+history-show = Показать все изменения
+history-effective = Действует с { $date }
+history-since = Изменилось после { $date }
+history-unchanged-since = Без изменений после { $date }
+history-as-of = Показаны требования, действовавшие на { $date }
+history-field = Требование
+history-before = Было
+history-after = Стало
+history-yes = Да
+history-no = Нет
+history-not-set = Не указано
+history-field-import_permit = Разрешение на ввоз
+history-field-microchip-before_vaccination = Микрочип до вакцинации
+history-field-microchip-iso_required = Микрочип ISO
+history-field-quarantine_days = Карантин, дней
+history-field-rabies_status = Статус по бешенству
+history-field-titer-exempt_origins = Тест на титр не нужен из
+history-field-titer-lab_approval = Лаборатория одобрена
+history-field-titer-methods = Методы теста на титр
+history-field-titer-min_iu_per_ml = Минимальный титр, МЕ/мл
+history-field-titer-validity_days = Результат теста действителен, дней
+history-field-titer-wait_days = Дней от теста на титр до прибытия
+
+## Watchlist
+
+watchlist-title = Список наблюдения
+watchlist-empty = Следите за страной или поездкой, чтобы узнать об изменении требований.
+watchlist-watch-trip = Следить за этой поездкой
+watchlist-trip = { $countries }
+watchlist-trip-departure = { $countries }, отправление { $date }
+watchlist-remove = Не следить за { $country }
+watchlist-alerts = Показывать уведомления браузера
+watchlist-alerts-blocked = Этот браузер не разрешает уведомления от petabroad.io.
+watch-notice-title = { $count ->
+        [one] Изменились требования { $count } страны из списка наблюдения
+       *[other] Изменились требования { $count } стран из списка наблюдения
+    }
+watch-notice-trip = В поездке { $countries }
+watch-notice-open = Открыть { $country }
+watch-notice-dismiss = Понятно
+
+## Checklist export
+
+checklist-title = Чек-лист поездки с питомцем: { $country }
+checklist-generated = Составлено { $date } на petabroad.io. Перед поездкой сверьтесь с официальными источниками.
+checklist-pet = Питомец
+checklist-origin = Откуда
+checklist-stopovers = Через
+checklist-destination = Куда
+checklist-arrival = Прибытие
+checklist-steps = Шаги
+checklist-no-steps = Правила въезда в эту страну ещё не внесены.
+checklist-done = Готово
+checklist-step = Шаг
+checklist-due = Когда
+checklist-due-by = До { $date }
+checklist-due-from = Не раньше { $date }
+checklist-source = Источник
+checklist-verified = проверено { $date }
+checklist-findings = Проверка документов
+checklist-status-satisfied = В порядке
+checklist-status-pending = Сделать
+checklist-status-violated = Проблема
+checklist-sources = Официальные источники
+checklist-export-title = Взять с собой
+checklist-export-print = Чек-лист для печати
+checklist-export-markdown = Скачать в Markdown
+
+## Certificates
+
+owner-title = Владелец
+owner-hint = Печатается в сертификатах и заявлениях на разрешение ввоза. Хранится только в этом браузере.
+owner-name = Полное имя
+owner-address = Адрес
+owner-phone = Телефон
+owner-email = Эл. почта
+certificate-export-title = Сертификаты и разрешения
+certificate-export-hint = Заполнены данными питомца, поездки и владельца, их можно взять к ветеринару.
+certificate-form = Официальная форма
+certificate-prepared = Подготовлено { $date } в petabroad.io для въезда в { $country }. Эта копия помогает заполнить официальную форму, выдать её может только официальный ветеринар или ведомство.
+certificate-progress = Заполнено полей: { $count } из { $total }
+certificate-blanks = Осталось заполнить от руки:
+certificate-complete = Всё, что вы можете заполнить, заполнено.
+certificate-blank = Заполнить
+certificate-veterinarian = Заполняет официальный ветеринар
+
+## Backup
+
+backup-title = Резервная копия
+backup-hint = Данные питомца и владельца, поездка, список наблюдения и настройки хранятся только в этом браузере. Сохраните их в файл, чтобы не потерять или перенести на другое устройство.
+backup-export = Сохранить в файл
+backup-import = Восстановить из файла
+backup-found = Резервная копия от { $date }
+backup-merge = Объединить с текущими данными
+backup-replace = Заменить текущие данные
+backup-cancel = Отмена
+backup-merged = Резервная копия объединена с текущими данными.
+backup-replaced = Резервная копия заменила текущие данные.
+backup-kept-pet = Текущий питомец сохранён, в резервной копии другой.
+backup-kept-owner = Текущие данные владельца сохранены, в резервной копии другие.
+backup-kept-trip = Текущая поездка сохранена, в резервной копии другая.
+backup-error-read = Не удалось прочитать файл.
+backup-error-malformed = Этот файл не является резервной копией petabroad.
+backup-error-newer = Эта резервная копия создана более новой версией petabroad. Обновите приложение, чтобы восстановить её.
+backup-error-invalid = Резервная копия ссылается на данные, неизвестные этой версии petabroad:
+backup-problem-country = код страны { $code }
+backup-problem-airline = авиакомпания { $airline }
+backup-problem-laboratory = лаборатория { $laboratory }
+backup-problem-locale = язык { $code }
+
+## Filters
+
+filter-title = Куда можно поехать?
+filter-no-quarantine = Без карантина
+filter-no-titer-test = Без теста на антитела
+filter-accepts-breed = Пускают мою породу
+filter-cabin-from-origin = Долететь в салоне из дома
+filter-eu-pet-passport = Действует паспорт ЕС
+filter-needs-origin = Сначала укажите, откуда вы летите
+filter-matches = { $count ->
+        [one] Подходит { $count } страна
+        [few] Подходят { $count } страны
+       *[many] Подходят { $count } стран
+    }
+filter-clear = Сбросить фильтры
+filter-cleared = Фильтры сброшены, показаны все страны
+
+## Country list
+
+table-caption = { $count } { $count ->
+        [one] страна
+        [few] страны
+       *[many] стран
+    }
+table-filter = Поиск страны
+table-country = Страна
+table-quarantine = Карантин
+table-titer = Тест на антитела
+table-permit = Разрешение на ввоз
+table-rabies = Бешенство
+table-yes = Нужен
+table-no = Не нужен
+table-unknown = Нет данных
+table-quarantine-none = Нет
+table-quarantine-days = { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    }
+
+rabies-free = Благополучна
+rabies-controlled = Под контролем
+rabies-high-incidence = Высокая заболеваемость
+
+## Microchip descriptions and parse errors
+
+chip-iso-country = Чип ISO 11784/11785, код страны { $code } ({ $country })
+chip-iso-country-unknown = Чип ISO 11784/11785, код страны { $code }
+chip-iso-manufacturer = Чип ISO 11784/11785, код производителя { $code } ({ $manufacturer })
+chip-iso-manufacturer-unknown = Чип ISO 11784/11785, код производителя { $code }
+chip-avid = 9-значный чип AVID, не соответствует ISO
+chip-fecava = 10-значный чип FECAVA, не соответствует ISO
+
+chip-error-empty = номер чипа пуст
+chip-error-character = символ '{ $character }' недопустим
+chip-error-length = { $length } { $length ->
+        [one] символ не соответствует
+        [few] символа не соответствуют
+       *[many] символов не соответствуют
+    } форматам ISO (15), AVID (9) или FECAVA (10)
+chip-error-reserved = код { $code } зарезервирован и никогда не выдаётся
+
+## Compliance findings
+
+finding-chip-number-missing = Введите номер микрочипа
+finding-chip-iso-required = { $chip }: принимаются только чипы ISO 11784/11785, нужно установить второй чип ISO
+finding-chip-reader = { $chip }: возьмите сканер, способный его прочитать, на границе его может не оказаться
+finding-chip-invalid = { $number } — недопустимый номер чипа: { $error }
+finding-chip-implanted-missing = Введите дату чипирования
+finding-vaccination-missing = Добавьте прививку от бешенства, её нужно сделать после чипирования
+finding-vaccination-after-chip = Прививка от бешенства { $vaccination } сделана после чипирования { $implanted }
+finding-vaccination-before-chip = Все прививки от бешенства сделаны до чипирования { $implanted }, питомца нужно привить заново
+
+finding-titer-not-needed = При въезде из вашей страны тест на антитела не нужен
+finding-titer-missing = Нужен титр антител к бешенству не менее { $min } МЕ/мл, кровь сдаётся не позднее чем за { $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    } до въезда
+finding-titer-below = Титр { $value } МЕ/мл ниже требуемых { $min } МЕ/мл
+finding-titer-meets = Титр { $value } МЕ/мл соответствует требуемым { $min } МЕ/мл
+finding-lab-missing = Выберите лабораторию, проводившую тест, она должна быть одобрена { $approval }
+finding-lab-not-approved = { $laboratory } не одобрена { $approval }, тест нужно повторить в одобренной лаборатории
+finding-lab-method = { $laboratory } не проводит тест признанным методом ({ $methods })
+finding-lab-approved = { $laboratory } — одобренная лаборатория
+finding-arrival-missing = Укажите дату прилёта: въезд возможен с { $date }
+finding-wait-pending = Срок ожидания после забора крови ({ $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    }) заканчивается { $date }, после запланированного прилёта
+finding-wait-over = Срок ожидания ({ $days } { $days ->
+        [one] день
+        [few] дня
+       *[many] дней
+    }) закончился { $date }
+finding-titer-expired = Срок действия результата теста истёк { $date }
+finding-titer-valid = Результат теста действителен до { $date }
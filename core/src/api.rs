@@ -0,0 +1,152 @@
+//! Types exchanged with the backend over the `/api/v1` endpoints
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::data::airlines::TransportMode;
+use crate::data::breeds::BreedRestriction;
+use crate::data::history::{get_history, Revision};
+use crate::data::requirements::{
+    get_requirements_database, CountryRequirements, RequirementsDatabase, Source,
+};
+use crate::map_data::get_countries_names;
+
+pub const API_VERSION: &str = "v1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Country {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequirementsResponse {
+    pub country: Country,
+    pub bloc: Option<Country>,
+    /// `null` when there is no data on the country
+    pub requirements: Option<CountryRequirements>,
+    pub sources: Vec<Source>,
+}
+
+impl RequirementsResponse {
+    /// Built from the datasets compiled into this crate, `None` for unknown countries
+    pub fn from_snapshot(country_id: &str) -> Option<RequirementsResponse> {
+        RequirementsResponse::from_database(country_id, get_requirements_database())
+    }
+
+    /// Requirements in effect on `date` after the compiled-in history
+    pub fn from_snapshot_on(country_id: &str, date: NaiveDate) -> Option<RequirementsResponse> {
+        let db = get_history().database_on(get_requirements_database(), date);
+        RequirementsResponse::from_database(country_id, &db)
+    }
+
+    fn from_database(country_id: &str, db: &RequirementsDatabase) -> Option<RequirementsResponse> {
+        let name = get_countries_names().get(country_id)?.to_string();
+        let bloc = db
+            .blocs()
+            .find(|(_, b)| b.members.iter().any(|m| m == country_id));
+        Some(RequirementsResponse {
+            country: Country {
+                id: country_id.to_string(),
+                name,
+            },
+            bloc: bloc.map(|(id, b)| Country {
+                id: id.to_string(),
+                name: b.name.clone(),
+            }),
+            requirements: db.for_country(country_id),
+            sources: db.sources_for(country_id).into_iter().cloned().collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryResponse {
+    pub country: Country,
+    /// The latest change first, empty when the requirements never changed
+    /// as far as the dataset knows
+    pub revisions: Vec<Revision>,
+}
+
+impl HistoryResponse {
+    /// Built from the datasets compiled into this crate, `None` for unknown countries
+    pub fn from_snapshot(country_id: &str) -> Option<HistoryResponse> {
+        let name = get_countries_names().get(country_id)?.to_string();
+        let db = get_requirements_database();
+        let bloc_id = db
+            .blocs()
+            .find(|(_, b)| b.members.iter().any(|m| m == country_id))
+            .map(|(id, _)| id);
+        Some(HistoryResponse {
+            country: Country {
+                id: country_id.to_string(),
+                name,
+            },
+            revisions: get_history().timeline(
+                country_id,
+                bloc_id,
+                db.record(country_id),
+                bloc_id.and_then(|id| db.record(id)),
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Carrier {
+    pub iata: String,
+    pub name: String,
+    /// Best transport mode first
+    pub modes: Vec<TransportMode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TripResponse {
+    pub from: Country,
+    pub to: RequirementsResponse,
+    pub breed_restrictions: Vec<BreedRestriction>,
+    pub carriers: Vec<Carrier>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_of_bloc_member() {
+        let response = RequirementsResponse::from_snapshot("FR").unwrap();
+        assert_eq!(response.country.name, "France");
+        assert_eq!(response.bloc.unwrap().id, "EU");
+        assert!(response.requirements.unwrap().titer.is_some());
+        assert!(!response.sources.is_empty());
+    }
+
+    #[test]
+    fn snapshot_on_past_date() {
+        let date = NaiveDate::from_ymd_opt(2005, 1, 1).unwrap();
+        let then = RequirementsResponse::from_snapshot_on("GB", date).unwrap();
+        assert_eq!(then.requirements.unwrap().titer.unwrap().wait_days, 180);
+        let now = RequirementsResponse::from_snapshot("GB").unwrap();
+        assert_eq!(now.requirements.unwrap().titer.unwrap().wait_days, 90);
+    }
+
+    #[test]
+    fn history_snapshot() {
+        let history = HistoryResponse::from_snapshot("GB").unwrap();
+        assert_eq!(history.revisions.len(), 2);
+        assert!(HistoryResponse::from_snapshot("FR")
+            .unwrap()
+            .revisions
+            .is_empty());
+        assert!(HistoryResponse::from_snapshot("XX").is_none());
+    }
+
+    #[test]
+    fn snapshot_of_unknown_country() {
+        assert!(RequirementsResponse::from_snapshot("XX").is_none());
+        assert!(RequirementsResponse::from_snapshot("BR")
+            .unwrap()
+            .requirements
+            .is_none());
+    }
+}
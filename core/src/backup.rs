@@ -0,0 +1,430 @@
+//! Backup of everything the app keeps in the browser: the pet and its owner,
+//! the trip, the watchlist and the preferences, to keep it safe or move it to
+//! another device. A backup is the session with a format marker and the day
+//! it was exported, it carries the session's schema version and goes through
+//! the same migrations. It is checked against the bundled data before it
+//! touches the current state, which it either replaces or is merged into.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+use crate::data::airlines::get_airline_database;
+use crate::data::labs::find_laboratory;
+use crate::i18n::{Locale, Message};
+use crate::map_data::get_countries_names;
+use crate::session::{Session, SessionError, SESSION_VERSION};
+
+/// Tells a backup from any other JSON file
+pub const BACKUP_FORMAT: &str = "petabroad-backup";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Document {
+    format: String,
+    version: u32,
+    exported: NaiveDate,
+    state: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backup {
+    pub exported: NaiveDate,
+    pub session: Session,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Adds what the backup has to the current state, the current pet, owner
+    /// and trip are kept where the backup has different ones
+    Merge,
+    Replace,
+}
+
+/// Part of the state the backup disagreed on, the current one was kept
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kept {
+    Pet,
+    Owner,
+    Trip,
+}
+
+impl Kept {
+    pub fn message(&self) -> Message {
+        match self {
+            Kept::Pet => Message::new("backup-kept-pet"),
+            Kept::Owner => Message::new("backup-kept-owner"),
+            Kept::Trip => Message::new("backup-kept-trip"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub session: Session,
+    pub kept: Vec<Kept>,
+}
+
+/// Reference to data this build does not have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    UnknownCountry(String),
+    UnknownAirline(String),
+    UnknownLaboratory(String),
+    UnknownLocale(String),
+}
+
+impl Problem {
+    pub fn message(&self) -> Message {
+        match self {
+            Problem::UnknownCountry(id) => {
+                Message::new("backup-problem-country").arg("code", id.as_str())
+            }
+            Problem::UnknownAirline(iata) => {
+                Message::new("backup-problem-airline").arg("airline", iata.as_str())
+            }
+            Problem::UnknownLaboratory(id) => {
+                Message::new("backup-problem-laboratory").arg("laboratory", id.as_str())
+            }
+            Problem::UnknownLocale(id) => {
+                Message::new("backup-problem-locale").arg("code", id.as_str())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BackupError {
+    /// Not JSON, or JSON of something else than a backup
+    NotABackup,
+    /// The state could not be read or migrated
+    Session(SessionError),
+    Invalid(Vec<Problem>),
+}
+
+impl BackupError {
+    pub fn message(&self) -> Message {
+        match self {
+            BackupError::Session(SessionError::Unsupported(_)) => {
+                Message::new("backup-error-newer")
+            }
+            BackupError::NotABackup | BackupError::Session(_) => {
+                Message::new("backup-error-malformed")
+            }
+            BackupError::Invalid(_) => Message::new("backup-error-invalid"),
+        }
+    }
+}
+
+impl fmt::Display for BackupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackupError::NotABackup => write!(f, "not a petabroad backup"),
+            BackupError::Session(e) => write!(f, "{}", e),
+            BackupError::Invalid(problems) => {
+                let problems: Vec<String> = problems.iter().map(|p| format!("{:?}", p)).collect();
+                write!(f, "invalid backup: {}", problems.join(", "))
+            }
+        }
+    }
+}
+
+/// Every reference of `session` to countries, airlines, laboratories and
+/// languages which the bundled data lacks, each once
+fn problems(session: &Session) -> Vec<Problem> {
+    let names = get_countries_names();
+    let trip = &session.trip;
+    let watchlist = &session.watchlist;
+    let countries = session
+        .selected_country
+        .iter()
+        .chain(&trip.origin)
+        .chain(&trip.stopovers)
+        .chain(&trip.destination)
+        .chain(&watchlist.countries)
+        .chain(
+            watchlist
+                .trips
+                .iter()
+                .flat_map(|t| t.origin.iter().chain(&t.stopovers).chain(&t.destination)),
+        )
+        .chain(watchlist.seen.keys())
+        .filter(|id| !names.contains_key(id.as_str()))
+        .map(|id| Problem::UnknownCountry(id.clone()));
+    let airlines = trip
+        .airline
+        .iter()
+        .chain(watchlist.trips.iter().flat_map(|t| &t.airline))
+        .filter(|iata| get_airline_database().find(iata).is_none())
+        .map(|iata| Problem::UnknownAirline(iata.clone()));
+    let laboratory = session
+        .profile
+        .titer
+        .as_ref()
+        .and_then(|titer| titer.laboratory.clone())
+        .filter(|id| find_laboratory(id).is_none())
+        .map(Problem::UnknownLaboratory);
+    let locale = session
+        .locale
+        .clone()
+        .filter(|id| Locale::from_id(id).is_none())
+        .map(Problem::UnknownLocale);
+
+    let mut problems = vec![];
+    for problem in countries.chain(airlines).chain(laboratory).chain(locale) {
+        if !problems.contains(&problem) {
+            problems.push(problem);
+        }
+    }
+    problems
+}
+
+/// The current value unless it is still empty, a different imported value
+/// is reported as a conflict
+fn keep<T: Default + PartialEq>(current: T, imported: T, kept: Kept, all: &mut Vec<Kept>) -> T {
+    if current == T::default() {
+        imported
+    } else {
+        if imported != T::default() && imported != current {
+            all.push(kept);
+        }
+        current
+    }
+}
+
+impl Backup {
+    pub fn new(session: Session, today: NaiveDate) -> Backup {
+        Backup {
+            exported: today,
+            session,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let document = Document {
+            format: BACKUP_FORMAT.to_string(),
+            version: SESSION_VERSION,
+            exported: self.exported,
+            state: serde_json::to_value(&self.session).expect("Session is always serializable"),
+        };
+        serde_json::to_string_pretty(&document).expect("Session is always serializable")
+    }
+
+    /// Reads a backup of this or an older build and checks it refers to
+    /// nothing but known data
+    pub fn from_json(json: &str) -> Result<Backup, BackupError> {
+        let document: Document = serde_json::from_str(json).map_err(|_| BackupError::NotABackup)?;
+        if document.format != BACKUP_FORMAT {
+            return Err(BackupError::NotABackup);
+        }
+        let session =
+            Session::upgrade(document.state, document.version).map_err(BackupError::Session)?;
+        let problems = problems(&session);
+        if !problems.is_empty() {
+            return Err(BackupError::Invalid(problems));
+        }
+        Ok(Backup {
+            exported: document.exported,
+            session,
+        })
+    }
+
+    /// The state after the import. A merge keeps the view and the
+    /// preferences of this device and adds the watched countries, trips and
+    /// filters of the backup to the current ones.
+    pub fn apply(self, current: &Session, mode: ImportMode) -> Import {
+        let imported = self.session;
+        if mode == ImportMode::Replace {
+            return Import {
+                session: imported,
+                kept: vec![],
+            };
+        }
+        let mut kept = vec![];
+        let mut session = current.clone();
+        session.profile = keep(session.profile, imported.profile, Kept::Pet, &mut kept);
+        session.owner = keep(session.owner, imported.owner, Kept::Owner, &mut kept);
+        session.trip = keep(session.trip, imported.trip, Kept::Trip, &mut kept);
+        session.locale = session.locale.or(imported.locale);
+        for criterion in imported.criteria {
+            if !session.criteria.contains(&criterion) {
+                session.criteria.push(criterion);
+            }
+        }
+        let watchlist = &mut session.watchlist;
+        for id in imported.watchlist.countries {
+            if !watchlist.watches_country(&id) {
+                watchlist.countries.push(id);
+            }
+        }
+        for trip in imported.watchlist.trips {
+            if !watchlist.watches_trip(&trip) {
+                watchlist.trips.push(trip);
+            }
+        }
+        for (id, seen) in imported.watchlist.seen {
+            watchlist.seen.entry(id).or_insert(seen);
+        }
+        Import { session, kept }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pet::owner::Owner;
+    use crate::pet::profile::{PetProfile, Species};
+    use crate::travel::destination_filter::Criterion;
+    use crate::travel::trip::Trip;
+    use serde_json::json;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+    }
+
+    fn trip(origin: &str, destination: &str) -> Trip {
+        Trip {
+            origin: Some(origin.to_string()),
+            destination: Some(destination.to_string()),
+            ..Trip::default()
+        }
+    }
+
+    fn session() -> Session {
+        let mut session = Session {
+            profile: PetProfile {
+                name: "Rex".to_string(),
+                species: Species::Dog,
+                ..PetProfile::default()
+            },
+            trip: trip("DE", "JP"),
+            criteria: vec![Criterion::NoQuarantine],
+            locale: Some("de".to_string()),
+            ..Session::default()
+        };
+        session.watchlist.toggle_country("JP");
+        session
+    }
+
+    #[test]
+    fn round_trips() {
+        let backup = Backup::new(session(), today());
+        let json = backup.to_json();
+        let document: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(document["format"], json!("petabroad-backup"));
+        assert_eq!(document["version"], json!(SESSION_VERSION));
+        assert_eq!(document["exported"], json!("2026-10-19"));
+        assert_eq!(Backup::from_json(&json).unwrap(), backup);
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        for json in [
+            "not json",
+            "{}",
+            r#"{"version":1,"state":{}}"#,
+            r#"{"format":"other","version":1,"exported":"2026-10-19","state":{}}"#,
+        ] {
+            assert!(
+                matches!(Backup::from_json(json), Err(BackupError::NotABackup)),
+                "{}",
+                json
+            );
+        }
+        let newer = json!({
+            "format": BACKUP_FORMAT,
+            "version": SESSION_VERSION + 1,
+            "exported": "2030-01-01",
+            "state": {}
+        });
+        let error = Backup::from_json(&newer.to_string()).unwrap_err();
+        assert_eq!(error.message(), Message::new("backup-error-newer"));
+        let malformed = json!({
+            "format": BACKUP_FORMAT,
+            "version": 1,
+            "exported": "2026-10-19",
+            "state": { "criteria": ["everywhere"] }
+        });
+        assert!(matches!(
+            Backup::from_json(&malformed.to_string()),
+            Err(BackupError::Session(SessionError::Malformed(_)))
+        ));
+    }
+
+    #[test]
+    fn reports_unknown_references() {
+        let mut session = session();
+        session.trip = Trip {
+            airline: Some("Q9".to_string()),
+            ..trip("DE", "QQ")
+        };
+        session.watchlist.toggle_trip(&trip("QQ", "JP"));
+        session.locale = Some("xx".to_string());
+        let json = Backup::new(session, today()).to_json();
+        match Backup::from_json(&json) {
+            Err(BackupError::Invalid(problems)) => assert_eq!(
+                problems,
+                vec![
+                    Problem::UnknownCountry("QQ".to_string()),
+                    Problem::UnknownAirline("Q9".to_string()),
+                    Problem::UnknownLocale("xx".to_string()),
+                ]
+            ),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn replace_takes_the_backup_as_is() {
+        let current = Session {
+            list_view: true,
+            ..session()
+        };
+        let imported = Session {
+            trip: trip("FR", "AU"),
+            ..Session::default()
+        };
+        let import = Backup::new(imported.clone(), today()).apply(&current, ImportMode::Replace);
+        assert_eq!(import.session, imported);
+        assert!(import.kept.is_empty());
+    }
+
+    #[test]
+    fn merge_keeps_current_data_and_adds_the_rest() {
+        let current = session();
+        let mut imported = Session {
+            profile: PetProfile {
+                name: "Tom".to_string(),
+                species: Species::Cat,
+                ..PetProfile::default()
+            },
+            owner: Owner {
+                name: "Anna Schmidt".to_string(),
+                ..Owner::default()
+            },
+            trip: trip("DE", "JP"),
+            criteria: vec![Criterion::NoTiterTest, Criterion::NoQuarantine],
+            locale: Some("en".to_string()),
+            list_view: true,
+            ..Session::default()
+        };
+        imported.watchlist.toggle_country("AU");
+        imported.watchlist.toggle_trip(&trip("FR", "NZ"));
+
+        let import = Backup::new(imported, today()).apply(&current, ImportMode::Merge);
+        let session = import.session;
+        assert_eq!(import.kept, vec![Kept::Pet]);
+        assert_eq!(session.profile.name, "Rex");
+        // filled in where the current state had nothing
+        assert_eq!(session.owner.name, "Anna Schmidt");
+        assert_eq!(session.trip, current.trip);
+        assert_eq!(session.locale.as_deref(), Some("de"));
+        assert!(!session.list_view);
+        assert_eq!(
+            session.criteria,
+            vec![Criterion::NoQuarantine, Criterion::NoTiterTest]
+        );
+        assert_eq!(session.watchlist.countries, vec!["JP", "AU"]);
+        assert_eq!(session.watchlist.trips, vec![trip("FR", "NZ")]);
+    }
+}
@@ -0,0 +1,355 @@
+//! Backend client independent of the HTTP stack and storage it runs on.
+//! Responses are revalidated with ETags and kept in a cache, so the last
+//! known data, or the compiled-in snapshot, is shown while offline.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::api::{HistoryResponse, RequirementsResponse, API_VERSION};
+
+pub const STATUS_OK: u16 = 200;
+pub const STATUS_NOT_MODIFIED: u16 = 304;
+pub const STATUS_NOT_FOUND: u16 = 404;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub etag: Option<String>,
+    pub body: String,
+}
+
+/// Performs GET requests, e.g. with `fetch` in the browser.
+/// An `Err` means the server could not be reached at all.
+#[allow(async_fn_in_trait)] // implementations are single threaded, no `Send` bound needed
+pub trait Transport {
+    async fn get(&self, url: &str, if_none_match: Option<&str>) -> Result<HttpResponse, String>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub etag: Option<String>,
+    pub body: String,
+    /// When the server last confirmed the body is current
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Persistent storage of the last response per URL
+#[allow(async_fn_in_trait)]
+pub trait ResponseCache {
+    async fn load(&self, url: &str) -> Option<CachedResponse>;
+    async fn store(&self, url: &str, response: &CachedResponse);
+}
+
+/// Where the returned data came from
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Origin {
+    /// Downloaded or revalidated just now
+    Server,
+    /// Server unreachable, last cached response
+    Cache,
+    /// Server unreachable and nothing cached, data compiled into the app
+    Snapshot,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fetched<T> {
+    pub data: T,
+    pub origin: Origin,
+    /// `None` for the snapshot
+    pub fetched_at: Option<DateTime<Utc>>,
+}
+
+impl<T> Fetched<T> {
+    pub fn is_stale(&self) -> bool {
+        self.origin != Origin::Server
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    NotFound,
+    /// Server unreachable and no cached or compiled-in data
+    Unavailable(String),
+    Malformed(serde_json::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NotFound => write!(f, "not found"),
+            FetchError::Unavailable(reason) => write!(f, "data is unavailable: {}", reason),
+            FetchError::Malformed(e) => write!(f, "malformed response: {}", e),
+        }
+    }
+}
+
+pub struct DataClient<T, C> {
+    base_url: String,
+    transport: T,
+    cache: C,
+}
+
+impl<T: Transport, C: ResponseCache> DataClient<T, C> {
+    /// `base_url` is the server root, without the API version
+    pub fn new(base_url: &str, transport: T, cache: C) -> DataClient<T, C> {
+        DataClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            transport,
+            cache,
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/api/{}{}", self.base_url, API_VERSION, path)
+    }
+
+    fn parse<R: DeserializeOwned>(body: &str) -> Result<R, FetchError> {
+        serde_json::from_str(body).map_err(FetchError::Malformed)
+    }
+
+    fn fallback<R: DeserializeOwned>(
+        cached: Option<CachedResponse>,
+        snapshot: Option<R>,
+        reason: String,
+    ) -> Result<Fetched<R>, FetchError> {
+        if let Some(cached) = cached {
+            if let Ok(data) = Self::parse(&cached.body) {
+                return Ok(Fetched {
+                    data,
+                    origin: Origin::Cache,
+                    fetched_at: Some(cached.fetched_at),
+                });
+            }
+        }
+        match snapshot {
+            Some(data) => Ok(Fetched {
+                data,
+                origin: Origin::Snapshot,
+                fetched_at: None,
+            }),
+            None => Err(FetchError::Unavailable(reason)),
+        }
+    }
+
+    /// GET `path` revalidating the cached copy, `snapshot` is only built when needed
+    pub async fn get_json<R: DeserializeOwned>(
+        &self,
+        path: &str,
+        snapshot: impl FnOnce() -> Option<R>,
+    ) -> Result<Fetched<R>, FetchError> {
+        let url = self.url(path);
+        let cached = self.cache.load(&url).await;
+        let etag = cached.as_ref().and_then(|c| c.etag.as_deref());
+        let response = match self.transport.get(&url, etag).await {
+            Ok(response) => response,
+            Err(reason) => return Self::fallback(cached, snapshot(), reason),
+        };
+        let fresh = match (response.status, &cached) {
+            (STATUS_OK, _) => CachedResponse {
+                etag: response.etag,
+                body: response.body,
+                fetched_at: Utc::now(),
+            },
+            (STATUS_NOT_MODIFIED, Some(cached)) => CachedResponse {
+                fetched_at: Utc::now(),
+                ..cached.clone()
+            },
+            (STATUS_NOT_FOUND, _) => return Err(FetchError::NotFound),
+            (status, _) => {
+                return Self::fallback(cached, snapshot(), format!("HTTP status {}", status))
+            }
+        };
+        // a broken body is no better than no answer at all
+        let data = match Self::parse(&fresh.body) {
+            Ok(data) => data,
+            Err(e) => return Self::fallback(cached, snapshot(), e.to_string()),
+        };
+        self.cache.store(&url, &fresh).await;
+        Ok(Fetched {
+            data,
+            origin: Origin::Server,
+            fetched_at: Some(fresh.fetched_at),
+        })
+    }
+
+    pub async fn requirements(
+        &self,
+        country_id: &str,
+    ) -> Result<Fetched<RequirementsResponse>, FetchError> {
+        self.get_json(&format!("/countries/{}/requirements", country_id), || {
+            RequirementsResponse::from_snapshot(country_id)
+        })
+        .await
+    }
+
+    /// Requirements in effect on a past `date`
+    pub async fn requirements_on(
+        &self,
+        country_id: &str,
+        date: NaiveDate,
+    ) -> Result<Fetched<RequirementsResponse>, FetchError> {
+        self.get_json(
+            &format!("/countries/{}/requirements?date={}", country_id, date),
+            || RequirementsResponse::from_snapshot_on(country_id, date),
+        )
+        .await
+    }
+
+    pub async fn history(&self, country_id: &str) -> Result<Fetched<HistoryResponse>, FetchError> {
+        self.get_json(&format!("/countries/{}/history", country_id), || {
+            HistoryResponse::from_snapshot(country_id)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// Serves a single body under a fixed ETag, or nothing when offline
+    struct FakeServer {
+        body: Option<&'static str>,
+        etag: &'static str,
+        requests: RefCell<Vec<Option<String>>>,
+    }
+
+    impl Transport for &FakeServer {
+        async fn get(
+            &self,
+            _url: &str,
+            if_none_match: Option<&str>,
+        ) -> Result<HttpResponse, String> {
+            self.requests
+                .borrow_mut()
+                .push(if_none_match.map(|e| e.to_string()));
+            let body = self.body.ok_or_else(|| "connection refused".to_string())?;
+            if if_none_match == Some(self.etag) {
+                return Ok(HttpResponse {
+                    status: STATUS_NOT_MODIFIED,
+                    etag: Some(self.etag.to_string()),
+                    body: String::new(),
+                });
+            }
+            Ok(HttpResponse {
+                status: STATUS_OK,
+                etag: Some(self.etag.to_string()),
+                body: body.to_string(),
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryCache(RefCell<HashMap<String, CachedResponse>>);
+
+    impl ResponseCache for &MemoryCache {
+        async fn load(&self, url: &str) -> Option<CachedResponse> {
+            self.0.borrow().get(url).cloned()
+        }
+
+        async fn store(&self, url: &str, response: &CachedResponse) {
+            self.0
+                .borrow_mut()
+                .insert(url.to_string(), response.clone());
+        }
+    }
+
+    fn server(body: Option<&'static str>) -> FakeServer {
+        FakeServer {
+            body,
+            etag: "\"v1\"",
+            requests: RefCell::new(vec![]),
+        }
+    }
+
+    #[test]
+    fn revalidates_cached_response_with_etag() {
+        let server = server(Some("[1, 2]"));
+        let cache = MemoryCache::default();
+        let client = DataClient::new("http://localhost/", &server, &cache);
+
+        let first = block_on(client.get_json::<Vec<u32>>("/numbers", || None)).unwrap();
+        assert_eq!(first.origin, Origin::Server);
+        assert_eq!(first.data, vec![1, 2]);
+
+        let second = block_on(client.get_json::<Vec<u32>>("/numbers", || None)).unwrap();
+        assert_eq!(second.origin, Origin::Server);
+        assert_eq!(second.data, vec![1, 2]);
+        assert_eq!(
+            *server.requests.borrow(),
+            vec![None, Some("\"v1\"".to_string())]
+        );
+        assert!(cache
+            .0
+            .borrow()
+            .contains_key("http://localhost/api/v1/numbers"));
+    }
+
+    #[test]
+    fn falls_back_to_cache_when_offline() {
+        let cache = MemoryCache::default();
+        let online = server(Some("[3]"));
+        block_on(
+            DataClient::new("http://localhost", &online, &cache)
+                .get_json::<Vec<u32>>("/n", || None),
+        )
+        .unwrap();
+
+        let offline = server(None);
+        let client = DataClient::new("http://localhost", &offline, &cache);
+        let fetched = block_on(client.get_json::<Vec<u32>>("/n", || Some(vec![0]))).unwrap();
+        assert_eq!(fetched.origin, Origin::Cache);
+        assert_eq!(fetched.data, vec![3]);
+        assert!(fetched.is_stale());
+    }
+
+    #[test]
+    fn falls_back_on_malformed_responses() {
+        let cache = MemoryCache::default();
+        let online = server(Some("[3]"));
+        block_on(
+            DataClient::new("http://localhost", &online, &cache)
+                .get_json::<Vec<u32>>("/n", || None),
+        )
+        .unwrap();
+
+        let broken = FakeServer {
+            body: Some("<html>Bad gateway</html>"),
+            etag: "\"v2\"",
+            ..server(None)
+        };
+        let client = DataClient::new("http://localhost", &broken, &cache);
+        let fetched = block_on(client.get_json::<Vec<u32>>("/n", || Some(vec![0]))).unwrap();
+        assert_eq!(fetched.origin, Origin::Cache);
+        assert_eq!(fetched.data, vec![3]);
+
+        let fetched = block_on(client.get_json::<Vec<u32>>("/other", || Some(vec![0]))).unwrap();
+        assert_eq!(fetched.origin, Origin::Snapshot);
+        assert_eq!(fetched.data, vec![0]);
+        assert!(matches!(
+            block_on(client.get_json::<Vec<u32>>("/other", || None)),
+            Err(FetchError::Unavailable(_))
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_snapshot_without_cache() {
+        let offline = server(None);
+        let cache = MemoryCache::default();
+        let client = DataClient::new("http://localhost", &offline, &cache);
+        let fetched = block_on(client.requirements("JP")).unwrap();
+        assert_eq!(fetched.origin, Origin::Snapshot);
+        assert_eq!(fetched.data.country.id, "JP");
+        assert!(fetched.fetched_at.is_none());
+
+        assert!(matches!(
+            block_on(client.get_json::<Vec<u32>>("/n", || None)),
+            Err(FetchError::Unavailable(_))
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+use chrono::NaiveDate;
+
+use super::Finding;
+use crate::data::requirements::MicrochipRequirement;
+use crate::i18n::Message;
+use crate::pet::microchip::parse_microchip;
+
+fn check_number(requirement: &MicrochipRequirement, number: &str) -> Finding {
+    if number.trim().is_empty() {
+        return Finding::pending(Message::new("finding-chip-number-missing"));
+    }
+    match parse_microchip(number) {
+        Ok(chip) if chip.is_iso() => Finding::satisfied(chip.message()),
+        Ok(chip) if requirement.iso_required => {
+            Finding::violated(Message::new("finding-chip-iso-required").arg("chip", chip.message()))
+        }
+        Ok(chip) => {
+            Finding::pending(Message::new("finding-chip-reader").arg("chip", chip.message()))
+        }
+        Err(e) => Finding::violated(
+            Message::new("finding-chip-invalid")
+                .arg("number", number)
+                .arg("error", e.message()),
+        ),
+    }
+}
+
+fn check_ordering(implanted: Option<NaiveDate>, vaccinations: &[NaiveDate]) -> Finding {
+    let implanted = match implanted {
+        Some(implanted) => implanted,
+        None => return Finding::pending(Message::new("finding-chip-implanted-missing")),
+    };
+    if vaccinations.is_empty() {
+        return Finding::pending(Message::new("finding-vaccination-missing"));
+    }
+    match vaccinations.iter().filter(|v| **v >= implanted).max() {
+        Some(vaccination) => Finding::satisfied(
+            Message::new("finding-vaccination-after-chip")
+                .arg("vaccination", *vaccination)
+                .arg("implanted", implanted),
+        ),
+        None => Finding::violated(
+            Message::new("finding-vaccination-before-chip").arg("implanted", implanted),
+        ),
+    }
+}
+
+/// Verifies the chip format and that it was implanted before the rabies vaccination
+pub fn check_microchip(
+    requirement: &MicrochipRequirement,
+    number: &str,
+    implanted: Option<NaiveDate>,
+    vaccinations: &[NaiveDate],
+) -> Vec<Finding> {
+    let mut findings = vec![check_number(requirement, number)];
+    if requirement.before_vaccination {
+        findings.push(check_ordering(implanted, vaccinations));
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compliance::Status;
+
+    const ISO_CHIP: &str = "276098106234567";
+    const AVID_CHIP: &str = "AVID*012*345*678";
+
+    fn requirement(iso_required: bool) -> MicrochipRequirement {
+        MicrochipRequirement {
+            iso_required,
+            before_vaccination: true,
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn statuses(findings: &[Finding]) -> Vec<Status> {
+        findings.iter().map(|f| f.status).collect()
+    }
+
+    #[test]
+    fn iso_chip_implanted_before_vaccination() {
+        let findings = check_microchip(
+            &requirement(true),
+            ISO_CHIP,
+            Some(date(2024, 3, 1)),
+            &[date(2024, 3, 1)],
+        );
+        assert_eq!(statuses(&findings), vec![Status::Satisfied; 2]);
+    }
+
+    #[test]
+    fn non_iso_chip_needs_own_reader() {
+        let findings = check_microchip(&requirement(false), AVID_CHIP, None, &[]);
+        assert_eq!(findings[0].status, Status::Pending);
+        assert!(findings[0].message.to_string().contains("reader"));
+    }
+
+    #[test]
+    fn non_iso_chip_refused_where_iso_is_required() {
+        let findings = check_microchip(&requirement(true), AVID_CHIP, None, &[]);
+        assert_eq!(findings[0].status, Status::Violated);
+    }
+
+    #[test]
+    fn invalid_number_is_violation() {
+        let findings = check_microchip(&requirement(false), "12345", None, &[]);
+        assert_eq!(findings[0].status, Status::Violated);
+    }
+
+    #[test]
+    fn vaccination_before_implant_does_not_count() {
+        let findings = check_microchip(
+            &requirement(true),
+            ISO_CHIP,
+            Some(date(2024, 3, 1)),
+            &[date(2023, 2, 1)],
+        );
+        assert_eq!(findings[1].status, Status::Violated);
+        let findings = check_microchip(
+            &requirement(true),
+            ISO_CHIP,
+            Some(date(2024, 3, 1)),
+            &[date(2023, 2, 1), date(2024, 4, 2)],
+        );
+        assert_eq!(findings[1].status, Status::Satisfied);
+        assert!(findings[1].message.to_string().contains("02 Apr 2024"));
+    }
+
+    #[test]
+    fn missing_dates_are_pending() {
+        let findings = check_microchip(&requirement(true), ISO_CHIP, None, &[date(2024, 1, 1)]);
+        assert_eq!(findings[1].status, Status::Pending);
+        let findings = check_microchip(&requirement(true), ISO_CHIP, Some(date(2024, 1, 1)), &[]);
+        assert_eq!(findings[1].status, Status::Pending);
+        let findings = check_microchip(&requirement(true), "", None, &[]);
+        assert_eq!(findings[0].status, Status::Pending);
+    }
+}
@@ -0,0 +1,75 @@
+pub mod microchip;
+pub mod titer;
+
+use crate::data::labs::find_laboratory;
+use crate::data::requirements::{MicrochipRequirement, TiterRequirement};
+use crate::i18n::Message;
+use crate::pet::profile::PetProfile;
+use crate::travel::trip::Trip;
+
+/// Ordered from best to worst so the overall status of a list is its maximum
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Status {
+    Satisfied,
+    /// Not enough information yet, or a step still to be taken
+    Pending,
+    Violated,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub status: Status,
+    pub message: Message,
+}
+
+impl Finding {
+    pub fn satisfied(message: Message) -> Finding {
+        Finding {
+            status: Status::Satisfied,
+            message,
+        }
+    }
+
+    pub fn pending(message: Message) -> Finding {
+        Finding {
+            status: Status::Pending,
+            message,
+        }
+    }
+
+    pub fn violated(message: Message) -> Finding {
+        Finding {
+            status: Status::Violated,
+            message,
+        }
+    }
+}
+
+/// Checks of the pet's chip and vaccinations against the destination's requirement
+pub fn microchip_findings(requirement: &MicrochipRequirement, pet: &PetProfile) -> Vec<Finding> {
+    microchip::check_microchip(
+        requirement,
+        &pet.microchip,
+        pet.microchip_implanted,
+        &pet.rabies_vaccinations,
+    )
+}
+
+/// Checks of the pet's titer result for the trip against the destination's requirement
+pub fn titer_findings(
+    requirement: &TiterRequirement,
+    pet: &PetProfile,
+    trip: &Trip,
+) -> Vec<Finding> {
+    let result = pet.titer.as_ref();
+    let laboratory = result
+        .and_then(|t| t.laboratory.as_deref())
+        .and_then(find_laboratory);
+    titer::check_titer(
+        requirement,
+        trip.origin.as_deref(),
+        result,
+        laboratory,
+        trip.arrival_date(),
+    )
+}
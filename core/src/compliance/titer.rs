@@ -0,0 +1,260 @@
+use chrono::{Duration, NaiveDate};
+
+use super::Finding;
+use crate::data::labs::Laboratory;
+use crate::data::requirements::TiterRequirement;
+use crate::i18n::Message;
+use crate::pet::profile::TiterResult;
+
+fn check_laboratory(requirement: &TiterRequirement, laboratory: Option<&Laboratory>) -> Finding {
+    let laboratory = match laboratory {
+        Some(laboratory) => laboratory,
+        None => {
+            return Finding::pending(
+                Message::new("finding-lab-missing")
+                    .arg("approval", requirement.lab_approval.as_str()),
+            )
+        }
+    };
+    if !laboratory.is_approved_by(&requirement.lab_approval) {
+        return Finding::violated(
+            Message::new("finding-lab-not-approved")
+                .arg("laboratory", laboratory.name.as_str())
+                .arg("approval", requirement.lab_approval.as_str()),
+        );
+    }
+    if !laboratory
+        .methods
+        .iter()
+        .any(|m| requirement.methods.contains(m))
+    {
+        let methods: Vec<String> = requirement.methods.iter().map(|m| m.to_string()).collect();
+        return Finding::violated(
+            Message::new("finding-lab-method")
+                .arg("laboratory", laboratory.name.as_str())
+                .arg("methods", methods.join(", ")),
+        );
+    }
+    Finding::satisfied(
+        Message::new("finding-lab-approved").arg("laboratory", laboratory.name.as_str()),
+    )
+}
+
+fn check_dates(
+    requirement: &TiterRequirement,
+    result: &TiterResult,
+    arrival: Option<NaiveDate>,
+) -> Vec<Finding> {
+    let earliest_entry = result.sample_date + Duration::days(requirement.wait_days as i64);
+    let expiry = requirement
+        .validity_days
+        .map(|days| result.sample_date + Duration::days(days as i64));
+    let arrival = match arrival {
+        Some(arrival) => arrival,
+        None => {
+            return vec![Finding::pending(
+                Message::new("finding-arrival-missing").arg("date", earliest_entry),
+            )]
+        }
+    };
+    let mut findings = vec![];
+    if arrival < earliest_entry {
+        findings.push(Finding::violated(
+            Message::new("finding-wait-pending")
+                .arg("days", requirement.wait_days)
+                .arg("date", earliest_entry),
+        ));
+    } else {
+        findings.push(Finding::satisfied(
+            Message::new("finding-wait-over")
+                .arg("days", requirement.wait_days)
+                .arg("date", earliest_entry),
+        ));
+    }
+    match expiry {
+        Some(expiry) if arrival > expiry => findings.push(Finding::violated(
+            Message::new("finding-titer-expired").arg("date", expiry),
+        )),
+        Some(expiry) => findings.push(Finding::satisfied(
+            Message::new("finding-titer-valid").arg("date", expiry),
+        )),
+        None => {}
+    }
+    findings
+}
+
+/// Verifies a user-entered titer result against the destination's requirement
+pub fn check_titer(
+    requirement: &TiterRequirement,
+    origin: Option<&str>,
+    result: Option<&TiterResult>,
+    laboratory: Option<&Laboratory>,
+    arrival: Option<NaiveDate>,
+) -> Vec<Finding> {
+    if !requirement.applies_to(origin) {
+        return vec![Finding::satisfied(Message::new("finding-titer-not-needed"))];
+    }
+    let result = match result {
+        Some(result) => result,
+        None => {
+            return vec![Finding::pending(
+                Message::new("finding-titer-missing")
+                    .arg("min", requirement.min_iu_per_ml)
+                    .arg("days", requirement.wait_days),
+            )]
+        }
+    };
+    let mut findings = vec![];
+    if result.iu_per_ml < requirement.min_iu_per_ml {
+        findings.push(Finding::violated(
+            Message::new("finding-titer-below")
+                .arg("value", result.iu_per_ml)
+                .arg("min", requirement.min_iu_per_ml),
+        ));
+    } else {
+        findings.push(Finding::satisfied(
+            Message::new("finding-titer-meets")
+                .arg("value", result.iu_per_ml)
+                .arg("min", requirement.min_iu_per_ml),
+        ));
+    }
+    findings.push(check_laboratory(requirement, laboratory));
+    findings.extend(check_dates(requirement, result, arrival));
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compliance::Status;
+    use crate::data::requirements::TiterMethod;
+
+    fn requirement() -> TiterRequirement {
+        TiterRequirement {
+            min_iu_per_ml: 0.5,
+            methods: vec![TiterMethod::Favn],
+            wait_days: 180,
+            validity_days: Some(730),
+            lab_approval: "JP".to_string(),
+            exempt_origins: vec!["AU".to_string()],
+        }
+    }
+
+    fn laboratory(approved_by: &str) -> Laboratory {
+        Laboratory {
+            id: "lab".to_string(),
+            name: "Test Lab".to_string(),
+            country: "DE".to_string(),
+            city: "Berlin".to_string(),
+            methods: vec![TiterMethod::Favn],
+            approved_by: vec![approved_by.to_string()],
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn result(iu_per_ml: f32) -> TiterResult {
+        TiterResult {
+            sample_date: date(2024, 1, 10),
+            iu_per_ml,
+            laboratory: Some("lab".to_string()),
+        }
+    }
+
+    fn statuses(findings: &[Finding]) -> Vec<Status> {
+        findings.iter().map(|f| f.status).collect()
+    }
+
+    #[test]
+    fn exempt_origin_needs_no_test() {
+        let findings = check_titer(&requirement(), Some("AU"), None, None, None);
+        assert_eq!(statuses(&findings), vec![Status::Satisfied]);
+    }
+
+    #[test]
+    fn missing_result_is_pending() {
+        let findings = check_titer(&requirement(), Some("DE"), None, None, None);
+        assert_eq!(statuses(&findings), vec![Status::Pending]);
+    }
+
+    #[test]
+    fn valid_result_after_waiting_period() {
+        let lab = laboratory("JP");
+        let findings = check_titer(
+            &requirement(),
+            Some("DE"),
+            Some(&result(0.8)),
+            Some(&lab),
+            Some(date(2024, 7, 8)),
+        );
+        assert_eq!(statuses(&findings), vec![Status::Satisfied; 4]);
+    }
+
+    #[test]
+    fn low_titer_is_violation() {
+        let lab = laboratory("JP");
+        let findings = check_titer(
+            &requirement(),
+            None,
+            Some(&result(0.3)),
+            Some(&lab),
+            Some(date(2024, 8, 1)),
+        );
+        assert_eq!(findings[0].status, Status::Violated);
+    }
+
+    #[test]
+    fn arrival_within_waiting_period_is_violation() {
+        let lab = laboratory("JP");
+        let findings = check_titer(
+            &requirement(),
+            Some("DE"),
+            Some(&result(0.8)),
+            Some(&lab),
+            Some(date(2024, 7, 7)),
+        );
+        assert_eq!(findings[2].status, Status::Violated);
+        assert!(findings[2].message.to_string().contains("08 Jul 2024"));
+    }
+
+    #[test]
+    fn expired_result_is_violation() {
+        let lab = laboratory("JP");
+        let findings = check_titer(
+            &requirement(),
+            Some("DE"),
+            Some(&result(0.8)),
+            Some(&lab),
+            Some(date(2026, 1, 10)),
+        );
+        assert_eq!(findings[3].status, Status::Violated);
+    }
+
+    #[test]
+    fn unapproved_laboratory_is_violation() {
+        let lab = laboratory("EU");
+        let findings = check_titer(
+            &requirement(),
+            Some("DE"),
+            Some(&result(0.8)),
+            Some(&lab),
+            Some(date(2024, 8, 1)),
+        );
+        assert_eq!(findings[1].status, Status::Violated);
+    }
+
+    #[test]
+    fn missing_arrival_date_is_pending() {
+        let lab = laboratory("JP");
+        let findings = check_titer(
+            &requirement(),
+            Some("DE"),
+            Some(&result(0.8)),
+            Some(&lab),
+            None,
+        );
+        assert_eq!(findings.last().unwrap().status, Status::Pending);
+    }
+}
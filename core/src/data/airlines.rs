@@ -0,0 +1,323 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::breeds::get_breed_registry;
+use crate::pet::profile::{PetProfile, Species};
+
+const AIRLINES_JSON: &str = include_str!("../../data/airlines.json");
+
+/// Ordered by preference: travelling with the owner beats travelling alone
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransportMode {
+    Cabin,
+    Hold,
+    Cargo,
+}
+
+impl TransportMode {
+    pub const ALL: [TransportMode; 3] = [
+        TransportMode::Cabin,
+        TransportMode::Hold,
+        TransportMode::Cargo,
+    ];
+}
+
+impl fmt::Display for TransportMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TransportMode::Cabin => "cabin",
+            TransportMode::Hold => "hold",
+            TransportMode::Cargo => "cargo",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModePolicy {
+    pub species: Vec<Species>,
+    /// Maximum weight of the animal itself
+    #[serde(default)]
+    pub max_weight_kg: Option<f32>,
+    /// Maximum external container dimensions, length x width x height
+    #[serde(default)]
+    pub max_container_cm: Option<[u32; 3]>,
+}
+
+impl ModePolicy {
+    fn accepts(&self, pet: &PetProfile) -> bool {
+        let weight_ok = match (self.max_weight_kg, pet.weight_kg) {
+            (Some(max), Some(weight)) => weight <= max,
+            _ => true,
+        };
+        self.species.contains(&pet.species) && weight_ok
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatEmbargo {
+    /// Calendar months (1-12) the embargo is in effect
+    pub months: Vec<u32>,
+    pub modes: Vec<TransportMode>,
+    #[serde(default)]
+    pub brachycephalic_only: bool,
+}
+
+impl HeatEmbargo {
+    fn applies(&self, mode: TransportMode, date: NaiveDate, brachycephalic: bool) -> bool {
+        self.modes.contains(&mode)
+            && self.months.contains(&date.month())
+            && (brachycephalic || !self.brachycephalic_only)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Airline {
+    pub iata: String,
+    pub name: String,
+    /// Countries in the carrier's network
+    pub countries: Vec<String>,
+    pub cabin: Option<ModePolicy>,
+    pub hold: Option<ModePolicy>,
+    pub cargo: Option<ModePolicy>,
+    /// Pets flying into or out of these countries are carried as manifest cargo only
+    #[serde(default)]
+    pub cargo_only_countries: Vec<String>,
+    #[serde(default)]
+    pub brachycephalic_embargo: Vec<TransportMode>,
+    #[serde(default)]
+    pub heat_embargo: Option<HeatEmbargo>,
+}
+
+impl Airline {
+    pub fn policy(&self, mode: TransportMode) -> Option<&ModePolicy> {
+        match mode {
+            TransportMode::Cabin => self.cabin.as_ref(),
+            TransportMode::Hold => self.hold.as_ref(),
+            TransportMode::Cargo => self.cargo.as_ref(),
+        }
+    }
+
+    pub fn serves(&self, from: &str, to: &str) -> bool {
+        from != to
+            && self.countries.iter().any(|c| c == from)
+            && self.countries.iter().any(|c| c == to)
+    }
+
+    fn cargo_only(&self, route: &RouteQuery) -> bool {
+        self.cargo_only_countries
+            .iter()
+            .any(|c| c == route.from || c == route.to)
+    }
+
+    /// Transport modes in which the airline would carry the pet on the route
+    pub fn accepted_modes(
+        &self,
+        route: &RouteQuery,
+        pet: &PetProfile,
+        brachycephalic: bool,
+    ) -> Vec<TransportMode> {
+        if !self.serves(route.from, route.to) {
+            return vec![];
+        }
+        let cargo_only = self.cargo_only(route);
+        TransportMode::ALL
+            .iter()
+            .copied()
+            .filter(|mode| !cargo_only || *mode == TransportMode::Cargo)
+            .filter(|mode| self.policy(*mode).map(|p| p.accepts(pet)).unwrap_or(false))
+            .filter(|mode| !(brachycephalic && self.brachycephalic_embargo.contains(mode)))
+            .filter(|mode| match (&self.heat_embargo, route.date) {
+                (Some(embargo), Some(date)) => !embargo.applies(*mode, date, brachycephalic),
+                _ => true,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteQuery<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    /// Travel date, used to check seasonal embargoes
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarrierOption<'a> {
+    pub airline: &'a Airline,
+    pub modes: Vec<TransportMode>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AirlineDatabase {
+    airlines: Vec<Airline>,
+}
+
+impl AirlineDatabase {
+    pub fn new(airlines: Vec<Airline>) -> AirlineDatabase {
+        AirlineDatabase { airlines }
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<AirlineDatabase> {
+        serde_json::from_str(json)
+    }
+
+    pub fn airlines(&self) -> &[Airline] {
+        &self.airlines
+    }
+
+    pub fn find(&self, iata: &str) -> Option<&Airline> {
+        self.airlines.iter().find(|a| a.iata == iata)
+    }
+
+    /// Carriers which would accept the pet on the route, best transport mode first
+    pub fn carriers_for(&self, route: &RouteQuery, pet: &PetProfile) -> Vec<CarrierOption<'_>> {
+        let brachycephalic = get_breed_registry().is_brachycephalic(pet);
+        let mut options: Vec<CarrierOption> = self
+            .airlines
+            .iter()
+            .map(|airline| CarrierOption {
+                airline,
+                modes: airline.accepted_modes(route, pet, brachycephalic),
+            })
+            .filter(|option| !option.modes.is_empty())
+            .collect();
+        options.sort_by(|a, b| {
+            a.modes[0]
+                .cmp(&b.modes[0])
+                .then(a.airline.name.cmp(&b.airline.name))
+        });
+        options
+    }
+}
+
+pub fn get_airline_database() -> &'static AirlineDatabase {
+    static DATABASE: OnceLock<AirlineDatabase> = OnceLock::new();
+    DATABASE.get_or_init(|| {
+        AirlineDatabase::from_json(AIRLINES_JSON)
+            .unwrap_or_else(|e| panic!("Malformed airlines dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_AIRLINES: &str = r#"{
+        "airlines": [
+            {
+                "iata": "XA", "name": "Cabin Air", "countries": ["DE", "FR", "GB"],
+                "cabin": { "species": ["dog", "cat"], "max_weight_kg": 8.0 },
+                "hold": { "species": ["dog"], "max_weight_kg": 50.0 },
+                "cargo": { "species": ["dog", "cat"] },
+                "cargo_only_countries": ["GB"],
+                "brachycephalic_embargo": ["hold"],
+                "heat_embargo": { "months": [6, 7, 8], "modes": ["cargo"] }
+            },
+            {
+                "iata": "XB", "name": "Cargo Air", "countries": ["DE", "US"],
+                "cabin": null, "hold": null,
+                "cargo": { "species": ["dog"] }
+            }
+        ]
+    }"#;
+
+    fn dog(breed: &str, weight_kg: f32) -> PetProfile {
+        PetProfile {
+            breed: breed.to_string(),
+            weight_kg: Some(weight_kg),
+            ..PetProfile::default()
+        }
+    }
+
+    fn route<'a>(from: &'a str, to: &'a str) -> RouteQuery<'a> {
+        RouteQuery {
+            from,
+            to,
+            date: None,
+        }
+    }
+
+    #[test]
+    fn small_dog_flies_in_cabin() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        let options = db.carriers_for(&route("DE", "FR"), &dog("Beagle", 5.0));
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].airline.iata, "XA");
+        assert_eq!(
+            options[0].modes,
+            vec![
+                TransportMode::Cabin,
+                TransportMode::Hold,
+                TransportMode::Cargo
+            ]
+        );
+    }
+
+    #[test]
+    fn heavy_dog_is_not_accepted_in_cabin() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        let options = db.carriers_for(&route("DE", "FR"), &dog("Beagle", 20.0));
+        assert_eq!(
+            options[0].modes,
+            vec![TransportMode::Hold, TransportMode::Cargo]
+        );
+    }
+
+    #[test]
+    fn cargo_only_countries_force_cargo() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        let options = db.carriers_for(&route("DE", "GB"), &dog("Beagle", 5.0));
+        assert_eq!(options[0].modes, vec![TransportMode::Cargo]);
+    }
+
+    #[test]
+    fn brachycephalic_embargo_excludes_mode() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        let options = db.carriers_for(&route("DE", "FR"), &dog("French Bulldog", 12.0));
+        assert_eq!(options[0].modes, vec![TransportMode::Cargo]);
+    }
+
+    #[test]
+    fn heat_embargo_applies_in_summer_only() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        let airline = &db.airlines[0];
+        let pet = dog("French Bulldog", 12.0);
+        let summer = RouteQuery {
+            date: NaiveDate::from_ymd_opt(2024, 7, 1),
+            ..route("DE", "FR")
+        };
+        let winter = RouteQuery {
+            date: NaiveDate::from_ymd_opt(2024, 1, 15),
+            ..route("DE", "FR")
+        };
+        assert!(airline.accepted_modes(&summer, &pet, true).is_empty());
+        assert_eq!(
+            airline.accepted_modes(&winter, &pet, true),
+            vec![TransportMode::Cargo]
+        );
+    }
+
+    #[test]
+    fn unserved_route_and_species_are_filtered_out() {
+        let db = AirlineDatabase::from_json(TEST_AIRLINES).unwrap();
+        assert!(db
+            .carriers_for(&route("FR", "US"), &dog("Beagle", 5.0))
+            .is_empty());
+        let cat = PetProfile {
+            species: Species::Cat,
+            ..PetProfile::default()
+        };
+        assert!(db.carriers_for(&route("DE", "US"), &cat).is_empty());
+    }
+
+    #[test]
+    fn bundled_dataset_is_valid() {
+        let db = get_airline_database();
+        assert!(db.airlines.iter().any(|a| a.iata == "LH"));
+    }
+}
@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::pet::profile::{PetProfile, Species};
+
+const BREED_RESTRICTIONS_JSON: &str = include_str!("../../data/breed_restrictions.json");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RestrictionLevel {
+    /// Import or keeping of the breed is prohibited
+    Banned,
+    /// The breed is allowed under extra conditions (licence, muzzle, insurance)
+    Restricted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Breed {
+    pub id: String,
+    pub name: String,
+    pub species: Species,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Short-nosed breeds many airlines refuse to carry in the hold
+    #[serde(default)]
+    pub brachycephalic: bool,
+}
+
+impl Breed {
+    fn matches(&self, name: &str) -> bool {
+        let name = normalize(name);
+        normalize(&self.name) == name || self.aliases.iter().any(|a| normalize(a) == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreedRestriction {
+    pub breed: String,
+    pub level: RestrictionLevel,
+    /// State, province or city the restriction is limited to; `None` means nationwide
+    #[serde(default)]
+    pub region: Option<String>,
+    pub note: String,
+}
+
+impl BreedRestriction {
+    /// Nationwide bans are the ones that block entry into the country altogether
+    pub fn is_blocking(&self) -> bool {
+        self.level == RestrictionLevel::Banned && self.region.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BreedRegistry {
+    breeds: Vec<Breed>,
+    countries: HashMap<String, Vec<BreedRestriction>>,
+}
+
+impl BreedRegistry {
+    pub fn from_json(json: &str) -> serde_json::Result<BreedRegistry> {
+        serde_json::from_str(json)
+    }
+
+    pub fn breeds(&self) -> &[Breed] {
+        &self.breeds
+    }
+
+    /// Restrictions keyed by country id, whatever breed they are about
+    pub fn countries(&self) -> impl Iterator<Item = (&str, &[BreedRestriction])> {
+        self.countries
+            .iter()
+            .map(|(id, list)| (id.as_str(), list.as_slice()))
+    }
+
+    pub fn find_breed(&self, species: Species, name: &str) -> Option<&Breed> {
+        self.breeds
+            .iter()
+            .find(|b| b.species == species && b.matches(name))
+    }
+
+    pub fn is_brachycephalic(&self, pet: &PetProfile) -> bool {
+        self.find_breed(pet.species, &pet.breed)
+            .map(|b| b.brachycephalic)
+            .unwrap_or(false)
+    }
+
+    /// Restrictions of the given country which apply to the pet's breed
+    pub fn restrictions_for(&self, country_id: &str, pet: &PetProfile) -> Vec<&BreedRestriction> {
+        let breed = match self.find_breed(pet.species, &pet.breed) {
+            Some(breed) => breed,
+            None => return vec![],
+        };
+        self.countries
+            .get(country_id)
+            .map(|list| list.iter().filter(|r| r.breed == breed.id).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn is_prohibited(&self, country_id: &str, pet: &PetProfile) -> bool {
+        self.restrictions_for(country_id, pet)
+            .iter()
+            .any(|r| r.is_blocking())
+    }
+
+    /// Ids of the countries the pet can not enter because of its breed
+    pub fn prohibited_countries(&self, pet: &PetProfile) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .countries
+            .keys()
+            .filter(|id| self.is_prohibited(id, pet))
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase().replace(['-', '_'], " ")
+}
+
+pub fn get_breed_registry() -> &'static BreedRegistry {
+    static REGISTRY: OnceLock<BreedRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        BreedRegistry::from_json(BREED_RESTRICTIONS_JSON)
+            .unwrap_or_else(|e| panic!("Malformed breed restrictions dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_REGISTRY: &str = r#"{
+        "breeds": [
+            { "id": "pit_bull_terrier", "name": "American Pit Bull Terrier", "species": "dog",
+              "aliases": ["pit bull", "pitbull"] },
+            { "id": "rottweiler", "name": "Rottweiler", "species": "dog", "aliases": ["rottie"] },
+            { "id": "french_bulldog", "name": "French Bulldog", "species": "dog",
+              "brachycephalic": true },
+            { "id": "savannah", "name": "Savannah", "species": "cat" }
+        ],
+        "countries": {
+            "XA": [
+                { "breed": "pit_bull_terrier", "level": "banned", "note": "Nationwide ban" },
+                { "breed": "rottweiler", "level": "restricted", "note": "Muzzle in public" }
+            ],
+            "XB": [
+                { "breed": "pit_bull_terrier", "level": "banned", "region": "Capital",
+                  "note": "Banned in the capital" }
+            ],
+            "XC": [
+                { "breed": "savannah", "level": "banned", "note": "Hybrid cats" }
+            ]
+        }
+    }"#;
+
+    fn test_registry() -> BreedRegistry {
+        serde_json::from_str(TEST_REGISTRY).unwrap()
+    }
+
+    fn pet(species: Species, breed: &str) -> PetProfile {
+        PetProfile {
+            species,
+            breed: breed.to_string(),
+            ..PetProfile::default()
+        }
+    }
+
+    #[test]
+    fn breed_is_found_by_name_or_alias_in_any_spelling() {
+        let registry = test_registry();
+        let id = |name: &str| {
+            registry
+                .find_breed(Species::Dog, name)
+                .map(|b| b.id.as_str())
+        };
+        assert_eq!(id("American Pit Bull Terrier"), Some("pit_bull_terrier"));
+        assert_eq!(id("  american-pit_bull terrier "), Some("pit_bull_terrier"));
+        assert_eq!(id("PITBULL"), Some("pit_bull_terrier"));
+        assert_eq!(id("Pit-Bull"), Some("pit_bull_terrier"));
+        assert_eq!(id("Rottie"), Some("rottweiler"));
+        assert_eq!(id("Beagle"), None);
+        assert_eq!(id(""), None);
+    }
+
+    #[test]
+    fn breed_of_another_species_is_not_found() {
+        let registry = test_registry();
+        assert!(registry.find_breed(Species::Cat, "pit bull").is_none());
+        assert!(registry.find_breed(Species::Dog, "Savannah").is_none());
+        assert!(registry.find_breed(Species::Cat, "Savannah").is_some());
+    }
+
+    #[test]
+    fn restrictions_are_those_of_the_pets_breed() {
+        let registry = test_registry();
+        let restrictions = registry.restrictions_for("XA", &pet(Species::Dog, "rottie"));
+        assert_eq!(restrictions.len(), 1);
+        assert_eq!(restrictions[0].level, RestrictionLevel::Restricted);
+        assert!(registry
+            .restrictions_for("XA", &pet(Species::Dog, "Mixed"))
+            .is_empty());
+        assert!(registry
+            .restrictions_for("ZZ", &pet(Species::Dog, "pit bull"))
+            .is_empty());
+    }
+
+    #[test]
+    fn only_nationwide_bans_prohibit_entry() {
+        let registry = test_registry();
+        let pit_bull = pet(Species::Dog, "pitbull");
+        assert!(registry.is_prohibited("XA", &pit_bull));
+        // a regional ban is a warning, the country can still be entered
+        assert!(!registry.is_prohibited("XB", &pit_bull));
+        assert_eq!(registry.restrictions_for("XB", &pit_bull).len(), 1);
+        assert!(!registry.is_prohibited("XA", &pet(Species::Dog, "Rottweiler")));
+    }
+
+    #[test]
+    fn prohibited_countries_are_sorted_ids() {
+        let registry = test_registry();
+        assert_eq!(
+            registry.prohibited_countries(&pet(Species::Dog, "Pit Bull")),
+            vec!["XA"]
+        );
+        assert_eq!(
+            registry.prohibited_countries(&pet(Species::Cat, "savannah")),
+            vec!["XC"]
+        );
+        assert!(registry
+            .prohibited_countries(&pet(Species::Dog, "Beagle"))
+            .is_empty());
+        assert!(registry
+            .prohibited_countries(&PetProfile::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn brachycephalic_breeds_are_flagged() {
+        let registry = test_registry();
+        assert!(registry.is_brachycephalic(&pet(Species::Dog, "french bulldog")));
+        assert!(!registry.is_brachycephalic(&pet(Species::Dog, "Rottweiler")));
+        assert!(!registry.is_brachycephalic(&pet(Species::Dog, "Beagle")));
+    }
+
+    #[test]
+    fn bundled_registry_is_consistent() {
+        let registry = get_breed_registry();
+        let ids: Vec<&str> = registry.breeds().iter().map(|b| b.id.as_str()).collect();
+        for (country, restrictions) in &registry.countries {
+            for restriction in restrictions {
+                assert!(
+                    ids.contains(&restriction.breed.as_str()),
+                    "{}: {}",
+                    country,
+                    restriction.breed
+                );
+            }
+        }
+        let pit_bull = pet(Species::Dog, "Pit Bull");
+        assert!(registry
+            .prohibited_countries(&pit_bull)
+            .contains(&"GB".to_string()));
+        assert!(!registry.is_prohibited("US", &pit_bull));
+    }
+}
@@ -0,0 +1,177 @@
+//! Official health certificates and import permit applications, described
+//! field by field so they can be pre-filled from the pet profile, the owner
+//! and the trip. Labels are copied from the official forms and stay in their
+//! language.
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use super::requirements::get_requirements_database;
+
+const CERTIFICATES_JSON: &str = include_str!("../../data/certificates.json");
+
+/// Where the value of a form field comes from
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldSource {
+    OwnerName,
+    OwnerAddress,
+    OwnerPhone,
+    OwnerEmail,
+    PetName,
+    Species,
+    Breed,
+    BirthDate,
+    Microchip,
+    MicrochipImplanted,
+    /// Every rabies vaccination
+    Vaccinations,
+    /// The most recent rabies vaccination
+    LastVaccination,
+    TiterSampleDate,
+    TiterValue,
+    TiterLaboratory,
+    Origin,
+    Destination,
+    Departure,
+    Arrival,
+    Airline,
+    /// Not kept by petabroad, e.g. the sex of the pet or the vaccine's batch
+    ByHand,
+    /// Completed by the official veterinarian who issues the certificate
+    Veterinarian,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateField {
+    pub label: String,
+    pub source: FieldSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateSection {
+    pub title: String,
+    pub fields: Vec<TemplateField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateTemplate {
+    pub id: String,
+    /// Short name for buttons and lists
+    pub name: String,
+    /// Title as printed on the official form
+    pub title: String,
+    /// Legal act or authority page the form is published in
+    pub reference: String,
+    pub url: String,
+    /// Country or bloc ids the form is needed to enter
+    pub destinations: Vec<String>,
+    /// Country or bloc ids whose pets travel on a pet passport instead, a
+    /// bloc also covers the countries accepting its passport
+    #[serde(default)]
+    pub passport_origins: Vec<String>,
+    pub sections: Vec<TemplateSection>,
+}
+
+impl CertificateTemplate {
+    /// Whether the form is needed to enter `destination`, from anywhere if
+    /// the origin is not known yet
+    pub fn applies_to(&self, destination: &str, origin: Option<&str>) -> bool {
+        let db = get_requirements_database();
+        let covers = |id: &String, country: &str| id == country || db.is_member(id, country);
+        if !self.destinations.iter().any(|id| covers(id, destination)) {
+            return false;
+        }
+        match origin {
+            Some(origin) => !self
+                .passport_origins
+                .iter()
+                .any(|id| id == origin || db.accepts_passport(id, origin)),
+            None => true,
+        }
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &TemplateField> {
+        self.sections.iter().flat_map(|section| &section.fields)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CertificateList {
+    certificates: Vec<CertificateTemplate>,
+}
+
+/// Templates listed in a document shaped like `certificates.json`
+pub fn templates_from_json(json: &str) -> serde_json::Result<Vec<CertificateTemplate>> {
+    serde_json::from_str::<CertificateList>(json).map(|list| list.certificates)
+}
+
+pub fn get_templates() -> &'static [CertificateTemplate] {
+    static TEMPLATES: OnceLock<Vec<CertificateTemplate>> = OnceLock::new();
+    TEMPLATES.get_or_init(|| {
+        templates_from_json(CERTIFICATES_JSON)
+            .unwrap_or_else(|e| panic!("Malformed certificates dataset: {}", e))
+    })
+}
+
+pub fn find_template(id: &str) -> Option<&'static CertificateTemplate> {
+    get_templates().iter().find(|t| t.id == id)
+}
+
+/// Forms to prepare for entering `destination` from `origin`
+pub fn templates_for(destination: &str, origin: Option<&str>) -> Vec<&'static CertificateTemplate> {
+    get_templates()
+        .iter()
+        .filter(|t| t.applies_to(destination, origin))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map_data::get_countries_names;
+
+    fn ids(templates: Vec<&CertificateTemplate>) -> Vec<&str> {
+        templates.iter().map(|t| t.id.as_str()).collect()
+    }
+
+    #[test]
+    fn destinations_are_countries_or_blocs() {
+        let names = get_countries_names();
+        let db = get_requirements_database();
+        let known =
+            |id: &String| names.contains_key(id.as_str()) || db.blocs().any(|(bloc, _)| bloc == id);
+        for template in get_templates() {
+            assert!(template.destinations.iter().all(known), "{}", template.id);
+            assert!(
+                template.passport_origins.iter().all(known),
+                "{}",
+                template.id
+            );
+            assert!(template.fields().count() > 0, "{}", template.id);
+        }
+    }
+
+    #[test]
+    fn passport_origins_need_no_certificate() {
+        assert_eq!(ids(templates_for("FR", Some("US"))), vec!["eu-annex-iv"]);
+        assert_eq!(ids(templates_for("FR", Some("GB"))), vec!["eu-annex-iv"]);
+        assert!(templates_for("FR", Some("DE")).is_empty());
+        // Switzerland is not a member but accepts the EU pet passport
+        assert!(templates_for("FR", Some("CH")).is_empty());
+        assert!(templates_for("GB", Some("FR")).is_empty());
+        assert_eq!(ids(templates_for("GB", Some("US"))), vec!["gb-ahc"]);
+        assert_eq!(ids(templates_for("FR", None)), vec!["eu-annex-iv"]);
+    }
+
+    #[test]
+    fn import_permits_apply_from_anywhere() {
+        assert_eq!(
+            ids(templates_for("AU", Some("DE"))),
+            vec!["au-import-permit"]
+        );
+        assert_eq!(ids(templates_for("NZ", None)), vec!["nz-import-permit"]);
+        assert!(templates_for("JP", Some("DE")).is_empty());
+        assert!(find_template("sg-import-licence").is_some());
+    }
+}
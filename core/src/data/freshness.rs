@@ -0,0 +1,230 @@
+//! How long ago the requirement records were checked against their sources,
+//! per record and across the whole dataset.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::requirements::{CountryRequirements, RequirementsDatabase, Verification};
+
+/// Records checked longer ago than this are flagged unless configured otherwise
+pub const DEFAULT_STALE_AFTER_DAYS: u32 = 180;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Freshness {
+    /// Never verified, ordered first as the most doubtful
+    Unverified,
+    Stale,
+    Fresh,
+}
+
+impl Freshness {
+    pub fn of(
+        verified: Option<&Verification>,
+        today: NaiveDate,
+        stale_after_days: u32,
+    ) -> Freshness {
+        match verified {
+            None => Freshness::Unverified,
+            Some(v) if (today - v.date).num_days() > stale_after_days as i64 => Freshness::Stale,
+            Some(_) => Freshness::Fresh,
+        }
+    }
+}
+
+/// Days since the verification, zero for dates in the future
+pub fn age_days(verified: &Verification, today: NaiveDate) -> u32 {
+    (today - verified.date).num_days().max(0) as u32
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FreshnessEntry {
+    /// Country or bloc id
+    pub id: String,
+    pub verified: Option<Verification>,
+    pub age_days: Option<u32>,
+    /// Number of cited sources, records without any can not be re-checked
+    pub sources: usize,
+    pub freshness: Freshness,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FreshnessReport {
+    pub today: NaiveDate,
+    pub stale_after_days: u32,
+    /// Most doubtful first: unverified, then stale and fresh records, the
+    /// oldest first within each
+    pub entries: Vec<FreshnessEntry>,
+}
+
+impl FreshnessReport {
+    /// Report over records given as id, record and number of sources
+    pub fn new<'a>(
+        records: impl IntoIterator<Item = (&'a str, &'a CountryRequirements, usize)>,
+        today: NaiveDate,
+        stale_after_days: u32,
+    ) -> FreshnessReport {
+        let mut entries: Vec<FreshnessEntry> = records
+            .into_iter()
+            .map(|(id, record, sources)| FreshnessEntry {
+                id: id.to_string(),
+                verified: record.verified.clone(),
+                age_days: record.verified.as_ref().map(|v| age_days(v, today)),
+                sources,
+                freshness: Freshness::of(record.verified.as_ref(), today, stale_after_days),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.freshness
+                .cmp(&b.freshness)
+                .then(b.age_days.cmp(&a.age_days))
+                .then(a.id.cmp(&b.id))
+        });
+        FreshnessReport {
+            today,
+            stale_after_days,
+            entries,
+        }
+    }
+
+    /// Every bloc and country record of the dataset
+    pub fn of_database(
+        db: &RequirementsDatabase,
+        today: NaiveDate,
+        stale_after_days: u32,
+    ) -> FreshnessReport {
+        let records = db
+            .blocs()
+            .map(|(id, bloc)| (id, &bloc.requirements))
+            .chain(db.countries())
+            .map(|(id, record)| (id, record, db.sources(id).len()));
+        FreshnessReport::new(records, today, stale_after_days)
+    }
+
+    pub fn count(&self, freshness: Freshness) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.freshness == freshness)
+            .count()
+    }
+
+    /// Records which need attention: unverified, stale or without sources
+    pub fn needs_attention(&self) -> impl Iterator<Item = &FreshnessEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.freshness != Freshness::Fresh || e.sources == 0)
+    }
+}
+
+/// Plain text table for terminals and logs
+impl fmt::Display for FreshnessReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Freshness on {}, stale after {} days: {} fresh, {} stale, {} unverified",
+            self.today,
+            self.stale_after_days,
+            self.count(Freshness::Fresh),
+            self.count(Freshness::Stale),
+            self.count(Freshness::Unverified)
+        )?;
+        for entry in &self.entries {
+            let state = match entry.freshness {
+                Freshness::Fresh => "fresh",
+                Freshness::Stale => "STALE",
+                Freshness::Unverified => "UNVERIFIED",
+            };
+            match &entry.verified {
+                Some(v) => writeln!(
+                    f,
+                    "{:<6} {:<10} {} ({} days ago) by {}, {} sources",
+                    entry.id,
+                    state,
+                    v.date,
+                    entry.age_days.unwrap_or_default(),
+                    v.verifier,
+                    entry.sources
+                )?,
+                None => writeln!(f, "{:<6} {:<10} {} sources", entry.id, state, entry.sources)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::requirements::get_requirements_database;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn verified(on: NaiveDate) -> CountryRequirements {
+        CountryRequirements {
+            verified: Some(Verification {
+                date: on,
+                verifier: "tester".to_string(),
+            }),
+            ..CountryRequirements::default()
+        }
+    }
+
+    #[test]
+    fn freshness_by_threshold() {
+        let today = date(2024, 7, 1);
+        let v = Verification {
+            date: date(2024, 1, 1),
+            verifier: "tester".to_string(),
+        };
+        assert_eq!(age_days(&v, today), 182);
+        assert_eq!(Freshness::of(Some(&v), today, 182), Freshness::Fresh);
+        assert_eq!(Freshness::of(Some(&v), today, 181), Freshness::Stale);
+        assert_eq!(Freshness::of(None, today, 180), Freshness::Unverified);
+        assert_eq!(age_days(&v, date(2023, 12, 1)), 0);
+    }
+
+    #[test]
+    fn report_lists_doubtful_records_first() {
+        let old = verified(date(2023, 1, 1));
+        let older = verified(date(2022, 1, 1));
+        let recent = verified(date(2024, 6, 1));
+        let unverified = CountryRequirements::default();
+        let report = FreshnessReport::new(
+            [
+                ("AA", &recent, 1),
+                ("BB", &old, 1),
+                ("CC", &unverified, 2),
+                ("DD", &older, 0),
+            ],
+            date(2024, 7, 1),
+            180,
+        );
+        let ids: Vec<&str> = report.entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["CC", "DD", "BB", "AA"]);
+        assert_eq!(report.count(Freshness::Stale), 2);
+        assert_eq!(report.count(Freshness::Fresh), 1);
+        assert_eq!(report.needs_attention().count(), 3);
+        let text = report.to_string();
+        assert!(text.starts_with(
+            "Freshness on 2024-07-01, stale after 180 days: 1 fresh, 2 stale, 1 unverified"
+        ));
+        assert!(text.contains("CC     UNVERIFIED 2 sources"));
+    }
+
+    #[test]
+    fn dataset_records_are_verified_and_cited() {
+        let db = get_requirements_database();
+        let report = FreshnessReport::of_database(db, date(2026, 1, 1), DEFAULT_STALE_AFTER_DAYS);
+        assert_eq!(
+            report.entries.len(),
+            db.blocs().count() + db.countries().count()
+        );
+        for entry in &report.entries {
+            assert!(entry.verified.is_some(), "{} is not verified", entry.id);
+            assert!(entry.sources > 0, "{} cites no sources", entry.id);
+        }
+    }
+}
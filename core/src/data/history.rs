@@ -0,0 +1,430 @@
+//! Dated versions of the requirement records, to show the rules which were
+//! in effect on a past date and what changed since.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use super::requirements::{merged, CountryRequirements, RequirementsDatabase};
+use crate::map_data::get_countries_names;
+
+const HISTORY_JSON: &str = include_str!("../../data/history.json");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Version {
+    /// First day the version applied
+    pub effective: NaiveDate,
+    /// What changed from the version before
+    pub summary: String,
+    /// `None` for the latest version, which is the record in requirements.json
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<CountryRequirements>,
+}
+
+/// A requirement which differs between two versions, values are `null`
+/// where the requirement is not set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Dotted path of the field, e.g. `titer.wait_days`
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Change of the requirements of a country on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    pub effective: NaiveDate,
+    /// Summaries of the versions taking effect on the day, the country's
+    /// own before its bloc's
+    pub summaries: Vec<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    /// Versions oldest first, keyed by country or bloc id
+    records: BTreeMap<String, Vec<Version>>,
+}
+
+impl History {
+    pub fn from_json(json: &str) -> serde_json::Result<History> {
+        serde_json::from_str(json)
+    }
+
+    /// Countries and blocs with a history, ordered by id
+    pub fn records(&self) -> impl Iterator<Item = (&str, &[Version])> {
+        self.records
+            .iter()
+            .map(|(id, versions)| (id.as_str(), versions.as_slice()))
+    }
+
+    pub fn versions(&self, id: &str) -> &[Version] {
+        self.records.get(id).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+
+    /// Day of the oldest version of any record
+    pub fn first_date(&self) -> Option<NaiveDate> {
+        self.records.values().flatten().map(|v| v.effective).min()
+    }
+
+    /// Record of a country or bloc on `date` given its `current` one.
+    /// Records without a history are taken as unchanged, dates before the
+    /// oldest version of a history have no record.
+    pub fn record_on(
+        &self,
+        id: &str,
+        date: NaiveDate,
+        current: Option<&CountryRequirements>,
+    ) -> Option<CountryRequirements> {
+        let versions = self.versions(id);
+        if versions.is_empty() {
+            return current.cloned();
+        }
+        versions
+            .iter()
+            .rev()
+            .find(|v| v.effective <= date)
+            .and_then(|v| v.requirements.as_ref().or(current))
+            .cloned()
+    }
+
+    /// Effective requirements of a country on `date`, given the current
+    /// records of the country and of its bloc
+    pub fn requirements_on(
+        &self,
+        country_id: &str,
+        bloc_id: Option<&str>,
+        date: NaiveDate,
+        own: Option<&CountryRequirements>,
+        bloc: Option<&CountryRequirements>,
+    ) -> Option<CountryRequirements> {
+        let own = self.record_on(country_id, date, own);
+        let bloc = bloc_id.and_then(|id| self.record_on(id, date, bloc));
+        merged(own.as_ref(), bloc.as_ref())
+    }
+
+    /// The dataset as it was on `date`
+    pub fn database_on(&self, db: &RequirementsDatabase, date: NaiveDate) -> RequirementsDatabase {
+        let mut then = db.clone();
+        for id in self.records.keys() {
+            then.set_record(id, self.record_on(id, date, db.record(id)));
+        }
+        then
+    }
+
+    /// Changes of a country's requirements, the latest first, given the
+    /// current records of the country and of its bloc
+    pub fn timeline(
+        &self,
+        country_id: &str,
+        bloc_id: Option<&str>,
+        own: Option<&CountryRequirements>,
+        bloc: Option<&CountryRequirements>,
+    ) -> Vec<Revision> {
+        let mut days: BTreeMap<NaiveDate, Vec<String>> = BTreeMap::new();
+        for id in std::iter::once(country_id).chain(bloc_id) {
+            for version in self.versions(id) {
+                days.entry(version.effective)
+                    .or_default()
+                    .push(version.summary.clone());
+            }
+        }
+        days.into_iter()
+            .rev()
+            .map(|(effective, summaries)| {
+                let before = effective
+                    .pred_opt()
+                    .and_then(|day| self.requirements_on(country_id, bloc_id, day, own, bloc));
+                let after = self.requirements_on(country_id, bloc_id, effective, own, bloc);
+                Revision {
+                    effective,
+                    summaries,
+                    changes: changes(before.as_ref(), after.as_ref()),
+                }
+            })
+            .collect()
+    }
+
+    /// Map countries whose requirements on `date` differ from the current ones
+    pub fn changed_since(&self, db: &RequirementsDatabase, date: NaiveDate) -> Vec<String> {
+        let then = self.database_on(db, date);
+        let mut changed: Vec<String> = get_countries_names()
+            .keys()
+            .filter(|id| {
+                !changes(then.for_country(id).as_ref(), db.for_country(id).as_ref()).is_empty()
+            })
+            .map(|id| id.to_string())
+            .collect();
+        changed.sort();
+        changed
+    }
+}
+
+/// Fields of a record by dotted path, leaving out those which are not set
+/// and the verification, which is not a requirement
+fn fields(record: Option<&CountryRequirements>) -> BTreeMap<String, Value> {
+    fn collect(path: &str, value: Value, fields: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    let path = if path.is_empty() {
+                        key
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    collect(&path, value, fields);
+                }
+            }
+            Value::Null => {}
+            value => {
+                fields.insert(path.to_string(), value);
+            }
+        }
+    }
+    let mut fields = BTreeMap::new();
+    if let Some(record) = record {
+        let record = CountryRequirements {
+            verified: None,
+            ..record.clone()
+        };
+        // through the JSON text so that `f32`s keep their shortest spelling
+        let value = serde_json::to_string(&record)
+            .and_then(|text| serde_json::from_str(&text))
+            .expect("CountryRequirements is serializable");
+        collect("", value, &mut fields);
+    }
+    fields
+}
+
+/// Requirements which differ between two records, ordered by field
+pub fn changes(
+    old: Option<&CountryRequirements>,
+    new: Option<&CountryRequirements>,
+) -> Vec<FieldChange> {
+    let old = fields(old);
+    let mut new = fields(new);
+    let mut changes = vec![];
+    for (field, old) in old {
+        let new = new.remove(&field).unwrap_or(Value::Null);
+        if old != new {
+            changes.push(FieldChange { field, old, new });
+        }
+    }
+    for (field, new) in new {
+        changes.push(FieldChange {
+            field,
+            old: Value::Null,
+            new,
+        });
+    }
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+/// Net changes of the requirements after `date`, composed from a timeline
+/// as returned by `History::timeline`
+pub fn changes_since(revisions: &[Revision], date: NaiveDate) -> Vec<FieldChange> {
+    let mut net: BTreeMap<&str, (&Value, &Value)> = BTreeMap::new();
+    for revision in revisions.iter().rev().filter(|r| r.effective > date) {
+        for change in &revision.changes {
+            net.entry(&change.field)
+                .and_modify(|(_, new)| *new = &change.new)
+                .or_insert((&change.old, &change.new));
+        }
+    }
+    net.into_iter()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(field, (old, new))| FieldChange {
+            field: field.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        })
+        .collect()
+}
+
+/// History from versions keyed by country or bloc id, each oldest first
+impl FromIterator<(String, Vec<Version>)> for History {
+    fn from_iter<I: IntoIterator<Item = (String, Vec<Version>)>>(records: I) -> History {
+        History {
+            records: records.into_iter().collect(),
+        }
+    }
+}
+
+pub fn get_history() -> &'static History {
+    static HISTORY: OnceLock<History> = OnceLock::new();
+    HISTORY.get_or_init(|| {
+        History::from_json(HISTORY_JSON)
+            .unwrap_or_else(|e| panic!("Malformed history dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::requirements::get_requirements_database;
+    use serde_json::json;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    const DATABASE: &str = r#"{
+        "blocs": { "B": { "name": "Bloc", "members": ["AA"], "requirements": { "quarantine_days": 0 } } },
+        "countries": { "AA": { "import_permit": false } }
+    }"#;
+
+    const HISTORY: &str = r#"{
+        "records": {
+            "AA": [
+                { "effective": "2010-01-01", "summary": "permit", "requirements": { "import_permit": true } },
+                { "effective": "2020-01-01", "summary": "no permit" }
+            ],
+            "B": [
+                { "effective": "2015-06-01", "summary": "quarantine", "requirements": { "quarantine_days": 30 } },
+                { "effective": "2020-01-01", "summary": "no quarantine" }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn records_on_past_dates() {
+        let history = History::from_json(HISTORY).unwrap();
+        let current = CountryRequirements {
+            import_permit: Some(false),
+            ..CountryRequirements::default()
+        };
+        let on = |day| history.record_on("AA", day, Some(&current));
+        assert_eq!(on(date(2009, 12, 31)), None);
+        assert_eq!(on(date(2010, 1, 1)).unwrap().import_permit, Some(true));
+        assert_eq!(on(date(2019, 12, 31)).unwrap().import_permit, Some(true));
+        assert_eq!(on(date(2020, 1, 1)), Some(current.clone()));
+        assert_eq!(
+            history.record_on("CC", date(2000, 1, 1), Some(&current)),
+            Some(current)
+        );
+        assert_eq!(history.first_date(), Some(date(2010, 1, 1)));
+    }
+
+    #[test]
+    fn database_on_a_past_date() {
+        let db = RequirementsDatabase::from_json(DATABASE).unwrap();
+        let history = History::from_json(HISTORY).unwrap();
+        let then = history.database_on(&db, date(2016, 1, 1));
+        let aa = then.for_country("AA").unwrap();
+        assert_eq!(aa.import_permit, Some(true));
+        assert_eq!(aa.quarantine_days, Some(30));
+        // the bloc exists without requirements before its history starts
+        assert_eq!(
+            history.database_on(&db, date(2005, 1, 1)).for_country("AA"),
+            Some(CountryRequirements::default())
+        );
+        assert_eq!(
+            history.database_on(&db, date(2021, 1, 1)).for_country("AA"),
+            db.for_country("AA")
+        );
+    }
+
+    #[test]
+    fn timeline_of_bloc_member() {
+        let db = RequirementsDatabase::from_json(DATABASE).unwrap();
+        let history = History::from_json(HISTORY).unwrap();
+        let timeline = history.timeline("AA", Some("B"), db.record("AA"), db.record("B"));
+        let days: Vec<NaiveDate> = timeline.iter().map(|r| r.effective).collect();
+        assert_eq!(days, [date(2020, 1, 1), date(2015, 6, 1), date(2010, 1, 1)]);
+        assert_eq!(timeline[0].summaries, ["no permit", "no quarantine"]);
+        assert_eq!(
+            timeline[0].changes,
+            [
+                FieldChange {
+                    field: "import_permit".to_string(),
+                    old: json!(true),
+                    new: json!(false),
+                },
+                FieldChange {
+                    field: "quarantine_days".to_string(),
+                    old: json!(30),
+                    new: json!(0),
+                },
+            ]
+        );
+        assert_eq!(timeline[2].changes[0].old, Value::Null);
+    }
+
+    #[test]
+    fn net_changes_since_a_date() {
+        let db = RequirementsDatabase::from_json(DATABASE).unwrap();
+        let history = History::from_json(HISTORY).unwrap();
+        let timeline = history.timeline("AA", Some("B"), db.record("AA"), db.record("B"));
+        let since = |day| {
+            changes_since(&timeline, day)
+                .into_iter()
+                .map(|c| c.field)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            since(date(2016, 1, 1)),
+            ["import_permit", "quarantine_days"]
+        );
+        assert_eq!(
+            since(date(2005, 1, 1)),
+            ["import_permit", "quarantine_days"]
+        );
+        assert_eq!(
+            changes_since(&timeline, date(2005, 1, 1))[0].old,
+            Value::Null
+        );
+        assert!(since(date(2020, 1, 1)).is_empty());
+    }
+
+    #[test]
+    fn changes_by_field() {
+        let old: CountryRequirements = serde_json::from_value(json!({
+            "titer": { "min_iu_per_ml": 0.2, "methods": ["favn"], "wait_days": 180, "lab_approval": "EU" },
+            "verified": { "date": "2020-01-01", "verifier": "a" }
+        }))
+        .unwrap();
+        let new: CountryRequirements = serde_json::from_value(json!({
+            "titer": { "min_iu_per_ml": 0.2, "methods": ["favn", "rffit"], "wait_days": 90, "lab_approval": "EU" },
+            "quarantine_days": 0
+        }))
+        .unwrap();
+        let fields: Vec<String> = changes(Some(&old), Some(&new))
+            .into_iter()
+            .map(|c| c.field)
+            .collect();
+        assert_eq!(
+            fields,
+            ["quarantine_days", "titer.methods", "titer.wait_days"]
+        );
+        let removed = changes(Some(&old), None);
+        assert!(removed.iter().all(|c| c.new == Value::Null));
+        assert!(removed
+            .iter()
+            .any(|c| c.field == "titer.min_iu_per_ml" && c.old == json!(0.2)));
+    }
+
+    #[test]
+    fn dataset_history_applies_to_records() {
+        let db = get_requirements_database();
+        let history = get_history();
+        for (id, versions) in history.records() {
+            assert!(db.record(id).is_some(), "{} has no record", id);
+            assert!(versions.windows(2).all(|w| w[0].effective < w[1].effective));
+            assert!(versions.last().unwrap().requirements.is_none());
+        }
+        let gb = history.timeline("GB", None, db.record("GB"), None);
+        assert_eq!(gb[0].effective, date(2012, 1, 1));
+        assert!(gb[0]
+            .changes
+            .iter()
+            .any(|c| c.field == "titer.wait_days" && c.old == json!(180) && c.new == json!(90)));
+        assert!(history
+            .changed_since(db, date(2005, 1, 1))
+            .contains(&"GB".to_string()));
+        assert!(history.changed_since(db, date(2013, 1, 1)).is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use super::requirements::TiterMethod;
+
+const TITER_LABS_JSON: &str = include_str!("../../data/titer_labs.json");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Laboratory {
+    pub id: String,
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    pub methods: Vec<TiterMethod>,
+    /// Authorities (bloc or country ids) which accept results from this laboratory
+    pub approved_by: Vec<String>,
+}
+
+impl Laboratory {
+    pub fn is_approved_by(&self, authority: &str) -> bool {
+        self.approved_by.iter().any(|a| a == authority)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LaboratoryList {
+    laboratories: Vec<Laboratory>,
+}
+
+/// Laboratories listed in a document shaped like `titer_labs.json`
+pub fn laboratories_from_json(json: &str) -> serde_json::Result<Vec<Laboratory>> {
+    serde_json::from_str::<LaboratoryList>(json).map(|list| list.laboratories)
+}
+
+pub fn get_laboratories() -> &'static [Laboratory] {
+    static LABORATORIES: OnceLock<Vec<Laboratory>> = OnceLock::new();
+    LABORATORIES.get_or_init(|| {
+        laboratories_from_json(TITER_LABS_JSON)
+            .unwrap_or_else(|e| panic!("Malformed titer laboratories dataset: {}", e))
+    })
+}
+
+pub fn find_laboratory(id: &str) -> Option<&'static Laboratory> {
+    get_laboratories().iter().find(|l| l.id == id)
+}
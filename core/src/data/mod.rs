@@ -0,0 +1,36 @@
+pub mod airlines;
+pub mod breeds;
+pub mod certificates;
+pub mod freshness;
+pub mod history;
+pub mod labs;
+pub mod overview;
+pub mod regions;
+pub mod requirements;
+
+#[cfg(test)]
+mod tests {
+    use super::airlines::get_airline_database;
+    use super::labs::get_laboratories;
+    use crate::map_data::{get_countries_aliases, get_countries_names};
+
+    #[test]
+    fn dataset_countries_are_on_the_map() {
+        let names = get_countries_names();
+        for airline in get_airline_database().airlines() {
+            for id in airline
+                .countries
+                .iter()
+                .chain(&airline.cargo_only_countries)
+            {
+                assert!(names.contains_key(id.as_str()), "{}: {}", airline.iata, id);
+            }
+        }
+        for lab in get_laboratories() {
+            assert!(names.contains_key(lab.country.as_str()), "{}", lab.id);
+        }
+        for (alias, id) in get_countries_aliases() {
+            assert!(names.contains_key(id), "{}: {}", alias, id);
+        }
+    }
+}
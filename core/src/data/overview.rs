@@ -0,0 +1,177 @@
+//! Headline entry requirements of every country side by side, for the list
+//! view which offers what the map does without panning an SVG.
+
+use std::cmp::Ordering;
+
+use super::requirements::{get_requirements_database, CountryRequirements, RabiesStatus};
+use crate::map_data::get_countries_names;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Column {
+    Country,
+    Quarantine,
+    Titer,
+    Permit,
+    Rabies,
+}
+
+impl Column {
+    pub const ALL: [Column; 5] = [
+        Column::Country,
+        Column::Quarantine,
+        Column::Titer,
+        Column::Permit,
+        Column::Rabies,
+    ];
+}
+
+/// One country of the list, `None` wherever the dataset says nothing
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverviewRow {
+    pub id: &'static str,
+    pub name: String,
+    pub quarantine_days: Option<u32>,
+    pub titer_required: Option<bool>,
+    pub import_permit: Option<bool>,
+    pub rabies_status: Option<RabiesStatus>,
+}
+
+impl OverviewRow {
+    /// `origin` decides whether the titer test applies, unknown origin
+    /// counts as requiring it
+    pub fn new(
+        id: &'static str,
+        name: String,
+        requirements: Option<&CountryRequirements>,
+        origin: Option<&str>,
+    ) -> OverviewRow {
+        OverviewRow {
+            id,
+            name,
+            quarantine_days: requirements.and_then(|r| r.quarantine_days),
+            titer_required: requirements
+                .map(|r| r.titer.as_ref().is_some_and(|t| t.applies_to(origin))),
+            import_permit: requirements.and_then(|r| r.import_permit),
+            rabies_status: requirements.and_then(|r| r.rabies_status),
+        }
+    }
+
+    /// Case insensitive match of the name or id
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.name.to_lowercase().contains(&query) || self.id.eq_ignore_ascii_case(&query)
+    }
+
+    fn key(&self, column: Column) -> Option<u32> {
+        match column {
+            Column::Country => Some(0),
+            Column::Quarantine => self.quarantine_days,
+            Column::Titer => self.titer_required.map(u32::from),
+            Column::Permit => self.import_permit.map(u32::from),
+            Column::Rabies => self.rabies_status.map(|s| s as u32),
+        }
+    }
+
+    fn compare_names(&self, other: &OverviewRow) -> Ordering {
+        self.name
+            .to_lowercase()
+            .cmp(&other.name.to_lowercase())
+            .then(self.id.cmp(other.id))
+    }
+}
+
+/// Every country of the map with the requirements of the bundled dataset,
+/// names rendered by `name`
+pub fn overview_rows(name: impl Fn(&str) -> String, origin: Option<&str>) -> Vec<OverviewRow> {
+    let db = get_requirements_database();
+    get_countries_names()
+        .keys()
+        .map(|id| OverviewRow::new(id, name(id), db.for_country(id).as_ref(), origin))
+        .collect()
+}
+
+/// Sorts by `column`, then by name. Countries without data come last in
+/// both directions so they never push the useful rows out of sight.
+pub fn sort_rows(rows: &mut [OverviewRow], column: Column, ascending: bool) {
+    let direction = |order: Ordering| if ascending { order } else { order.reverse() };
+    rows.sort_by(|a, b| {
+        let order = match (a.key(column), b.key(column)) {
+            (Some(x), Some(y)) => direction(x.cmp(&y)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        let names = a.compare_names(b);
+        order.then(if column == Column::Country {
+            direction(names)
+        } else {
+            names
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<OverviewRow> {
+        overview_rows(|id| get_countries_names()[id].to_string(), Some("US"))
+    }
+
+    fn position(rows: &[OverviewRow], id: &str) -> usize {
+        rows.iter().position(|r| r.id == id).unwrap()
+    }
+
+    #[test]
+    fn lists_every_country_with_inherited_requirements() {
+        let rows = rows();
+        assert_eq!(rows.len(), get_countries_names().len());
+        let au = rows.iter().find(|r| r.id == "AU").unwrap();
+        assert_eq!(au.quarantine_days, Some(10));
+        assert_eq!(au.titer_required, Some(true));
+        assert_eq!(au.import_permit, Some(true));
+        assert_eq!(au.rabies_status, Some(RabiesStatus::Free));
+        // EU bloc rules, and the US is exempt from the titer test
+        let fr = rows.iter().find(|r| r.id == "FR").unwrap();
+        assert_eq!(fr.quarantine_days, Some(0));
+        assert_eq!(fr.titer_required, Some(false));
+        let unknown = rows.iter().find(|r| r.id == "BR").unwrap();
+        assert_eq!(unknown.quarantine_days, None);
+        assert_eq!(unknown.titer_required, None);
+    }
+
+    #[test]
+    fn unknown_values_sort_last_in_both_directions() {
+        let mut rows = rows();
+        sort_rows(&mut rows, Column::Quarantine, true);
+        assert_eq!(rows[0].quarantine_days, Some(0));
+        assert!(position(&rows, "AU") < position(&rows, "BR"));
+        sort_rows(&mut rows, Column::Quarantine, false);
+        assert_eq!(rows[0].quarantine_days, Some(10));
+        assert_eq!(rows[0].id, "AU");
+        assert_eq!(rows[1].id, "NZ");
+        assert!(position(&rows, "FR") < position(&rows, "BR"));
+    }
+
+    #[test]
+    fn sorts_by_name_within_equal_values() {
+        let mut rows = rows();
+        sort_rows(&mut rows, Column::Rabies, true);
+        assert!(position(&rows, "AU") < position(&rows, "GB"));
+        assert!(position(&rows, "GB") < position(&rows, "FR"));
+        sort_rows(&mut rows, Column::Country, false);
+        assert!(position(&rows, "ZW") < position(&rows, "AF"));
+    }
+
+    #[test]
+    fn filters_by_name_or_id() {
+        let rows = rows();
+        let found: Vec<&str> = rows
+            .iter()
+            .filter(|r| r.matches(" zeal"))
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(found, vec!["NZ"]);
+        assert!(rows.iter().any(|r| r.matches("gb") && r.id == "GB"));
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+const REGIONS_JSON: &str = include_str!("../../data/regions.json");
+
+/// Continents the map countries are grouped in, after the UN M49 scheme
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Regions {
+    /// Country ids keyed by region id
+    regions: BTreeMap<String, Vec<String>>,
+}
+
+impl Regions {
+    pub fn from_json(json: &str) -> serde_json::Result<Regions> {
+        serde_json::from_str(json)
+    }
+
+    /// Regions ordered by id
+    pub fn regions(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.regions
+            .iter()
+            .map(|(id, members)| (id.as_str(), members.as_slice()))
+    }
+
+    pub fn region_of(&self, country_id: &str) -> Option<&str> {
+        self.regions()
+            .find(|(_, members)| members.iter().any(|m| m == country_id))
+            .map(|(id, _)| id)
+    }
+}
+
+pub fn get_regions() -> &'static Regions {
+    static REGIONS: OnceLock<Regions> = OnceLock::new();
+    REGIONS.get_or_init(|| {
+        Regions::from_json(REGIONS_JSON)
+            .unwrap_or_else(|e| panic!("Malformed regions dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map_data::get_countries_names;
+
+    #[test]
+    fn every_map_country_is_in_one_region() {
+        let regions = get_regions();
+        let names = get_countries_names();
+        for id in names.keys() {
+            assert!(regions.region_of(id).is_some(), "{} has no region", id);
+        }
+        let listed: usize = regions.regions().map(|(_, members)| members.len()).sum();
+        assert_eq!(listed, names.len());
+        assert_eq!(regions.region_of("JP"), Some("asia"));
+        assert_eq!(regions.region_of("XX"), None);
+    }
+}
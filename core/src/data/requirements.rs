@@ -0,0 +1,347 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+const REQUIREMENTS_JSON: &str = include_str!("../../data/requirements.json");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TiterMethod {
+    Favn,
+    Rffit,
+    Elisa,
+}
+
+impl fmt::Display for TiterMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TiterMethod::Favn => "FAVN",
+            TiterMethod::Rffit => "RFFIT",
+            TiterMethod::Elisa => "ELISA",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TiterRequirement {
+    pub min_iu_per_ml: f32,
+    pub methods: Vec<TiterMethod>,
+    /// Days between the blood sample and the earliest allowed entry
+    pub wait_days: u32,
+    /// Days a result stays valid after sampling, `None` while boosters are kept up to date
+    #[serde(default)]
+    pub validity_days: Option<u32>,
+    /// Approval list the testing laboratory has to be on, e.g. `EU` or `JP`
+    pub lab_approval: String,
+    /// Origins a titer test is not required from
+    #[serde(default)]
+    pub exempt_origins: Vec<String>,
+}
+
+impl TiterRequirement {
+    /// Unknown origin is treated as requiring the test
+    pub fn applies_to(&self, origin: Option<&str>) -> bool {
+        match origin {
+            Some(origin) => !self.exempt_origins.iter().any(|o| o == origin),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MicrochipRequirement {
+    /// Non-ISO chips are refused rather than accepted with the owner's own reader
+    pub iso_required: bool,
+    /// The chip has to be implanted before the rabies vaccination which is relied on
+    pub before_vaccination: bool,
+}
+
+/// Rabies situation of the country itself, as classified by its authorities
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabiesStatus {
+    Free,
+    Controlled,
+    HighIncidence,
+}
+
+/// Last check of a record against its sources
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Verification {
+    pub date: NaiveDate,
+    /// Who did the check
+    pub verifier: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CountryRequirements {
+    #[serde(default)]
+    pub microchip: Option<MicrochipRequirement>,
+    #[serde(default)]
+    pub titer: Option<TiterRequirement>,
+    /// Days in government quarantine on arrival when every other requirement is met
+    #[serde(default)]
+    pub quarantine_days: Option<u32>,
+    /// An import permit has to be obtained before travelling
+    #[serde(default)]
+    pub import_permit: Option<bool>,
+    #[serde(default)]
+    pub rabies_status: Option<RabiesStatus>,
+    #[serde(default)]
+    pub verified: Option<Verification>,
+}
+
+impl CountryRequirements {
+    /// Country specific rules take precedence over the ones of its bloc
+    pub fn or(self, fallback: &CountryRequirements) -> CountryRequirements {
+        CountryRequirements {
+            microchip: self.microchip.or_else(|| fallback.microchip.clone()),
+            titer: self.titer.or_else(|| fallback.titer.clone()),
+            quarantine_days: self.quarantine_days.or(fallback.quarantine_days),
+            import_permit: self.import_permit.or(fallback.import_permit),
+            rabies_status: self.rabies_status.or(fallback.rabies_status),
+            // the merged record is only as fresh as its older part
+            verified: match (self.verified, &fallback.verified) {
+                (Some(own), Some(bloc)) if bloc.date < own.date => Some(bloc.clone()),
+                (Some(own), _) => Some(own),
+                (None, bloc) => bloc.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bloc {
+    pub name: String,
+    pub members: Vec<String>,
+    pub requirements: CountryRequirements,
+    /// Countries outside the bloc which accept its pet passport like members do
+    #[serde(default)]
+    pub passport_countries: Vec<String>,
+}
+
+/// Official publication the requirements of a country or bloc are taken from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Source {
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequirementsDatabase {
+    #[serde(default)]
+    blocs: HashMap<String, Bloc>,
+    countries: HashMap<String, CountryRequirements>,
+    /// Keyed by country or bloc id
+    #[serde(default)]
+    sources: HashMap<String, Vec<Source>>,
+}
+
+impl RequirementsDatabase {
+    pub fn from_json(json: &str) -> serde_json::Result<RequirementsDatabase> {
+        serde_json::from_str(json)
+    }
+
+    pub fn blocs(&self) -> impl Iterator<Item = (&str, &Bloc)> {
+        self.blocs.iter().map(|(id, bloc)| (id.as_str(), bloc))
+    }
+
+    /// Requirements set for countries themselves, without those inherited from blocs
+    pub fn countries(&self) -> impl Iterator<Item = (&str, &CountryRequirements)> {
+        self.countries.iter().map(|(id, r)| (id.as_str(), r))
+    }
+
+    /// Sets the country's own requirements and citations, replacing any it had
+    pub fn insert_country(
+        &mut self,
+        country_id: &str,
+        requirements: CountryRequirements,
+        sources: Vec<Source>,
+    ) {
+        self.countries.insert(country_id.to_string(), requirements);
+        if sources.is_empty() {
+            self.sources.remove(country_id);
+        } else {
+            self.sources.insert(country_id.to_string(), sources);
+        }
+    }
+
+    /// Record a country or bloc sets itself
+    pub fn record(&self, id: &str) -> Option<&CountryRequirements> {
+        self.blocs
+            .get(id)
+            .map(|bloc| &bloc.requirements)
+            .or_else(|| self.countries.get(id))
+    }
+
+    /// Replaces the record of a country or bloc, `None` removes a country's
+    /// record and empties a bloc's
+    pub fn set_record(&mut self, id: &str, record: Option<CountryRequirements>) {
+        if let Some(bloc) = self.blocs.get_mut(id) {
+            bloc.requirements = record.unwrap_or_default();
+            return;
+        }
+        match record {
+            Some(record) => {
+                self.countries.insert(id.to_string(), record);
+            }
+            None => {
+                self.countries.remove(id);
+            }
+        }
+    }
+
+    pub fn bloc_of(&self, country_id: &str) -> Option<&Bloc> {
+        self.blocs
+            .values()
+            .find(|b| b.members.iter().any(|m| m == country_id))
+    }
+
+    pub fn is_member(&self, bloc_id: &str, country_id: &str) -> bool {
+        self.blocs
+            .get(bloc_id)
+            .is_some_and(|bloc| bloc.members.iter().any(|m| m == country_id))
+    }
+
+    /// The pet passport issued in the bloc is valid for entering the country
+    pub fn accepts_passport(&self, bloc_id: &str, country_id: &str) -> bool {
+        self.blocs.get(bloc_id).is_some_and(|bloc| {
+            bloc.members
+                .iter()
+                .chain(&bloc.passport_countries)
+                .any(|c| c == country_id)
+        })
+    }
+
+    /// Citations of a country or bloc as listed in the dataset
+    pub fn sources(&self, id: &str) -> &[Source] {
+        self.sources.get(id).map(|s| s.as_slice()).unwrap_or(&[])
+    }
+
+    /// Citations behind the effective requirements, the country's own first
+    pub fn sources_for(&self, country_id: &str) -> Vec<&Source> {
+        let bloc_id = self
+            .blocs
+            .iter()
+            .find(|(_, b)| b.members.iter().any(|m| m == country_id))
+            .map(|(id, _)| id.as_str());
+        self.sources(country_id)
+            .iter()
+            .chain(bloc_id.map(|id| self.sources(id)).unwrap_or(&[]))
+            .collect()
+    }
+
+    /// Effective requirements for entering the country, `None` if we have no data on it
+    pub fn for_country(&self, country_id: &str) -> Option<CountryRequirements> {
+        merged(
+            self.countries.get(country_id),
+            self.bloc_of(country_id).map(|b| &b.requirements),
+        )
+    }
+}
+
+/// Requirements of a country given its own record and the one of its bloc
+pub fn merged(
+    own: Option<&CountryRequirements>,
+    bloc: Option<&CountryRequirements>,
+) -> Option<CountryRequirements> {
+    match (own, bloc) {
+        (Some(own), Some(bloc)) => Some(own.clone().or(bloc)),
+        (Some(own), None) => Some(own.clone()),
+        (None, Some(bloc)) => Some(bloc.clone()),
+        (None, None) => None,
+    }
+}
+
+pub fn get_requirements_database() -> &'static RequirementsDatabase {
+    static DATABASE: OnceLock<RequirementsDatabase> = OnceLock::new();
+    DATABASE.get_or_init(|| {
+        RequirementsDatabase::from_json(REQUIREMENTS_JSON)
+            .unwrap_or_else(|e| panic!("Malformed requirements dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bloc_members_inherit_bloc_requirements() {
+        let db = get_requirements_database();
+        let fr = db.for_country("FR").unwrap();
+        assert_eq!(fr.titer.unwrap().lab_approval, "EU");
+        assert_eq!(db.bloc_of("FR").unwrap().name, "European Union");
+    }
+
+    #[test]
+    fn eu_passport_is_valid_beyond_members() {
+        let db = get_requirements_database();
+        assert!(db.accepts_passport("EU", "FR"));
+        assert!(db.accepts_passport("EU", "CH"));
+        assert!(!db.accepts_passport("EU", "GB"));
+        assert!(!db.accepts_passport("XX", "FR"));
+    }
+
+    #[test]
+    fn sources_include_bloc_citations() {
+        let db = get_requirements_database();
+        assert!(!db.sources_for("FR").is_empty());
+        assert_eq!(
+            db.sources_for("FR"),
+            db.sources("EU").iter().collect::<Vec<_>>()
+        );
+        assert!(db.sources_for("JP")[0].url.starts_with("https://"));
+    }
+
+    #[test]
+    fn country_requirements_override_bloc() {
+        let db = RequirementsDatabase::from_json(
+            r#"{
+                "blocs": { "B": { "name": "Bloc", "members": ["AA", "BB"], "requirements": {
+                    "titer": { "min_iu_per_ml": 0.5, "methods": ["favn"], "wait_days": 90, "lab_approval": "B" },
+                    "verified": { "date": "2024-01-10", "verifier": "bloc desk" }
+                } } },
+                "countries": { "BB": {
+                    "titer": { "min_iu_per_ml": 0.5, "methods": ["favn"], "wait_days": 180, "lab_approval": "BB" },
+                    "verified": { "date": "2024-06-01", "verifier": "country desk" }
+                } }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(db.for_country("AA").unwrap().titer.unwrap().wait_days, 90);
+        // the older verification wins
+        assert_eq!(
+            db.for_country("BB").unwrap().verified.unwrap().verifier,
+            "bloc desk"
+        );
+        assert_eq!(db.for_country("BB").unwrap().titer.unwrap().wait_days, 180);
+        assert!(db.for_country("CC").is_none());
+    }
+
+    #[test]
+    fn inserted_country_replaces_record_and_sources() {
+        let mut db = RequirementsDatabase::from_json(
+            r#"{
+                "countries": { "AA": { "quarantine_days": 10 } },
+                "sources": { "AA": [{ "title": "Old act", "url": "https://example.org/old" }] }
+            }"#,
+        )
+        .unwrap();
+        let record = CountryRequirements {
+            quarantine_days: Some(0),
+            ..CountryRequirements::default()
+        };
+        db.insert_country("AA", record.clone(), vec![]);
+        assert_eq!(db.for_country("AA"), Some(record));
+        assert!(db.sources("AA").is_empty());
+        let source = Source {
+            title: "New act".to_string(),
+            url: "https://example.org/new".to_string(),
+        };
+        db.insert_country("BB", CountryRequirements::default(), vec![source.clone()]);
+        assert_eq!(db.sources("BB"), [source]);
+    }
+}
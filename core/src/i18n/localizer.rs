@@ -0,0 +1,342 @@
+use chrono::{DateTime, NaiveDate, TimeZone};
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::{Arg, Direction, Locale, Message};
+use crate::map_data::get_countries_names;
+
+struct Catalog {
+    messages: &'static str,
+    countries: &'static str,
+}
+
+fn catalog(locale: Locale) -> Catalog {
+    match locale {
+        Locale::En => Catalog {
+            messages: include_str!("../../i18n/en/messages.ftl"),
+            countries: include_str!("../../i18n/en/countries.json"),
+        },
+        Locale::De => Catalog {
+            messages: include_str!("../../i18n/de/messages.ftl"),
+            countries: include_str!("../../i18n/de/countries.json"),
+        },
+        Locale::Ru => Catalog {
+            messages: include_str!("../../i18n/ru/messages.ftl"),
+            countries: include_str!("../../i18n/ru/countries.json"),
+        },
+        Locale::Ar => Catalog {
+            messages: include_str!("../../i18n/ar/messages.ftl"),
+            countries: include_str!("../../i18n/ar/countries.json"),
+        },
+    }
+}
+
+/// Renders messages of one locale. Anything missing from its catalog falls
+/// back to English, so a partial translation never leaves a blank.
+pub struct Localizer {
+    locale: Locale,
+    bundle: FluentBundle<FluentResource>,
+    countries: HashMap<String, String>,
+}
+
+impl Localizer {
+    fn new(locale: Locale) -> Localizer {
+        let catalog = catalog(locale);
+        let resource = FluentResource::try_new(catalog.messages.to_string())
+            .unwrap_or_else(|(_, e)| panic!("Malformed {} catalog: {:?}", locale.id(), e));
+        let language = locale
+            .id()
+            .parse()
+            .unwrap_or_else(|e| panic!("Invalid language tag {}: {}", locale.id(), e));
+        let mut bundle = FluentBundle::new_concurrent(vec![language]);
+        // isolation marks keep e.g. Latin names from reordering Arabic text,
+        // they would only get in the way in left-to-right text
+        bundle.set_use_isolating(locale.direction() == Direction::RightToLeft);
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|e| panic!("Duplicate messages in {} catalog: {:?}", locale.id(), e));
+        let countries = serde_json::from_str(catalog.countries)
+            .unwrap_or_else(|e| panic!("Malformed {} country names: {}", locale.id(), e));
+        Localizer {
+            locale,
+            bundle,
+            countries,
+        }
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    fn fallback(&self) -> Option<&'static Localizer> {
+        if self.locale == Locale::default() {
+            None
+        } else {
+            Some(get_localizer(Locale::default()))
+        }
+    }
+
+    fn format_pattern(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let pattern = self.bundle.get_message(id)?.value()?;
+        let mut errors = vec![];
+        let text = self.bundle.format_pattern(pattern, args, &mut errors);
+        Some(text.into_owned())
+    }
+
+    fn value(&self, arg: &Arg) -> FluentValue<'static> {
+        match arg {
+            Arg::Number(number) => (*number).into(),
+            _ => self.text(arg).into(),
+        }
+    }
+
+    fn text(&self, arg: &Arg) -> String {
+        match arg {
+            Arg::Text(text) => text.clone(),
+            Arg::Number(number) => number.to_string(),
+            Arg::Date(date) => self.date(*date),
+            Arg::Country(id) => self.country_name(id),
+            Arg::Message(message) => self.format(message),
+            Arg::List(items) => {
+                let items: Vec<String> = items.iter().map(|item| self.text(item)).collect();
+                self.list_or(&items)
+            }
+        }
+    }
+
+    /// Message without arguments, its id if no catalog has it
+    pub fn tr(&self, id: &str) -> String {
+        self.format_pattern(id, None)
+            .or_else(|| self.fallback().map(|f| f.tr(id)))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub fn format(&self, message: &Message) -> String {
+        let mut args = FluentArgs::new();
+        for (name, value) in &message.args {
+            args.set(*name, self.value(value));
+        }
+        self.format_pattern(message.id, Some(&args))
+            .or_else(|| self.fallback().map(|f| f.format(message)))
+            .unwrap_or_else(|| message.id.to_string())
+    }
+
+    pub fn country_name(&self, id: &str) -> String {
+        match self.countries.get(id) {
+            Some(name) => name.clone(),
+            None => match self.fallback() {
+                Some(fallback) => fallback.country_name(id),
+                None => get_countries_names()
+                    .get(id)
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| id.to_string()),
+            },
+        }
+    }
+
+    pub fn date(&self, date: NaiveDate) -> String {
+        date.format(&self.tr("date-format")).to_string()
+    }
+
+    pub fn date_time<Tz: TimeZone>(&self, date_time: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: fmt::Display,
+    {
+        date_time.format(&self.tr("date-time-format")).to_string()
+    }
+
+    /// "a, b or c"
+    pub fn list_or(&self, items: &[String]) -> String {
+        match items.split_last() {
+            Some((last, rest)) if !rest.is_empty() => {
+                format!(
+                    "{}{}{}",
+                    rest.join(", "),
+                    self.tr("list-or-separator"),
+                    last
+                )
+            }
+            _ => items.join(""),
+        }
+    }
+}
+
+pub fn get_localizer(locale: Locale) -> &'static Localizer {
+    static LOCALIZERS: OnceLock<HashMap<Locale, Localizer>> = OnceLock::new();
+    &LOCALIZERS.get_or_init(|| {
+        Locale::ALL
+            .iter()
+            .map(|&locale| (locale, Localizer::new(locale)))
+            .collect()
+    })[&locale]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ids of the messages defined in a catalog
+    fn message_ids(locale: Locale) -> Vec<&'static str> {
+        catalog(locale)
+            .messages
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id))
+            .collect()
+    }
+
+    #[test]
+    fn catalogs_are_complete() {
+        let english = message_ids(Locale::En);
+        assert!(english.len() > 50);
+        for locale in Locale::ALL {
+            let ids = message_ids(locale);
+            for id in &english {
+                assert!(ids.contains(id), "{} is missing {}", locale.id(), id);
+            }
+            for id in &ids {
+                assert!(english.contains(id), "{} has unknown {}", locale.id(), id);
+            }
+        }
+    }
+
+    #[test]
+    fn catalogs_format_without_errors() {
+        let args = [
+            "approval",
+            "airline",
+            "bloc",
+            "breed",
+            "chip",
+            "code",
+            "count",
+            "countries",
+            "country",
+            "character",
+            "date",
+            "days",
+            "doses",
+            "earliest",
+            "error",
+            "height",
+            "implanted",
+            "laboratory",
+            "latest",
+            "length",
+            "manufacturer",
+            "methods",
+            "min",
+            "modes",
+            "number",
+            "place",
+            "reason",
+            "series",
+            "size",
+            "species",
+            "status",
+            "total",
+            "vaccination",
+            "value",
+            "verifier",
+            "weeks",
+            "width",
+        ];
+        for locale in Locale::ALL {
+            let localizer = get_localizer(locale);
+            for id in message_ids(locale) {
+                let mut fluent_args = FluentArgs::new();
+                for arg in args {
+                    fluent_args.set(arg, 2);
+                }
+                let pattern = localizer.bundle.get_message(id).unwrap().value().unwrap();
+                let mut errors = vec![];
+                localizer
+                    .bundle
+                    .format_pattern(pattern, Some(&fluent_args), &mut errors);
+                assert!(errors.is_empty(), "{} {}: {:?}", locale.id(), id, errors);
+            }
+            let date = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+            assert!(localizer.date(date).contains("2024"));
+            let date_time = chrono_tz::Europe::Berlin
+                .from_local_datetime(&date.and_hms_opt(9, 30, 0).unwrap())
+                .unwrap();
+            assert!(localizer.date_time(&date_time).contains("09:30"));
+        }
+    }
+
+    #[test]
+    fn every_map_country_has_a_name_in_every_locale() {
+        for locale in Locale::ALL {
+            let localizer = get_localizer(locale);
+            for id in get_countries_names().keys() {
+                assert!(
+                    localizer.countries.contains_key(*id),
+                    "{} has no name for {}",
+                    locale.id(),
+                    id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn formats_arguments_in_locale() {
+        let message = Message::new("finding-titer-valid")
+            .arg("date", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap());
+        assert_eq!(
+            get_localizer(Locale::En).format(&message),
+            "The test result is valid until 08 Jul 2024"
+        );
+        assert_eq!(
+            get_localizer(Locale::De).format(&message),
+            "Das Testergebnis ist bis zum 08.07.2024 gültig"
+        );
+        let trip = Message::new("trip-to").arg("country", Arg::Country("RE".to_string()));
+        assert_eq!(get_localizer(Locale::En).format(&trip), "To: Réunion");
+        assert_eq!(get_localizer(Locale::Ru).format(&trip), "Куда: Реюньон");
+    }
+
+    #[test]
+    fn selects_plural_forms() {
+        let localizer = get_localizer(Locale::Ru);
+        let wait = |days: u32| {
+            localizer.format(
+                &Message::new("finding-wait-over")
+                    .arg("days", days)
+                    .arg("date", NaiveDate::from_ymd_opt(2024, 7, 8).unwrap()),
+            )
+        };
+        assert!(wait(21).contains("21 день"));
+        assert!(wait(30).contains("30 дней"));
+        assert!(wait(3).contains("3 дня"));
+    }
+
+    #[test]
+    fn falls_back_to_english_and_id() {
+        let localizer = get_localizer(Locale::Ar);
+        assert_eq!(localizer.tr("no-such-message"), "no-such-message");
+        assert_eq!(localizer.country_name("ZZ"), "ZZ");
+        assert_eq!(get_localizer(Locale::En).tr("species-dog"), "Dog");
+    }
+
+    #[test]
+    fn joins_alternatives() {
+        let items = ["FAVN".to_string(), "RFFIT".to_string(), "ELISA".to_string()];
+        assert_eq!(
+            get_localizer(Locale::En).list_or(&items),
+            "FAVN, RFFIT or ELISA"
+        );
+        assert_eq!(get_localizer(Locale::De).list_or(&items[..1]), "FAVN");
+        let message = Message::new("trip-via").arg(
+            "countries",
+            Arg::List(vec![Arg::Country("DE".to_string()), "XX".into()]),
+        );
+        assert_eq!(
+            get_localizer(Locale::En).format(&message),
+            "Via: Germany or XX"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Translations of user-facing text. Messages live in Fluent catalogs under
+//! `core/i18n/<locale>/messages.ftl`, country names in `countries.json` next
+//! to them, taken from the CLDR territory display names. Code which produces
+//! text for the user returns a [`Message`] and leaves rendering to the UI.
+
+pub mod localizer;
+
+use chrono::NaiveDate;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Direction {
+    /// Value of the HTML `dir` attribute
+    pub fn id(&self) -> &'static str {
+        match self {
+            Direction::LeftToRight => "ltr",
+            Direction::RightToLeft => "rtl",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Ru,
+    Ar,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::De, Locale::Ru, Locale::Ar];
+
+    /// BCP 47 language tag
+    pub fn id(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Ru => "ru",
+            Locale::Ar => "ar",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Locale> {
+        Locale::ALL.iter().copied().find(|l| l.id() == id)
+    }
+
+    /// Name of the language in the language itself, for the language switcher
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+            Locale::Ru => "Русский",
+            Locale::Ar => "العربية",
+        }
+    }
+
+    pub fn direction(&self) -> Direction {
+        match self {
+            Locale::Ar => Direction::RightToLeft,
+            _ => Direction::LeftToRight,
+        }
+    }
+
+    /// First supported locale among the user's preferred languages, e.g.
+    /// `navigator.languages`. Regional variants match their language.
+    pub fn negotiate<S: AsRef<str>>(preferred: &[S]) -> Locale {
+        preferred
+            .iter()
+            .filter_map(|tag| {
+                let language = tag.as_ref().split(['-', '_']).next()?;
+                Locale::from_id(&language.to_ascii_lowercase())
+            })
+            .next()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    Text(String),
+    Number(f64),
+    Date(NaiveDate),
+    /// Country id, rendered as the localized country name
+    Country(String),
+    Message(Message),
+    /// Alternatives, rendered as "a, b or c"
+    List(Vec<Arg>),
+}
+
+impl From<&str> for Arg {
+    fn from(value: &str) -> Arg {
+        Arg::Text(value.to_string())
+    }
+}
+
+impl From<String> for Arg {
+    fn from(value: String) -> Arg {
+        Arg::Text(value)
+    }
+}
+
+impl From<f32> for Arg {
+    fn from(value: f32) -> Arg {
+        // through the shortest decimal representation, 0.1f32 is not 0.1f64
+        Arg::Number(value.to_string().parse().unwrap_or_default())
+    }
+}
+
+impl From<u32> for Arg {
+    fn from(value: u32) -> Arg {
+        Arg::Number(value as f64)
+    }
+}
+
+impl From<usize> for Arg {
+    fn from(value: usize) -> Arg {
+        Arg::Number(value as f64)
+    }
+}
+
+impl From<NaiveDate> for Arg {
+    fn from(value: NaiveDate) -> Arg {
+        Arg::Date(value)
+    }
+}
+
+impl From<Message> for Arg {
+    fn from(value: Message) -> Arg {
+        Arg::Message(value)
+    }
+}
+
+/// Catalog message id with its arguments, rendered by a
+/// [`localizer::Localizer`] in the user's locale
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub id: &'static str,
+    pub args: Vec<(&'static str, Arg)>,
+}
+
+impl Message {
+    pub fn new(id: &'static str) -> Message {
+        Message { id, args: vec![] }
+    }
+
+    pub fn arg(mut self, name: &'static str, value: impl Into<Arg>) -> Message {
+        self.args.push((name, value.into()));
+        self
+    }
+}
+
+/// English rendering, for logs and tests
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            localizer::get_localizer(Locale::default()).format(self)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiates_preferred_languages() {
+        assert_eq!(Locale::negotiate(&["de-AT", "en-US"]), Locale::De);
+        assert_eq!(Locale::negotiate(&["fr-FR", "RU", "en"]), Locale::Ru);
+        assert_eq!(Locale::negotiate(&["ar_EG"]), Locale::Ar);
+        assert_eq!(Locale::negotiate(&["fr", "it"]), Locale::En);
+        assert_eq!(Locale::negotiate::<&str>(&[]), Locale::En);
+    }
+
+    #[test]
+    fn arabic_is_right_to_left() {
+        assert_eq!(Locale::Ar.direction(), Direction::RightToLeft);
+        assert_eq!(Locale::De.direction().id(), "ltr");
+    }
+}
@@ -0,0 +1,16 @@
+//! Platform independent part of petabroad: country data, entry requirements,
+//! travel scheduling and map geometry. Nothing in here may depend on the DOM.
+
+pub mod api;
+pub mod backup;
+pub mod client;
+pub mod compliance;
+pub mod data;
+pub mod i18n;
+pub mod map_data;
+pub mod map_navigation;
+pub mod pet;
+pub mod rules;
+pub mod session;
+pub mod travel;
+pub mod utils;
@@ -233,6 +233,65 @@ pub fn get_countries_names() -> HashMap<&'static str, &'static str> {
     ])
 }
 
+/// Other names countries go by in spreadsheets and official documents, keyed
+/// by the name lowercased with accents and punctuation removed. Takes
+/// precedence over the names above, some of which are shared by a country
+/// and its overseas territories.
+pub fn get_countries_aliases() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("america", "US"),
+        ("usa", "US"),
+        ("united states of america", "US"),
+        ("us virgin islands", "VI"),
+        ("uk", "GB"),
+        ("great britain", "GB"),
+        ("britain", "GB"),
+        ("england", "GB"),
+        ("scotland", "GB"),
+        ("wales", "GB"),
+        ("northern ireland", "GB"),
+        ("france", "FR"),
+        ("french guiana", "GF"),
+        ("netherlands", "NL"),
+        ("the netherlands", "NL"),
+        ("holland", "NL"),
+        ("bonaire", "BQBO"),
+        ("saba", "BQSA"),
+        ("sint eustatius", "BQSE"),
+        ("curacao", "CW"),
+        ("sint maarten", "SX"),
+        ("saint martin", "MF"),
+        ("russia", "RU"),
+        ("czechia", "CZ"),
+        ("north macedonia", "MK"),
+        ("eswatini", "SZ"),
+        ("ivory coast", "CI"),
+        ("cabo verde", "CV"),
+        ("faroe islands", "FO"),
+        ("gambia", "GM"),
+        ("drc", "CD"),
+        ("dr congo", "CD"),
+        ("congo kinshasa", "CD"),
+        ("congo", "CG"),
+        ("congo brazzaville", "CG"),
+        ("korea", "KR"),
+        ("republic of korea", "KR"),
+        ("dprk", "KP"),
+        ("uae", "AE"),
+        ("emirates", "AE"),
+        ("turkiye", "TR"),
+        ("burma", "MM"),
+        ("east timor", "TL"),
+        ("viet nam", "VN"),
+        ("lao pdr", "LA"),
+        ("palestinian territories", "PS"),
+        ("brunei darussalam", "BN"),
+        ("federated states of micronesia", "FM"),
+        ("saint barthelemy", "BL"),
+        ("saint barts", "BL"),
+    ])
+}
+
 pub fn get_countries_borders() -> HashMap<&'static str, &'static str> {
     HashMap::from([
     ("BD", "M1495.5 362.5l-0.4 0-0.3-1.3 0.4-0.1 0.2 0.8 0.1 0.6z m-5.1-4.3l-0.5 0.5-0.2-2.7 0.8 1.5-0.1 0.7z m2.1-1.3l-0.5 0-0.4-0.6 0.3-0.6 0.6 1.2z m-4.1 1.9l-0.9 0.2-0.5-0.1 0.7-1.6-0.4-1.4-0.5-0.5-0.6-1.4 0.5-0.2 0.5 0.4 0.5 1.1 0.9 1 0.1 2-0.3 0.5z m10.1 0.7l0.6 3.3 0.3 1-0.5-0.1-1.3-0.8-0.6 0.6-0.1 0.4 0.4 1.1 0.3 0.4 0.6 1.7-1.9-2.5-0.5-2.1-0.2-1.1-0.7-1.3-0.6-1.7-1.2-2.3-1.1-1.3-0.3-0.5-0.4 0-0.9 0.9-0.4 0.2-1.1 0.1-0.8-0.8-1.2-1.9-0.2-0.4 0-1.2-0.4-1.1-1.2 0.1 0 0.4 0.8 0.1 0.4 0.6 0.2 0.8-0.7 0.5 0.2 0.4 0.4 0.6-0.4 0.1-0.1 0.8 0.5 0.8 0 0.3 0.7 1 0.2 0.4 0 0.7-1.1 1.4-0.3 0.9-0.2 0.5-0.4 0-0.6-0.4 0-0.9 0.2-0.7-0.9 0.7-0.7-0.9-0.1 1.2-0.7 0.6-0.5 0.7-0.3-1.3-0.3 1-0.5 0.6-0.7 0-0.9-0.9-0.4-1.4-0.2-1.2-1-2.3-0.3-1.3-0.5-1.3 0.3-0.9-1.2-0.5-0.1-0.2 0-0.9-0.9-1.1-0.2-0.5 0.4-2.1-0.1-1.4-0.6-0.6-0.8-0.2-0.6-0.2-0.7-0.6-0.8-0.1-0.8-0.9 0-0.5 0.4-1.3 0.9 0.2 0.3-0.5 0.2-1.4 1.6 0.1 0.8-0.2 0.2-0.6-0.8-0.5-0.4-0.8-1 0-0.9-0.5-0.6-0.8-0.7-0.6-0.6-0.1-0.4-0.5 0.1-1.3 1.3-1.8-0.1-0.4-0.6-0.5 0.3-0.4 0.6 0.3 1.1 1.1 0.1 0.4 0.8 0.2 0.8 0.1-0.4-0.8 0.5-0.3 0.6 0.7 0.1 0.7 0.6 0.6 0.6 0.4 1 0.4 0.5-0.2-0.1-0.8 0.2-0.5 0.5 0.3 0.9 1.4 0 0.7 0.5 1.8 0 1.2 0.2 0.4 2.6 0.8 1 0.1 1.6 0 3.1-0.1 1.1 0.2 3.1-0.1 0.9 0.3 1.6 1.1 0 0.6-1.1-0.3-0.1 1.4-0.4 2-0.6 0.1-0.2 1-1.2 0-0.6 0.7-0.9 0-0.1 0.4-0.5 0.7-0.1 1.6 0.1 0.5 0.7 1.4 0.6 1.7 0.2-0.6 0.3 0 0.6 1 0.7 0.4 0.4-0.2 0.3-0.6-0.3-1.2 0.1-0.5 0.7-0.9-0.2-1.1 0.6 0 0.6-0.3 0.5 0.3 0.9 2.3 0.3 1.6 0.2 0.9 1 1.6 0.5 1.7 0.6 2.8z"),
@@ -0,0 +1,369 @@
+//! Keyboard navigation on the world map: which countries share a border and
+//! which one lies next in a given direction, derived from the border paths.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use crate::map_data::get_countries_borders;
+use crate::utils::viewbox::Point;
+
+/// Vertices of two countries closer than this, in map units, make them neighbours
+const BORDER_TOLERANCE: f32 = 1.0;
+/// Candidates may deviate from the heading by up to atan(2), about 63°
+const CONE_SLOPE: f32 = 2.0;
+/// Weight of the deviation from the heading against the distance along it
+const CROSS_AXIS_WEIGHT: f32 = 2.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Heading {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Heading {
+    /// Unit vector in SVG coordinates, y grows downwards
+    fn vector(&self) -> (f32, f32) {
+        match self {
+            Heading::Up => (0.0, -1.0),
+            Heading::Down => (0.0, 1.0),
+            Heading::Left => (-1.0, 0.0),
+            Heading::Right => (1.0, 0.0),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Bounds {
+    min: (f32, f32),
+    max: (f32, f32),
+}
+
+impl Bounds {
+    fn new(point: (f32, f32)) -> Bounds {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    fn extend(&mut self, (x, y): (f32, f32)) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    fn area(&self) -> f32 {
+        (self.max.0 - self.min.0) * (self.max.1 - self.min.1)
+    }
+
+    fn center(&self) -> (f32, f32) {
+        (
+            (self.min.0 + self.max.0) / 2.0,
+            (self.min.1 + self.max.1) / 2.0,
+        )
+    }
+
+    fn overlaps(&self, other: &Bounds, margin: f32) -> bool {
+        self.min.0 - margin <= other.max.0
+            && other.min.0 - margin <= self.max.0
+            && self.min.1 - margin <= other.max.1
+            && other.min.1 - margin <= self.max.1
+    }
+}
+
+enum Token {
+    Command(char),
+    Number(f32),
+}
+
+fn tokenize(d: &str) -> Vec<Token> {
+    let bytes = d.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if c.is_ascii_alphabetic() {
+            tokens.push(Token::Command(c));
+            i += 1;
+        } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            // "-0.3-1.3" and "1.5.5" hold two numbers each
+            let start = i;
+            i += 1;
+            let mut seen_dot = c == '.';
+            while i < bytes.len() {
+                let n = bytes[i] as char;
+                if n.is_ascii_digit() || (n == '.' && !seen_dot) {
+                    seen_dot |= n == '.';
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            if let Ok(number) = d[start..i].parse() {
+                tokens.push(Token::Number(number));
+            }
+        } else {
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Absolute vertices of each subpath of an SVG path, which only uses the
+/// `M`, `m`, `L`, `l` and `z` commands
+fn parse_subpaths(d: &str) -> Vec<Vec<(f32, f32)>> {
+    let mut subpaths: Vec<Vec<(f32, f32)>> = vec![];
+    let mut command = 'M';
+    let mut current = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    let mut pending: Option<f32> = None;
+    for token in tokenize(d) {
+        let (x, y) = match (token, pending.take()) {
+            (Token::Command(c), _) => {
+                command = c;
+                if c == 'z' || c == 'Z' {
+                    current = start;
+                }
+                continue;
+            }
+            (Token::Number(x), None) => {
+                pending = Some(x);
+                continue;
+            }
+            (Token::Number(y), Some(x)) => (x, y),
+        };
+        let point = match command {
+            'm' | 'l' => (current.0 + x, current.1 + y),
+            _ => (x, y),
+        };
+        current = point;
+        match command {
+            'M' | 'm' => {
+                start = point;
+                subpaths.push(vec![point]);
+                // further pairs after a move are implicit line-tos
+                command = if command == 'm' { 'l' } else { 'L' };
+            }
+            _ => match subpaths.last_mut() {
+                Some(subpath) => subpath.push(point),
+                None => subpaths.push(vec![point]),
+            },
+        }
+    }
+    subpaths
+}
+
+type Cell = (i32, i32);
+
+fn cell((x, y): (f32, f32)) -> Cell {
+    (
+        (x / BORDER_TOLERANCE).floor() as i32,
+        (y / BORDER_TOLERANCE).floor() as i32,
+    )
+}
+
+#[derive(Debug)]
+struct Shape {
+    bounds: Bounds,
+    /// Center of the largest part, so e.g. Alaska does not pull the center
+    /// of the United States into the Pacific
+    center: (f32, f32),
+    cells: HashSet<Cell>,
+}
+
+impl Shape {
+    fn new(d: &str) -> Option<Shape> {
+        let subpaths = parse_subpaths(d);
+        let mut bounds: Option<Bounds> = None;
+        let mut largest: Option<Bounds> = None;
+        let mut cells = HashSet::new();
+        for subpath in subpaths.iter().filter(|s| !s.is_empty()) {
+            let mut part = Bounds::new(subpath[0]);
+            for point in subpath {
+                part.extend(*point);
+                cells.insert(cell(*point));
+            }
+            match bounds.as_mut() {
+                Some(bounds) => {
+                    bounds.extend(part.min);
+                    bounds.extend(part.max);
+                }
+                None => bounds = Some(part),
+            }
+            if largest.is_none_or(|l| part.area() > l.area()) {
+                largest = Some(part);
+            }
+        }
+        Some(Shape {
+            bounds: bounds?,
+            center: largest?.center(),
+            cells,
+        })
+    }
+
+    fn touches(&self, other: &Shape) -> bool {
+        if !self.bounds.overlaps(&other.bounds, BORDER_TOLERANCE) {
+            return false;
+        }
+        let (small, large) = if self.cells.len() < other.cells.len() {
+            (&self.cells, &other.cells)
+        } else {
+            (&other.cells, &self.cells)
+        };
+        small
+            .iter()
+            .any(|(x, y)| (-1..=1).any(|dx| (-1..=1).any(|dy| large.contains(&(x + dx, y + dy)))))
+    }
+}
+
+#[derive(Debug)]
+pub struct MapGraph {
+    shapes: HashMap<&'static str, Shape>,
+    neighbours: HashMap<&'static str, Vec<&'static str>>,
+}
+
+impl MapGraph {
+    pub fn new(borders: &HashMap<&'static str, &'static str>) -> MapGraph {
+        let shapes: HashMap<&'static str, Shape> = borders
+            .iter()
+            .filter_map(|(id, d)| Shape::new(d).map(|shape| (*id, shape)))
+            .collect();
+        let mut ids: Vec<&'static str> = shapes.keys().copied().collect();
+        ids.sort_unstable();
+        let mut neighbours: HashMap<&'static str, Vec<&'static str>> = HashMap::new();
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                if shapes[a].touches(&shapes[b]) {
+                    neighbours.entry(a).or_default().push(b);
+                    neighbours.entry(b).or_default().push(a);
+                }
+            }
+        }
+        MapGraph { shapes, neighbours }
+    }
+
+    /// Countries sharing a land border, or nearly touching across a narrow strait
+    pub fn neighbours(&self, id: &str) -> &[&'static str] {
+        self.neighbours.get(id).map_or(&[], |n| n.as_slice())
+    }
+
+    pub fn center(&self, id: &str) -> Option<Point> {
+        self.shapes.get(id).map(|shape| Point {
+            x: shape.center.0.round() as i32,
+            y: shape.center.1.round() as i32,
+        })
+    }
+
+    /// Country whose center is closest to `point`
+    pub fn nearest(&self, point: Point) -> Option<&'static str> {
+        let (x, y) = (point.x as f32, point.y as f32);
+        self.shapes
+            .iter()
+            .map(|(id, shape)| {
+                let (dx, dy) = (shape.center.0 - x, shape.center.1 - y);
+                (*id, dx * dx + dy * dy)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(b.0)))
+            .map(|(id, _)| id)
+    }
+
+    /// Country to move to from `from` when pressing an arrow key. Neighbours
+    /// win over closer countries without a common border, islands fall back
+    /// to the closest country in that direction.
+    pub fn next(&self, from: &str, heading: Heading) -> Option<&'static str> {
+        let origin = self.shapes.get(from)?.center;
+        let (hx, hy) = heading.vector();
+        let score = |id: &&'static str| -> Option<(f32, &'static str)> {
+            let center = self.shapes.get(*id)?.center;
+            let (dx, dy) = (center.0 - origin.0, center.1 - origin.1);
+            let along = dx * hx + dy * hy;
+            let cross = (dx * hy - dy * hx).abs();
+            if along <= 0.0 || cross > along * CONE_SLOPE {
+                return None;
+            }
+            Some((along + cross * CROSS_AXIS_WEIGHT, *id))
+        };
+        let best = |candidates: Vec<(f32, &'static str)>| {
+            candidates
+                .into_iter()
+                .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(b.1)))
+                .map(|(_, id)| id)
+        };
+        best(self.neighbours(from).iter().filter_map(score).collect()).or_else(|| {
+            best(
+                self.shapes
+                    .keys()
+                    .filter(|id| **id != from)
+                    .filter_map(score)
+                    .collect(),
+            )
+        })
+    }
+}
+
+pub fn get_map_graph() -> &'static MapGraph {
+    static MAP_GRAPH: OnceLock<MapGraph> = OnceLock::new();
+    MAP_GRAPH.get_or_init(|| MapGraph::new(&get_countries_borders()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_relative_and_absolute_commands() {
+        let subpaths = parse_subpaths("M10 10l5 0 0-5z m-2-2L1.5.5");
+        assert_eq!(
+            subpaths,
+            vec![
+                vec![(10.0, 10.0), (15.0, 10.0), (15.0, 5.0)],
+                vec![(8.0, 8.0), (1.5, 0.5)],
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_land_neighbours() {
+        let graph = get_map_graph();
+        let mut france = graph.neighbours("FR").to_vec();
+        france.sort_unstable();
+        for id in ["BE", "CH", "DE", "ES", "IT"] {
+            assert!(france.contains(&id), "{} should border FR", id);
+        }
+        assert!(!france.contains(&"PL"));
+        assert!(graph.neighbours("AU").is_empty());
+        for (id, neighbours) in &graph.neighbours {
+            for neighbour in neighbours {
+                assert!(graph.neighbours(neighbour).contains(id));
+            }
+        }
+    }
+
+    #[test]
+    fn arrow_keys_move_to_adjacent_countries() {
+        let graph = get_map_graph();
+        assert_eq!(graph.next("FR", Heading::Right), Some("CH"));
+        assert_eq!(graph.next("FR", Heading::Left), Some("ES"));
+        assert_eq!(graph.next("ES", Heading::Left), Some("PT"));
+        assert_eq!(graph.next("GB", Heading::Left), Some("IE"));
+        assert_eq!(graph.next("IN", Heading::Up), Some("NP"));
+        assert_eq!(graph.next("US", Heading::Down), Some("MX"));
+        assert_eq!(graph.next("US", Heading::Up), Some("CA"));
+    }
+
+    #[test]
+    fn islands_jump_to_nearest_country_in_direction() {
+        let graph = get_map_graph();
+        let next = graph.next("NZ", Heading::Left).unwrap();
+        assert_eq!(next, "AU");
+        assert!(graph.next("NZ", Heading::Up).is_some());
+    }
+
+    #[test]
+    fn nearest_country_to_a_point() {
+        let graph = get_map_graph();
+        let center = graph.center("BR").unwrap();
+        assert_eq!(graph.nearest(center), Some("BR"));
+    }
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::airlines::{Airline, TransportMode};
+
+/// Body measurements as defined by IATA Live Animals Regulations, Container Requirement 82
+#[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PetMeasurements {
+    /// A: tip of the nose to the root of the tail
+    pub length_cm: f32,
+    /// B: floor to the elbow joint
+    pub elbow_height_cm: f32,
+    /// C: width across the shoulders or widest point
+    pub width_cm: f32,
+    /// D: floor to the top of the head or the ear tips in natural standing position
+    pub height_cm: f32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dimensions {
+    pub length_cm: f32,
+    pub width_cm: f32,
+    pub height_cm: f32,
+}
+
+impl Dimensions {
+    const fn new(length_cm: f32, width_cm: f32, height_cm: f32) -> Dimensions {
+        Dimensions {
+            length_cm,
+            width_cm,
+            height_cm,
+        }
+    }
+
+    pub fn fits_within(&self, other: &Dimensions) -> bool {
+        self.length_cm <= other.length_cm
+            && self.width_cm <= other.width_cm
+            && self.height_cm <= other.height_cm
+    }
+}
+
+impl From<[u32; 3]> for Dimensions {
+    fn from(cm: [u32; 3]) -> Dimensions {
+        Dimensions::new(cm[0] as f32, cm[1] as f32, cm[2] as f32)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CrateSeries {
+    pub series: u32,
+    pub internal: Dimensions,
+    pub external: Dimensions,
+}
+
+/// Standard rigid plastic kennel series, approximate manufacturer dimensions
+pub const CRATE_SERIES: [CrateSeries; 7] = [
+    CrateSeries {
+        series: 100,
+        internal: Dimensions::new(48.0, 34.0, 31.0),
+        external: Dimensions::new(53.0, 41.0, 38.0),
+    },
+    CrateSeries {
+        series: 200,
+        internal: Dimensions::new(64.0, 45.0, 47.0),
+        external: Dimensions::new(71.0, 52.0, 55.0),
+    },
+    CrateSeries {
+        series: 300,
+        internal: Dimensions::new(74.0, 50.0, 53.0),
+        external: Dimensions::new(81.0, 57.0, 61.0),
+    },
+    CrateSeries {
+        series: 400,
+        internal: Dimensions::new(84.0, 56.0, 61.0),
+        external: Dimensions::new(91.0, 64.0, 69.0),
+    },
+    CrateSeries {
+        series: 500,
+        internal: Dimensions::new(94.0, 61.0, 68.0),
+        external: Dimensions::new(102.0, 69.0, 76.0),
+    },
+    CrateSeries {
+        series: 600,
+        internal: Dimensions::new(114.0, 72.0, 81.0),
+        external: Dimensions::new(122.0, 81.0, 89.0),
+    },
+    CrateSeries {
+        series: 700,
+        internal: Dimensions::new(129.0, 82.0, 91.0),
+        external: Dimensions::new(137.0, 91.0, 99.0),
+    },
+];
+
+/// CR82 formulas: length A + ½B, width 2 × C, height D
+pub fn minimum_internal_dimensions(m: &PetMeasurements) -> Dimensions {
+    Dimensions::new(
+        m.length_cm + m.elbow_height_cm / 2.0,
+        m.width_cm * 2.0,
+        m.height_cm,
+    )
+}
+
+/// Smallest standard series the pet fits in, `None` if a custom crate is needed
+pub fn recommended_crate(m: &PetMeasurements) -> Option<&'static CrateSeries> {
+    let minimum = minimum_internal_dimensions(m);
+    CRATE_SERIES
+        .iter()
+        .find(|c| minimum.fits_within(&c.internal))
+}
+
+/// Modes in which the airline carries a crate of this size
+pub fn airline_modes_for_crate(
+    crate_series: &CrateSeries,
+    airline: &Airline,
+) -> Vec<TransportMode> {
+    [TransportMode::Hold, TransportMode::Cargo]
+        .iter()
+        .copied()
+        .filter(|mode| match airline.policy(*mode) {
+            Some(policy) => policy
+                .max_container_cm
+                .map(|max| crate_series.external.fits_within(&max.into()))
+                .unwrap_or(true),
+            None => false,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::airlines::AirlineDatabase;
+
+    fn measurements(a: f32, b: f32, c: f32, d: f32) -> PetMeasurements {
+        PetMeasurements {
+            length_cm: a,
+            elbow_height_cm: b,
+            width_cm: c,
+            height_cm: d,
+        }
+    }
+
+    #[test]
+    fn applies_cr82_formulas() {
+        let dims = minimum_internal_dimensions(&measurements(60.0, 30.0, 20.0, 55.0));
+        assert_eq!(dims, Dimensions::new(75.0, 40.0, 55.0));
+    }
+
+    #[test]
+    fn small_cat_fits_series_100() {
+        let series = recommended_crate(&measurements(40.0, 12.0, 12.0, 28.0)).unwrap();
+        assert_eq!(series.series, 100);
+    }
+
+    #[test]
+    fn picks_smallest_series_that_fits_every_dimension() {
+        // length and width fit a 300, the height needs a 400
+        let series = recommended_crate(&measurements(60.0, 25.0, 22.0, 58.0)).unwrap();
+        assert_eq!(series.series, 400);
+    }
+
+    #[test]
+    fn giant_breed_needs_custom_crate() {
+        assert!(recommended_crate(&measurements(110.0, 50.0, 45.0, 95.0)).is_none());
+    }
+
+    #[test]
+    fn series_are_ordered_by_size() {
+        for pair in CRATE_SERIES.windows(2) {
+            assert!(pair[0].internal.fits_within(&pair[1].internal));
+            assert!(pair[0].external.fits_within(&pair[1].external));
+            assert!(pair[0].internal.fits_within(&pair[0].external));
+        }
+    }
+
+    #[test]
+    fn checks_crate_against_airline_limits() {
+        let db = AirlineDatabase::from_json(
+            r#"{ "airlines": [{
+                "iata": "XA", "name": "Test Air", "countries": [],
+                "cabin": null,
+                "hold": { "species": ["dog"], "max_container_cm": [102, 69, 76] },
+                "cargo": { "species": ["dog"] }
+            }] }"#,
+        )
+        .unwrap();
+        let airline = db.find("XA").unwrap();
+        let series_500 = &CRATE_SERIES[4];
+        let series_600 = &CRATE_SERIES[5];
+        assert_eq!(
+            airline_modes_for_crate(series_500, airline),
+            vec![TransportMode::Hold, TransportMode::Cargo]
+        );
+        assert_eq!(
+            airline_modes_for_crate(series_600, airline),
+            vec![TransportMode::Cargo]
+        );
+    }
+}
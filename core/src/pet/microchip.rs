@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::i18n::{Arg, Message};
+
+/// ISO 3166-1 numeric codes used as the first three digits of country coded chips
+const ISO_COUNTRY_CODES: [(u16, &str); 62] = [
+    (32, "AR"),
+    (36, "AU"),
+    (40, "AT"),
+    (56, "BE"),
+    (76, "BR"),
+    (100, "BG"),
+    (124, "CA"),
+    (152, "CL"),
+    (156, "CN"),
+    (158, "TW"),
+    (191, "HR"),
+    (196, "CY"),
+    (203, "CZ"),
+    (208, "DK"),
+    (233, "EE"),
+    (246, "FI"),
+    (250, "FR"),
+    (276, "DE"),
+    (300, "GR"),
+    (344, "HK"),
+    (348, "HU"),
+    (352, "IS"),
+    (356, "IN"),
+    (372, "IE"),
+    (376, "IL"),
+    (380, "IT"),
+    (392, "JP"),
+    (410, "KR"),
+    (428, "LV"),
+    (438, "LI"),
+    (440, "LT"),
+    (442, "LU"),
+    (458, "MY"),
+    (470, "MT"),
+    (484, "MX"),
+    (492, "MC"),
+    (498, "MD"),
+    (528, "NL"),
+    (554, "NZ"),
+    (578, "NO"),
+    (604, "PE"),
+    (608, "PH"),
+    (616, "PL"),
+    (620, "PT"),
+    (642, "RO"),
+    (643, "RU"),
+    (688, "RS"),
+    (702, "SG"),
+    (703, "SK"),
+    (704, "VN"),
+    (705, "SI"),
+    (710, "ZA"),
+    (724, "ES"),
+    (752, "SE"),
+    (756, "CH"),
+    (764, "TH"),
+    (784, "AE"),
+    (792, "TR"),
+    (804, "UA"),
+    (826, "GB"),
+    (840, "US"),
+    (858, "UY"),
+];
+
+/// ICAR manufacturer codes of the most common pet chip brands
+const MANUFACTURER_CODES: [(u16, &str); 6] = [
+    (900, "Shared manufacturer code"),
+    (956, "Trovan"),
+    (977, "AVID"),
+    (981, "Datamars"),
+    (982, "Allflex"),
+    (985, "Destron Fearing"),
+];
+
+/// What the first three digits of an ISO chip number identify
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IsoCode {
+    /// 001–899: ISO 3166 numeric code of the country the chip was issued in,
+    /// with the matching map id if we know it
+    Country(u16, Option<&'static str>),
+    /// 900–998: manufacturer code assigned by ICAR
+    Manufacturer(u16, Option<&'static str>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Microchip {
+    /// 15-digit FDX-B chip compliant with ISO 11784/11785
+    Iso { code: IsoCode, number: String },
+    /// 9-digit encrypted AVID chip, usually written as AVID*123*456*789
+    Avid { number: String },
+    /// 10 hexadecimal characters, FECAVA or Trovan unique format
+    Fecava { number: String },
+}
+
+impl Microchip {
+    pub fn is_iso(&self) -> bool {
+        matches!(self, Microchip::Iso { .. })
+    }
+
+    /// Chip kind and what its code tells about the origin
+    pub fn message(&self) -> Message {
+        match self {
+            Microchip::Iso {
+                code: IsoCode::Country(code, Some(country)),
+                ..
+            } => Message::new("chip-iso-country")
+                .arg("code", format!("{:03}", code))
+                .arg("country", Arg::Country(country.to_string())),
+            Microchip::Iso {
+                code: IsoCode::Country(code, None),
+                ..
+            } => Message::new("chip-iso-country-unknown").arg("code", format!("{:03}", code)),
+            Microchip::Iso {
+                code: IsoCode::Manufacturer(code, Some(manufacturer)),
+                ..
+            } => Message::new("chip-iso-manufacturer")
+                .arg("code", code.to_string())
+                .arg("manufacturer", *manufacturer),
+            Microchip::Iso {
+                code: IsoCode::Manufacturer(code, None),
+                ..
+            } => Message::new("chip-iso-manufacturer-unknown").arg("code", code.to_string()),
+            Microchip::Avid { .. } => Message::new("chip-avid"),
+            Microchip::Fecava { .. } => Message::new("chip-fecava"),
+        }
+    }
+}
+
+impl fmt::Display for Microchip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MicrochipError {
+    Empty,
+    InvalidCharacter(char),
+    /// Number of significant characters after removing separators
+    UnknownLength(usize),
+    /// 000 and 999 are not valid prefixes, the latter is reserved for test transponders
+    ReservedCode(u16),
+}
+
+impl MicrochipError {
+    pub fn message(&self) -> Message {
+        match self {
+            MicrochipError::Empty => Message::new("chip-error-empty"),
+            MicrochipError::InvalidCharacter(c) => {
+                Message::new("chip-error-character").arg("character", c.to_string())
+            }
+            MicrochipError::UnknownLength(len) => {
+                Message::new("chip-error-length").arg("length", *len)
+            }
+            MicrochipError::ReservedCode(code) => {
+                Message::new("chip-error-reserved").arg("code", format!("{:03}", code))
+            }
+        }
+    }
+}
+
+impl fmt::Display for MicrochipError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+fn iso_code(code: u16) -> Result<IsoCode, MicrochipError> {
+    match code {
+        1..=899 => Ok(IsoCode::Country(
+            code,
+            ISO_COUNTRY_CODES
+                .iter()
+                .find(|(c, _)| *c == code)
+                .map(|(_, id)| *id),
+        )),
+        900..=998 => Ok(IsoCode::Manufacturer(
+            code,
+            MANUFACTURER_CODES
+                .iter()
+                .find(|(c, _)| *c == code)
+                .map(|(_, name)| *name),
+        )),
+        _ => Err(MicrochipError::ReservedCode(code)),
+    }
+}
+
+/// Parses a chip number as printed on a certificate or shown by a reader.
+/// Spaces, dashes, dots and the asterisks of the AVID notation are ignored.
+pub fn parse_microchip(input: &str) -> Result<Microchip, MicrochipError> {
+    let input = input.trim();
+    let input = match input.as_bytes().get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(b"AVID") => &input[4..],
+        _ => input,
+    };
+    let mut number = String::new();
+    for c in input.chars() {
+        match c {
+            ' ' | '-' | '.' | '*' => {}
+            c if c.is_ascii_hexdigit() => number.push(c.to_ascii_uppercase()),
+            c => return Err(MicrochipError::InvalidCharacter(c)),
+        }
+    }
+    let decimal = number.chars().all(|c| c.is_ascii_digit());
+    match number.len() {
+        0 => Err(MicrochipError::Empty),
+        15 if decimal => {
+            let code = iso_code(number[..3].parse().unwrap())?;
+            Ok(Microchip::Iso { code, number })
+        }
+        9 if decimal => Ok(Microchip::Avid { number }),
+        10 => Ok(Microchip::Fecava { number }),
+        _ => Err(match number.chars().find(|c| !c.is_ascii_digit()) {
+            Some(c) => MicrochipError::InvalidCharacter(c),
+            None => MicrochipError::UnknownLength(number.len()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_country_coded_iso_chip() {
+        let chip = parse_microchip("250 26 9604 123456").unwrap();
+        assert_eq!(
+            chip,
+            Microchip::Iso {
+                code: IsoCode::Country(250, Some("FR")),
+                number: "250269604123456".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parses_manufacturer_coded_iso_chip() {
+        let chip = parse_microchip("985-141-000-123-456").unwrap();
+        assert!(chip.is_iso());
+        assert!(matches!(
+            chip,
+            Microchip::Iso {
+                code: IsoCode::Manufacturer(985, Some("Destron Fearing")),
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_microchip("933000000123456").unwrap(),
+            Microchip::Iso {
+                code: IsoCode::Manufacturer(933, None),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn flags_avid_and_fecava_formats() {
+        let avid = parse_microchip("AVID*012*345*678").unwrap();
+        assert_eq!(
+            avid,
+            Microchip::Avid {
+                number: "012345678".to_string()
+            }
+        );
+        assert!(!avid.is_iso());
+        assert_eq!(
+            parse_microchip("Avid*123*456*789").unwrap(),
+            Microchip::Avid {
+                number: "123456789".to_string()
+            }
+        );
+        let fecava = parse_microchip("00063e9a1f").unwrap();
+        assert_eq!(
+            fecava,
+            Microchip::Fecava {
+                number: "00063E9A1F".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        assert_eq!(parse_microchip("  "), Err(MicrochipError::Empty));
+        assert_eq!(
+            parse_microchip("25026960412345"),
+            Err(MicrochipError::UnknownLength(14))
+        );
+        assert_eq!(
+            parse_microchip("250269604/23456"),
+            Err(MicrochipError::InvalidCharacter('/'))
+        );
+        assert_eq!(
+            parse_microchip("2502696041234AB"),
+            Err(MicrochipError::InvalidCharacter('A'))
+        );
+        assert_eq!(
+            parse_microchip("999000000000001"),
+            Err(MicrochipError::ReservedCode(999))
+        );
+        assert_eq!(
+            parse_microchip("000123456789012"),
+            Err(MicrochipError::ReservedCode(0))
+        );
+    }
+}
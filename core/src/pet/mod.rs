@@ -0,0 +1,4 @@
+pub mod crate_size;
+pub mod microchip;
+pub mod owner;
+pub mod profile;
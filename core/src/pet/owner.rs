@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// The person the pet travels with, as they fill in certificates and permit
+/// applications. Only kept in the browser.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Owner {
+    #[serde(default)]
+    pub name: String,
+    /// Postal address, may span several lines
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub email: String,
+}
@@ -0,0 +1,74 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::crate_size::PetMeasurements;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Species {
+    #[default]
+    Dog,
+    Cat,
+    Ferret,
+}
+
+impl Species {
+    pub const ALL: [Species; 3] = [Species::Dog, Species::Cat, Species::Ferret];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Species::Dog => "dog",
+            Species::Cat => "cat",
+            Species::Ferret => "ferret",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Species> {
+        Species::ALL.iter().copied().find(|s| s.id() == id)
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Species::Dog => "Dog",
+            Species::Cat => "Cat",
+            Species::Ferret => "Ferret",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Rabies neutralising antibody test result as printed on the laboratory report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TiterResult {
+    pub sample_date: NaiveDate,
+    pub iu_per_ml: f32,
+    /// Laboratory id from the bundled approved laboratories list
+    pub laboratory: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PetProfile {
+    pub name: String,
+    pub species: Species,
+    /// Free-form breed as typed by the user, matched against the breed registry
+    pub breed: String,
+    /// Decides the age on arrival, which some countries set a minimum for
+    #[serde(default)]
+    pub birth_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub weight_kg: Option<f32>,
+    #[serde(default)]
+    pub measurements: Option<PetMeasurements>,
+    #[serde(default)]
+    pub titer: Option<TiterResult>,
+    /// Chip number as entered, parsed with `pet::microchip::parse_microchip`
+    #[serde(default)]
+    pub microchip: String,
+    #[serde(default)]
+    pub microchip_implanted: Option<NaiveDate>,
+    #[serde(default)]
+    pub rabies_vaccinations: Vec<NaiveDate>,
+}
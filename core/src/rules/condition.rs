@@ -0,0 +1,532 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::ops::Not;
+
+use crate::data::requirements::{get_requirements_database, RabiesStatus, RequirementsDatabase};
+use crate::i18n::{Arg, Message};
+use crate::pet::profile::{PetProfile, Species};
+use crate::travel::trip::Trip;
+
+/// Three-valued logic: a condition on something the user has not told us yet
+/// is neither met nor failed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Truth {
+    True,
+    False,
+    Unknown,
+}
+
+impl Truth {
+    pub fn and(self, other: Truth) -> Truth {
+        match (self, other) {
+            (Truth::False, _) | (_, Truth::False) => Truth::False,
+            (Truth::True, Truth::True) => Truth::True,
+            _ => Truth::Unknown,
+        }
+    }
+
+    pub fn or(self, other: Truth) -> Truth {
+        match (self, other) {
+            (Truth::True, _) | (_, Truth::True) => Truth::True,
+            (Truth::False, Truth::False) => Truth::False,
+            _ => Truth::Unknown,
+        }
+    }
+}
+
+impl Not for Truth {
+    type Output = Truth;
+
+    fn not(self) -> Truth {
+        match self {
+            Truth::True => Truth::False,
+            Truth::False => Truth::True,
+            Truth::Unknown => Truth::Unknown,
+        }
+    }
+}
+
+impl From<bool> for Truth {
+    fn from(value: bool) -> Truth {
+        if value {
+            Truth::True
+        } else {
+            Truth::False
+        }
+    }
+}
+
+impl From<Option<bool>> for Truth {
+    fn from(value: Option<bool>) -> Truth {
+        value.map_or(Truth::Unknown, Truth::from)
+    }
+}
+
+/// What a rule depends on, as written in `rules.json`, e.g.
+/// `{ "all": [{ "origin_in": ["AU"] }, { "not": "transit" }] }`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    #[default]
+    Always,
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+    OriginIn(Vec<String>),
+    /// Origin is a member of the bloc with this id
+    OriginInBloc(String),
+    /// Rabies status of the origin as given in the requirements dataset
+    OriginRabiesStatus(Vec<RabiesStatus>),
+    /// The destination does not ask for a titer test from the origin
+    TiterExemptOrigin,
+    Species(Vec<Species>),
+    /// Age on arrival
+    AgeUnderWeeks(u32),
+    /// At least one rabies vaccination is on record
+    Vaccinated,
+    /// The route has stopovers
+    Transit,
+    TransitThrough(Vec<String>),
+}
+
+/// How a condition came out, with the parts it is made of
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    pub message: Message,
+    pub truth: Truth,
+    pub children: Vec<Trace>,
+}
+
+fn countries(ids: &[String]) -> Arg {
+    Arg::List(ids.iter().cloned().map(Arg::Country).collect())
+}
+
+fn species_message(species: Species) -> Message {
+    Message::new(match species {
+        Species::Dog => "species-dog",
+        Species::Cat => "species-cat",
+        Species::Ferret => "species-ferret",
+    })
+}
+
+fn rabies_status_message(status: RabiesStatus) -> Message {
+    Message::new(match status {
+        RabiesStatus::Free => "rabies-free",
+        RabiesStatus::Controlled => "rabies-controlled",
+        RabiesStatus::HighIncidence => "rabies-high-incidence",
+    })
+}
+
+/// What is known about the pet and the journey into one destination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Facts {
+    pub destination: String,
+    pub origin: Option<String>,
+    pub species: Species,
+    pub age_weeks: Option<u32>,
+    pub vaccinated: Option<bool>,
+    /// Stopovers on the way, `None` when the destination is not the one
+    /// of the planned trip
+    pub transit: Option<Vec<String>>,
+}
+
+impl Facts {
+    /// Facts for entering `destination`, the pet's age taken on the arrival
+    /// day of the trip or on `today` if there is none
+    pub fn new(destination: &str, pet: &PetProfile, trip: &Trip, today: NaiveDate) -> Facts {
+        let arrival = trip.arrival_date().unwrap_or(today);
+        let age_weeks = pet
+            .birth_date
+            .filter(|birth| *birth <= arrival)
+            .map(|birth| ((arrival - birth).num_days() / 7) as u32);
+        let transit = if trip.destination.as_deref() == Some(destination) {
+            Some(trip.stopovers.clone())
+        } else {
+            None
+        };
+        Facts {
+            destination: destination.to_string(),
+            origin: trip.origin.clone().filter(|o| o != destination),
+            species: pet.species,
+            age_weeks,
+            // an empty list more likely means not entered than not vaccinated
+            vaccinated: if pet.rabies_vaccinations.is_empty() {
+                None
+            } else {
+                Some(true)
+            },
+            transit,
+        }
+    }
+}
+
+impl Condition {
+    pub fn evaluate(&self, facts: &Facts) -> Truth {
+        self.evaluate_in(facts, get_requirements_database())
+    }
+
+    /// The condition in the words of the catalogs, `destination` being the
+    /// country the rule belongs to
+    pub fn message(&self, destination: &str) -> Message {
+        match self {
+            Condition::Always => Message::new("condition-always"),
+            Condition::All(_) => Message::new("condition-all"),
+            Condition::Any(_) => Message::new("condition-any"),
+            Condition::Not(_) => Message::new("condition-not"),
+            Condition::OriginIn(ids) => {
+                Message::new("condition-origin-in").arg("countries", countries(ids))
+            }
+            Condition::OriginInBloc(bloc) => {
+                Message::new("condition-origin-in-bloc").arg("bloc", bloc.as_str())
+            }
+            Condition::OriginRabiesStatus(statuses) => {
+                Message::new("condition-origin-rabies-status").arg(
+                    "status",
+                    Arg::List(
+                        statuses
+                            .iter()
+                            .map(|s| Arg::Message(rabies_status_message(*s)))
+                            .collect(),
+                    ),
+                )
+            }
+            Condition::TiterExemptOrigin => Message::new("condition-titer-exempt-origin")
+                .arg("country", Arg::Country(destination.to_string())),
+            Condition::Species(species) => Message::new("condition-species").arg(
+                "species",
+                Arg::List(
+                    species
+                        .iter()
+                        .map(|s| Arg::Message(species_message(*s)))
+                        .collect(),
+                ),
+            ),
+            Condition::AgeUnderWeeks(weeks) => {
+                Message::new("condition-age-under-weeks").arg("weeks", *weeks)
+            }
+            Condition::Vaccinated => Message::new("condition-vaccinated"),
+            Condition::Transit => Message::new("condition-transit"),
+            Condition::TransitThrough(ids) => {
+                Message::new("condition-transit-through").arg("countries", countries(ids))
+            }
+        }
+    }
+
+    /// Evaluates the condition keeping the outcome of every part
+    pub fn trace(&self, facts: &Facts) -> Trace {
+        let parts: &[Condition] = match self {
+            Condition::All(conditions) | Condition::Any(conditions) => conditions,
+            Condition::Not(condition) => std::slice::from_ref(condition.as_ref()),
+            _ => &[],
+        };
+        let children: Vec<Trace> = parts.iter().map(|c| c.trace(facts)).collect();
+        let truth = match self {
+            Condition::All(_) => children.iter().fold(Truth::True, |t, c| t.and(c.truth)),
+            Condition::Any(_) => children.iter().fold(Truth::False, |t, c| t.or(c.truth)),
+            Condition::Not(_) => !children[0].truth,
+            _ => self.evaluate(facts),
+        };
+        Trace {
+            message: self.message(&facts.destination),
+            truth,
+            children,
+        }
+    }
+
+    fn evaluate_in(&self, facts: &Facts, db: &RequirementsDatabase) -> Truth {
+        let origin = facts.origin.as_deref();
+        match self {
+            Condition::Always => Truth::True,
+            Condition::All(conditions) => conditions
+                .iter()
+                .fold(Truth::True, |t, c| t.and(c.evaluate_in(facts, db))),
+            Condition::Any(conditions) => conditions
+                .iter()
+                .fold(Truth::False, |t, c| t.or(c.evaluate_in(facts, db))),
+            Condition::Not(condition) => !condition.evaluate_in(facts, db),
+            Condition::OriginIn(ids) => origin.map(|o| ids.iter().any(|id| id == o)).into(),
+            Condition::OriginInBloc(bloc) => origin.map(|o| db.is_member(bloc, o)).into(),
+            Condition::OriginRabiesStatus(statuses) => origin
+                .and_then(|o| db.for_country(o))
+                .and_then(|r| r.rabies_status)
+                .map(|status| statuses.contains(&status))
+                .into(),
+            Condition::TiterExemptOrigin => {
+                let titer = db.for_country(&facts.destination).and_then(|r| r.titer);
+                match (titer, origin) {
+                    (None, _) => Truth::True,
+                    (Some(titer), Some(origin)) => (!titer.applies_to(Some(origin))).into(),
+                    (Some(_), None) => Truth::Unknown,
+                }
+            }
+            Condition::Species(species) => species.contains(&facts.species).into(),
+            Condition::AgeUnderWeeks(weeks) => facts.age_weeks.map(|age| age < *weeks).into(),
+            Condition::Vaccinated => facts.vaccinated.into(),
+            Condition::Transit => facts.transit.as_ref().map(|t| !t.is_empty()).into(),
+            Condition::TransitThrough(ids) => facts
+                .transit
+                .as_ref()
+                .map(|t| t.iter().any(|stop| ids.contains(stop)))
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Truth::{False, True, Unknown};
+
+    const ALL_TRUTHS: [Truth; 3] = [True, False, Unknown];
+
+    fn facts() -> Facts {
+        Facts {
+            destination: "JP".to_string(),
+            origin: Some("US".to_string()),
+            species: Species::Dog,
+            age_weeks: Some(20),
+            vaccinated: Some(true),
+            transit: Some(vec!["KR".to_string()]),
+        }
+    }
+
+    fn unknown_facts() -> Facts {
+        Facts {
+            destination: "JP".to_string(),
+            origin: None,
+            species: Species::Cat,
+            age_weeks: None,
+            vaccinated: None,
+            transit: None,
+        }
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn eval(condition: Condition, facts: &Facts) -> Truth {
+        condition.evaluate(facts)
+    }
+
+    #[test]
+    fn kleene_and() {
+        for t in ALL_TRUTHS {
+            assert_eq!(False.and(t), False);
+            assert_eq!(t.and(False), False);
+            assert_eq!(True.and(t), t);
+            assert_eq!(t.and(True), t);
+        }
+        assert_eq!(Unknown.and(Unknown), Unknown);
+    }
+
+    #[test]
+    fn kleene_or() {
+        for t in ALL_TRUTHS {
+            assert_eq!(True.or(t), True);
+            assert_eq!(t.or(True), True);
+            assert_eq!(False.or(t), t);
+            assert_eq!(t.or(False), t);
+        }
+        assert_eq!(Unknown.or(Unknown), Unknown);
+    }
+
+    #[test]
+    fn kleene_not() {
+        assert_eq!(!True, False);
+        assert_eq!(!False, True);
+        assert_eq!(!Unknown, Unknown);
+        for t in ALL_TRUTHS {
+            assert_eq!(!!t, t);
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        let f = facts();
+        assert_eq!(eval(Condition::Always, &f), True);
+        assert_eq!(eval(Condition::All(vec![]), &f), True);
+        assert_eq!(eval(Condition::Any(vec![]), &f), False);
+        let dog = Condition::Species(vec![Species::Dog]);
+        let cat = Condition::Species(vec![Species::Cat]);
+        let young = Condition::AgeUnderWeeks(30);
+        assert_eq!(
+            eval(Condition::All(vec![dog.clone(), young.clone()]), &f),
+            True
+        );
+        assert_eq!(
+            eval(Condition::All(vec![dog.clone(), cat.clone()]), &f),
+            False
+        );
+        assert_eq!(
+            eval(Condition::Any(vec![cat.clone(), dog.clone()]), &f),
+            True
+        );
+        assert_eq!(
+            eval(Condition::Any(vec![cat.clone(), cat.clone()]), &f),
+            False
+        );
+        assert_eq!(eval(Condition::Not(Box::new(cat.clone())), &f), True);
+        let u = unknown_facts();
+        assert_eq!(
+            eval(Condition::All(vec![cat.clone(), young.clone()]), &u),
+            Unknown
+        );
+        assert_eq!(
+            eval(Condition::All(vec![dog.clone(), young.clone()]), &u),
+            False
+        );
+        assert_eq!(eval(Condition::Any(vec![cat, young.clone()]), &u), True);
+        assert_eq!(eval(Condition::Any(vec![dog, young.clone()]), &u), Unknown);
+        assert_eq!(eval(Condition::Not(Box::new(young)), &u), Unknown);
+    }
+
+    #[test]
+    fn origin_conditions() {
+        let f = facts();
+        let u = unknown_facts();
+        assert_eq!(eval(Condition::OriginIn(ids(&["US", "CA"])), &f), True);
+        assert_eq!(eval(Condition::OriginIn(ids(&["CA"])), &f), False);
+        assert_eq!(eval(Condition::OriginIn(ids(&["US"])), &u), Unknown);
+        let eu = Condition::OriginInBloc("EU".to_string());
+        assert_eq!(eval(eu.clone(), &f), False);
+        let from_fr = Facts {
+            origin: Some("FR".to_string()),
+            ..facts()
+        };
+        assert_eq!(eval(eu.clone(), &from_fr), True);
+        assert_eq!(
+            eval(Condition::OriginInBloc("XX".to_string()), &from_fr),
+            False
+        );
+        assert_eq!(eval(eu, &u), Unknown);
+    }
+
+    #[test]
+    fn origin_rabies_status() {
+        let free = Condition::OriginRabiesStatus(vec![RabiesStatus::Free]);
+        let from = |origin: &str| Facts {
+            origin: Some(origin.to_string()),
+            ..facts()
+        };
+        assert_eq!(eval(free.clone(), &from("AU")), True);
+        assert_eq!(eval(free.clone(), &from("FR")), False);
+        // no data on the origin
+        assert_eq!(eval(free.clone(), &from("BR")), Unknown);
+        assert_eq!(eval(free, &unknown_facts()), Unknown);
+    }
+
+    #[test]
+    fn titer_exempt_origin() {
+        let from = |destination: &str, origin: &str| Facts {
+            destination: destination.to_string(),
+            origin: Some(origin.to_string()),
+            ..facts()
+        };
+        assert_eq!(eval(Condition::TiterExemptOrigin, &from("JP", "AU")), True);
+        assert_eq!(eval(Condition::TiterExemptOrigin, &from("JP", "US")), False);
+        assert_eq!(eval(Condition::TiterExemptOrigin, &from("FR", "US")), True);
+        // no titer requirement known for the destination
+        assert_eq!(eval(Condition::TiterExemptOrigin, &from("BR", "US")), True);
+        assert_eq!(
+            eval(Condition::TiterExemptOrigin, &unknown_facts()),
+            Unknown
+        );
+    }
+
+    #[test]
+    fn pet_conditions() {
+        let f = facts();
+        let u = unknown_facts();
+        assert_eq!(eval(Condition::Species(vec![Species::Dog]), &f), True);
+        assert_eq!(eval(Condition::Species(vec![Species::Ferret]), &f), False);
+        assert_eq!(eval(Condition::Species(vec![Species::Dog]), &u), False);
+        assert_eq!(eval(Condition::AgeUnderWeeks(21), &f), True);
+        assert_eq!(eval(Condition::AgeUnderWeeks(20), &f), False);
+        assert_eq!(eval(Condition::AgeUnderWeeks(12), &u), Unknown);
+        assert_eq!(eval(Condition::Vaccinated, &f), True);
+        assert_eq!(eval(Condition::Vaccinated, &u), Unknown);
+    }
+
+    #[test]
+    fn transit_conditions() {
+        let f = facts();
+        let direct = Facts {
+            transit: Some(vec![]),
+            ..facts()
+        };
+        let u = unknown_facts();
+        assert_eq!(eval(Condition::Transit, &f), True);
+        assert_eq!(eval(Condition::Transit, &direct), False);
+        assert_eq!(eval(Condition::Transit, &u), Unknown);
+        assert_eq!(eval(Condition::TransitThrough(ids(&["KR"])), &f), True);
+        assert_eq!(eval(Condition::TransitThrough(ids(&["SG"])), &f), False);
+        assert_eq!(
+            eval(Condition::TransitThrough(ids(&["KR"])), &direct),
+            False
+        );
+        assert_eq!(eval(Condition::TransitThrough(ids(&["KR"])), &u), Unknown);
+    }
+
+    #[test]
+    fn traces_agree_with_evaluation() {
+        let condition = Condition::Any(vec![
+            Condition::All(vec![
+                Condition::OriginIn(ids(&["US"])),
+                Condition::Not(Box::new(Condition::Transit)),
+            ]),
+            Condition::AgeUnderWeeks(12),
+        ]);
+        for f in [facts(), unknown_facts()] {
+            let trace = condition.trace(&f);
+            assert_eq!(trace.truth, condition.evaluate(&f));
+            assert_eq!(trace.children.len(), 2);
+        }
+        let trace = condition.trace(&facts());
+        let all = &trace.children[0];
+        assert_eq!(all.truth, False);
+        assert_eq!(all.children[0].truth, True);
+        assert_eq!(all.children[1].truth, False);
+        assert_eq!(all.children[1].children[0].truth, True);
+        assert_eq!(
+            all.children[0].message,
+            Message::new("condition-origin-in")
+                .arg("countries", Arg::List(vec![Arg::Country("US".to_string())]))
+        );
+        assert_eq!(trace.children[1].truth, False);
+        assert!(trace.children[1].children.is_empty());
+    }
+
+    #[test]
+    fn facts_from_profile_and_trip() {
+        let today = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let pet = PetProfile {
+            birth_date: NaiveDate::from_ymd_opt(2024, 4, 1),
+            rabies_vaccinations: vec![NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()],
+            ..PetProfile::default()
+        };
+        let trip = Trip {
+            origin: Some("US".to_string()),
+            destination: Some("JP".to_string()),
+            stopovers: vec!["KR".to_string()],
+            departure: NaiveDate::from_ymd_opt(2024, 9, 1),
+            ..Trip::default()
+        };
+        let facts = Facts::new("JP", &pet, &trip, today);
+        assert_eq!(facts.age_weeks, Some(21));
+        assert_eq!(facts.vaccinated, Some(true));
+        assert_eq!(facts.transit, Some(vec!["KR".to_string()]));
+        // another country than the trip destination
+        let facts = Facts::new("FR", &PetProfile::default(), &trip, today);
+        assert_eq!(facts.age_weeks, None);
+        assert_eq!(facts.vaccinated, None);
+        assert_eq!(facts.transit, None);
+        assert_eq!(facts.origin.as_deref(), Some("US"));
+        // without a trip the age is taken today
+        let facts = Facts::new("FR", &pet, &Trip::default(), today);
+        assert_eq!(facts.age_weeks, Some(13));
+        assert_eq!(facts.origin, None);
+        let home = Facts::new("US", &pet, &trip, today);
+        assert_eq!(home.origin, None);
+    }
+}
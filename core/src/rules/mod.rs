@@ -0,0 +1,666 @@
+//! Entry rules declared as data: each rule pairs a condition on the origin,
+//! the pet and the route with a step the owner has to take. Evaluating them
+//! for a destination gives the concrete list of steps, including those which
+//! may apply once the user tells us more.
+
+pub mod condition;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use self::condition::{Condition, Facts, Trace, Truth};
+use crate::data::requirements::{get_requirements_database, RequirementsDatabase};
+use crate::i18n::Message;
+
+const RULES_JSON: &str = include_str!("../../data/rules.json");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Action {
+    EntryRefused,
+    MinimumAge {
+        weeks: u32,
+    },
+    Microchip {
+        /// Only ISO 11784/11785 chips are accepted
+        iso: bool,
+    },
+    RabiesVaccination {
+        /// Age of the pet at the first dose
+        min_age_weeks: u32,
+        /// Days between the last dose and entry
+        wait_days: u32,
+        #[serde(default = "one")]
+        doses: u32,
+    },
+    TiterTest {
+        min_iu_per_ml: f32,
+        /// Days between the blood sample and entry
+        wait_days: u32,
+    },
+    TapewormTreatment,
+    HealthCertificate {
+        /// Days between the vet's signature and arrival
+        issued_within_days: u32,
+    },
+    PetPassport,
+    ImportPermit,
+    /// The authorities have to be told about the arrival in advance
+    AdvanceNotice {
+        days: u32,
+    },
+    /// Owner's declaration that the pet stayed on board or in the airport
+    /// during stopovers
+    TransitDeclaration,
+    /// Zero days means the pet is released on arrival
+    Quarantine {
+        days: u32,
+    },
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl Action {
+    /// Kebab-case id, e.g. to build message ids from
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Action::EntryRefused => "entry-refused",
+            Action::MinimumAge { .. } => "minimum-age",
+            Action::Microchip { .. } => "microchip",
+            Action::RabiesVaccination { .. } => "rabies-vaccination",
+            Action::TiterTest { .. } => "titer-test",
+            Action::TapewormTreatment => "tapeworm-treatment",
+            Action::HealthCertificate { .. } => "health-certificate",
+            Action::PetPassport => "pet-passport",
+            Action::ImportPermit => "import-permit",
+            Action::AdvanceNotice { .. } => "advance-notice",
+            Action::TransitDeclaration => "transit-declaration",
+            Action::Quarantine { .. } => "quarantine",
+        }
+    }
+
+    /// What the owner has to do, in the words of the catalogs
+    pub fn message(&self) -> Message {
+        match self {
+            Action::EntryRefused => Message::new("step-entry-refused"),
+            Action::MinimumAge { weeks } => Message::new("step-minimum-age").arg("weeks", *weeks),
+            Action::Microchip { iso: true } => Message::new("step-microchip-iso"),
+            Action::Microchip { iso: false } => Message::new("step-microchip"),
+            Action::RabiesVaccination {
+                min_age_weeks,
+                wait_days,
+                doses,
+            } => Message::new("step-rabies-vaccination")
+                .arg("weeks", *min_age_weeks)
+                .arg("doses", *doses)
+                .arg("days", *wait_days),
+            Action::TiterTest {
+                min_iu_per_ml,
+                wait_days,
+            } => Message::new("step-titer-test")
+                .arg("min", *min_iu_per_ml)
+                .arg("days", *wait_days),
+            Action::TapewormTreatment => Message::new("step-tapeworm-treatment"),
+            Action::HealthCertificate { issued_within_days } => {
+                Message::new("step-health-certificate").arg("days", *issued_within_days)
+            }
+            Action::PetPassport => Message::new("step-pet-passport"),
+            Action::ImportPermit => Message::new("step-import-permit"),
+            Action::AdvanceNotice { days } => {
+                Message::new("step-advance-notice").arg("days", *days)
+            }
+            Action::TransitDeclaration => Message::new("step-transit-declaration"),
+            Action::Quarantine { days: 0 } => Message::new("step-quarantine-none"),
+            Action::Quarantine { days } => Message::new("step-quarantine").arg("days", *days),
+        }
+    }
+}
+
+/// Where a rule comes from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// Regulation and article, e.g. `Regulation (EU) No 576/2013, Art. 6`
+    pub reference: String,
+    pub url: String,
+    /// Day the rule was last checked against the source
+    pub verified: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Unique within a country, a country rule replaces the bloc rule with the same id
+    pub id: String,
+    /// Of the rules in a group only the first one whose condition holds applies
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub when: Condition,
+    pub then: Action,
+    #[serde(default)]
+    pub source: Option<Citation>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Applies,
+    /// Depends on facts we do not know
+    MayApply,
+    NotMet,
+}
+
+/// A step for the owner with the reasons for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub rule_id: String,
+    pub action: Action,
+    pub outcome: Outcome,
+    pub trace: Trace,
+    pub source: Option<Citation>,
+}
+
+impl Step {
+    pub fn certain(&self) -> bool {
+        self.outcome == Outcome::Applies
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RuleBook {
+    /// Rules shared by the members of a bloc, keyed by the bloc id of the
+    /// requirements dataset
+    #[serde(default)]
+    blocs: HashMap<String, Vec<Rule>>,
+    countries: HashMap<String, Vec<Rule>>,
+}
+
+impl RuleBook {
+    pub fn from_json(json: &str) -> serde_json::Result<RuleBook> {
+        serde_json::from_str(json)
+    }
+
+    /// Rules shared by the members of each bloc
+    pub fn blocs(&self) -> impl Iterator<Item = (&str, &[Rule])> {
+        self.blocs
+            .iter()
+            .map(|(id, rules)| (id.as_str(), rules.as_slice()))
+    }
+
+    /// Rules set for countries themselves, without those inherited from blocs
+    pub fn countries(&self) -> impl Iterator<Item = (&str, &[Rule])> {
+        self.countries
+            .iter()
+            .map(|(id, rules)| (id.as_str(), rules.as_slice()))
+    }
+
+    /// The country has rules of its own or inherited from a bloc
+    pub fn has_rules(&self, country_id: &str) -> bool {
+        !self.rules_for(country_id).is_empty()
+    }
+
+    /// Effective rules in evaluation order: the bloc's, with those the country
+    /// overrides replaced in place, followed by the country's own
+    pub fn rules_for(&self, country_id: &str) -> Vec<&Rule> {
+        self.rules_in(country_id, get_requirements_database())
+    }
+
+    /// Effective rules with bloc membership taken from `db` rather than the
+    /// bundled requirements dataset
+    pub fn rules_in(&self, country_id: &str, db: &RequirementsDatabase) -> Vec<&Rule> {
+        let own = self.countries.get(country_id).map_or(&[][..], |r| r);
+        let bloc = self
+            .blocs
+            .iter()
+            .find(|(id, _)| db.is_member(id, country_id))
+            .map_or(&[][..], |(_, rules)| rules);
+        let mut rules: Vec<&Rule> = bloc
+            .iter()
+            .map(|rule| own.iter().find(|r| r.id == rule.id).unwrap_or(rule))
+            .collect();
+        rules.extend(own.iter().filter(|r| !bloc.iter().any(|b| b.id == r.id)));
+        rules
+    }
+
+    /// Steps which apply or may apply
+    pub fn evaluate(&self, facts: &Facts) -> Vec<Step> {
+        self.explain(facts)
+            .into_iter()
+            .filter(|step| step.outcome != Outcome::NotMet)
+            .collect()
+    }
+
+    /// Every rule of the destination with its outcome, except those left out
+    /// because an earlier rule of their group applies
+    pub fn explain(&self, facts: &Facts) -> Vec<Step> {
+        let mut decided: HashSet<&str> = HashSet::new();
+        let mut steps = vec![];
+        for rule in self.rules_for(&facts.destination) {
+            let group = rule.group.as_deref();
+            if group.is_some_and(|g| decided.contains(g)) {
+                continue;
+            }
+            let trace = rule.when.trace(facts);
+            let outcome = match trace.truth {
+                Truth::True => Outcome::Applies,
+                // the rule may apply, a later one of the group still might
+                Truth::Unknown => Outcome::MayApply,
+                Truth::False => Outcome::NotMet,
+            };
+            if let (Outcome::Applies, Some(group)) = (outcome, group) {
+                decided.insert(group);
+            }
+            steps.push(Step {
+                rule_id: rule.id.clone(),
+                action: rule.then.clone(),
+                outcome,
+                trace,
+                source: rule.source.clone(),
+            });
+        }
+        steps
+    }
+}
+
+pub fn get_rule_book() -> &'static RuleBook {
+    static RULE_BOOK: OnceLock<RuleBook> = OnceLock::new();
+    RULE_BOOK.get_or_init(|| {
+        RuleBook::from_json(RULES_JSON).unwrap_or_else(|e| panic!("Malformed rules dataset: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::localizer::get_localizer;
+    use crate::i18n::Locale;
+    use crate::map_data::get_countries_names;
+    use crate::pet::profile::Species;
+
+    fn facts(destination: &str, origin: &str) -> Facts {
+        Facts {
+            destination: destination.to_string(),
+            origin: Some(origin.to_string()),
+            species: Species::Dog,
+            age_weeks: Some(52),
+            vaccinated: Some(true),
+            transit: Some(vec![]),
+        }
+    }
+
+    fn kinds(steps: &[Step]) -> Vec<&'static str> {
+        steps.iter().map(|s| s.action.kind()).collect()
+    }
+
+    fn find<'a>(steps: &'a [Step], kind: &str) -> Option<&'a Step> {
+        steps.iter().find(|s| s.action.kind() == kind)
+    }
+
+    fn book(json: &str) -> RuleBook {
+        RuleBook::from_json(json).unwrap()
+    }
+
+    #[test]
+    fn dataset_loads_and_covers_map_countries() {
+        let book = get_rule_book();
+        let names = get_countries_names();
+        for id in book.countries.keys() {
+            assert!(names.contains_key(id.as_str()), "{}", id);
+        }
+        let db = get_requirements_database();
+        for id in book.blocs.keys() {
+            assert!(db.blocs().any(|(bloc, _)| bloc == id), "{}", id);
+        }
+        for id in ["FR", "GB", "JP", "AU", "NZ", "SG"] {
+            assert!(book.has_rules(id), "{}", id);
+        }
+        assert!(!book.has_rules("BR"));
+    }
+
+    #[test]
+    fn rule_ids_are_unique() {
+        let book = get_rule_book();
+        for (id, rules) in book.blocs.iter().chain(&book.countries) {
+            let ids: HashSet<&str> = rules.iter().map(|r| r.id.as_str()).collect();
+            assert_eq!(ids.len(), rules.len(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn rules_agree_with_requirements() {
+        let book = get_rule_book();
+        let db = get_requirements_database();
+        for id in get_countries_names().keys() {
+            let requirements = match db.for_country(id) {
+                Some(r) => r,
+                None => continue,
+            };
+            for rule in book.rules_for(id) {
+                match &rule.then {
+                    Action::TiterTest {
+                        min_iu_per_ml,
+                        wait_days,
+                    } => {
+                        let titer = requirements.titer.as_ref().expect(id);
+                        assert_eq!(*wait_days, titer.wait_days, "{}", id);
+                        assert_eq!(*min_iu_per_ml, titer.min_iu_per_ml, "{}", id);
+                    }
+                    Action::Microchip { iso } => {
+                        let microchip = requirements.microchip.as_ref().expect(id);
+                        assert_eq!(*iso, microchip.iso_required, "{}", id);
+                    }
+                    // the default quarantine of a country, not a conditional one
+                    Action::Quarantine { days } if rule.when == Condition::Always => {
+                        assert_eq!(Some(*days), requirements.quarantine_days, "{}", id);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_action_and_condition_has_a_message() {
+        fn check_trace(trace: &Trace) {
+            let english = get_localizer(Locale::default());
+            assert_ne!(english.format(&trace.message), trace.message.id);
+            trace.children.iter().for_each(check_trace);
+        }
+        let english = get_localizer(Locale::default());
+        let book = get_rule_book();
+        for (id, rules) in book.blocs.iter().chain(&book.countries) {
+            for rule in rules {
+                let message = rule.then.message();
+                assert_ne!(english.format(&message), message.id, "{}", rule.id);
+                check_trace(&rule.when.trace(&facts(id, "US")));
+            }
+        }
+    }
+
+    #[test]
+    fn every_rule_cites_a_source() {
+        let book = get_rule_book();
+        for rule in book.blocs.values().chain(book.countries.values()).flatten() {
+            let source = rule.source.as_ref().expect(&rule.id);
+            assert!(source.url.starts_with("https://"), "{}", rule.id);
+            assert!(!source.reference.is_empty(), "{}", rule.id);
+        }
+    }
+
+    #[test]
+    fn explains_rules_which_do_not_apply() {
+        let steps = get_rule_book().explain(&facts("FR", "US"));
+        let titer = steps.iter().find(|s| s.rule_id == "titer-test").unwrap();
+        assert_eq!(titer.outcome, Outcome::NotMet);
+        // not required because the origin is exempt
+        assert_eq!(titer.trace.truth, Truth::False);
+        assert_eq!(titer.trace.children[0].truth, Truth::True);
+        assert_eq!(
+            titer.source.as_ref().unwrap().reference,
+            "Regulation (EU) No 576/2013, Art. 10 and Annex IV, point 2: sample at least three months before movement"
+        );
+        let evaluated = get_rule_book().evaluate(&facts("FR", "US"));
+        assert!(evaluated.iter().all(|s| s.outcome != Outcome::NotMet));
+        assert_eq!(
+            evaluated.len(),
+            steps
+                .iter()
+                .filter(|s| s.outcome != Outcome::NotMet)
+                .count()
+        );
+        // superseded rules of a group are left out, the failed ones kept
+        let nz = get_rule_book().explain(&facts("NZ", "US"));
+        let ids: Vec<&str> = nz
+            .iter()
+            .filter(|s| s.action.kind() == "quarantine")
+            .map(|s| s.rule_id.as_str())
+            .collect();
+        assert_eq!(ids, ["quarantine-australia", "quarantine"]);
+        let nz = get_rule_book().explain(&facts("NZ", "AU"));
+        assert!(!nz.iter().any(|s| s.rule_id == "quarantine"));
+    }
+
+    #[test]
+    fn country_rules_override_bloc_rules_in_place() {
+        let book = book(
+            r#"{
+                "blocs": { "EU": [
+                    { "id": "a", "then": { "kind": "pet_passport" } },
+                    { "id": "b", "then": { "kind": "quarantine", "days": 0 } }
+                ] },
+                "countries": { "FR": [
+                    { "id": "c", "then": { "kind": "import_permit" } },
+                    { "id": "a", "then": { "kind": "entry_refused" } }
+                ] }
+            }"#,
+        );
+        let ids: Vec<&str> = book.rules_for("FR").iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(book.rules_for("FR")[0].then, Action::EntryRefused);
+        // other members only see the bloc's rules
+        assert_eq!(book.rules_for("DE").len(), 2);
+        assert!(book.rules_for("US").is_empty());
+    }
+
+    #[test]
+    fn first_matching_rule_of_a_group_wins() {
+        let book = book(
+            r#"{
+                "countries": { "NZ": [
+                    { "id": "short", "group": "q", "when": { "origin_in": ["AU"] },
+                      "then": { "kind": "quarantine", "days": 0 } },
+                    { "id": "long", "group": "q", "then": { "kind": "quarantine", "days": 10 } },
+                    { "id": "other", "then": { "kind": "import_permit" } }
+                ] }
+            }"#,
+        );
+        let from_au = book.evaluate(&facts("NZ", "AU"));
+        let ids: Vec<&str> = from_au.iter().map(|s| s.rule_id.as_str()).collect();
+        assert_eq!(ids, ["short", "other"]);
+        let from_us = book.evaluate(&facts("NZ", "US"));
+        let ids: Vec<&str> = from_us.iter().map(|s| s.rule_id.as_str()).collect();
+        assert_eq!(ids, ["long", "other"]);
+        // an unknown origin leaves both quarantines open
+        let unknown = book.evaluate(&Facts {
+            origin: None,
+            ..facts("NZ", "AU")
+        });
+        let steps: Vec<(&str, bool)> = unknown
+            .iter()
+            .map(|s| (s.rule_id.as_str(), s.certain()))
+            .collect();
+        assert_eq!(steps, [("short", false), ("long", true), ("other", true)]);
+    }
+
+    #[test]
+    fn eu_titer_depends_on_origin() {
+        let from_us = get_rule_book().evaluate(&facts("FR", "US"));
+        assert!(find(&from_us, "titer-test").is_none());
+        assert!(find(&from_us, "pet-passport").is_none());
+        assert_eq!(
+            find(&from_us, "health-certificate").unwrap().action,
+            Action::HealthCertificate {
+                issued_within_days: 10
+            }
+        );
+        let from_de = get_rule_book().evaluate(&facts("FR", "DE"));
+        assert!(find(&from_de, "pet-passport").unwrap().certain());
+        assert!(find(&from_de, "health-certificate").is_none());
+        let from_br = get_rule_book().evaluate(&facts("FR", "BR"));
+        assert_eq!(
+            find(&from_br, "titer-test").unwrap().action,
+            Action::TiterTest {
+                min_iu_per_ml: 0.5,
+                wait_days: 90
+            }
+        );
+    }
+
+    #[test]
+    fn young_pets_are_refused() {
+        let puppy = Facts {
+            age_weeks: Some(10),
+            ..facts("FR", "DE")
+        };
+        let steps = get_rule_book().evaluate(&puppy);
+        assert!(find(&steps, "entry-refused").unwrap().certain());
+        let grown = get_rule_book().evaluate(&facts("FR", "DE"));
+        assert!(find(&grown, "entry-refused").is_none());
+        let unknown_age = Facts {
+            age_weeks: None,
+            ..facts("FR", "DE")
+        };
+        let steps = get_rule_book().evaluate(&unknown_age);
+        assert!(!find(&steps, "entry-refused").unwrap().certain());
+        assert!(find(&steps, "minimum-age").unwrap().certain());
+    }
+
+    #[test]
+    fn tapeworm_treatment_for_dogs_only() {
+        for id in ["GB", "IE", "FI", "MT", "NO"] {
+            let dog = get_rule_book().evaluate(&facts(id, "DE"));
+            assert!(find(&dog, "tapeworm-treatment").is_some(), "{}", id);
+            let cat = get_rule_book().evaluate(&Facts {
+                species: Species::Cat,
+                ..facts(id, "DE")
+            });
+            assert!(find(&cat, "tapeworm-treatment").is_none(), "{}", id);
+        }
+        let fr = get_rule_book().evaluate(&facts("FR", "DE"));
+        assert!(find(&fr, "tapeworm-treatment").is_none());
+    }
+
+    #[test]
+    fn transit_needs_a_declaration() {
+        let direct = get_rule_book().evaluate(&facts("FR", "US"));
+        assert!(find(&direct, "transit-declaration").is_none());
+        let via = get_rule_book().evaluate(&Facts {
+            transit: Some(vec!["AE".to_string()]),
+            ..facts("FR", "US")
+        });
+        assert!(find(&via, "transit-declaration").unwrap().certain());
+    }
+
+    #[test]
+    fn japan_quarantines_unvaccinated_pets() {
+        let vaccinated = get_rule_book().evaluate(&facts("JP", "US"));
+        assert_eq!(
+            find(&vaccinated, "quarantine").unwrap().action,
+            Action::Quarantine { days: 0 }
+        );
+        assert!(find(&vaccinated, "titer-test").is_some());
+        assert!(find(&vaccinated, "advance-notice").is_some());
+        let unvaccinated = get_rule_book().evaluate(&Facts {
+            vaccinated: Some(false),
+            ..facts("JP", "US")
+        });
+        let quarantine = find(&unvaccinated, "quarantine").unwrap();
+        assert_eq!(quarantine.action, Action::Quarantine { days: 180 });
+        assert!(quarantine.certain());
+        assert_eq!(
+            kinds(&unvaccinated)
+                .iter()
+                .filter(|k| **k == "quarantine")
+                .count(),
+            1
+        );
+        // from an exempt origin no titer test
+        let from_au = get_rule_book().evaluate(&facts("JP", "AU"));
+        assert!(find(&from_au, "titer-test").is_none());
+    }
+
+    #[test]
+    fn new_zealand_shortens_quarantine_for_direct_flights_from_australia() {
+        let direct = get_rule_book().evaluate(&facts("NZ", "AU"));
+        assert_eq!(
+            find(&direct, "quarantine").unwrap().action,
+            Action::Quarantine { days: 0 }
+        );
+        assert!(find(&direct, "import-permit").is_none());
+        let via = get_rule_book().evaluate(&Facts {
+            transit: Some(vec!["SG".to_string()]),
+            ..facts("NZ", "AU")
+        });
+        assert_eq!(
+            find(&via, "quarantine").unwrap().action,
+            Action::Quarantine { days: 10 }
+        );
+        let from_us = get_rule_book().evaluate(&facts("NZ", "US"));
+        assert_eq!(
+            find(&from_us, "quarantine").unwrap().action,
+            Action::Quarantine { days: 10 }
+        );
+        assert!(find(&from_us, "import-permit").is_some());
+    }
+
+    #[test]
+    fn australia_refuses_ferrets() {
+        let ferret = get_rule_book().evaluate(&Facts {
+            species: Species::Ferret,
+            ..facts("AU", "NZ")
+        });
+        assert!(find(&ferret, "entry-refused").unwrap().certain());
+        let dog = get_rule_book().evaluate(&facts("AU", "NZ"));
+        assert!(find(&dog, "entry-refused").is_none());
+        assert_eq!(
+            find(&dog, "quarantine").unwrap().action,
+            Action::Quarantine { days: 0 }
+        );
+        let from_us = get_rule_book().evaluate(&facts("AU", "US"));
+        assert_eq!(
+            kinds(&from_us),
+            [
+                "microchip",
+                "rabies-vaccination",
+                "titer-test",
+                "import-permit",
+                "health-certificate",
+                "quarantine"
+            ]
+        );
+    }
+
+    #[test]
+    fn singapore_quarantine_depends_on_origin() {
+        let from_gb = get_rule_book().evaluate(&facts("SG", "GB"));
+        assert_eq!(
+            find(&from_gb, "quarantine").unwrap().action,
+            Action::Quarantine { days: 0 }
+        );
+        let from_us = get_rule_book().evaluate(&facts("SG", "US"));
+        assert_eq!(
+            find(&from_us, "quarantine").unwrap().action,
+            Action::Quarantine { days: 30 }
+        );
+    }
+
+    #[test]
+    fn parses_nested_conditions() {
+        let rule: Rule = serde_json::from_str(
+            r#"{
+                "id": "direct",
+                "when": { "all": [{ "origin_in": ["AU"] }, { "not": "transit" }] },
+                "then": { "kind": "rabies_vaccination", "min_age_weeks": 12, "wait_days": 21 }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rule.when,
+            Condition::All(vec![
+                Condition::OriginIn(vec!["AU".to_string()]),
+                Condition::Not(Box::new(Condition::Transit)),
+            ])
+        );
+        assert_eq!(
+            rule.then,
+            Action::RabiesVaccination {
+                min_age_weeks: 12,
+                wait_days: 21,
+                doses: 1
+            }
+        );
+        assert_eq!(rule.group, None);
+    }
+}
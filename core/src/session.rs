@@ -0,0 +1,342 @@
+//! What the app remembers between visits: the map viewport, the open country,
+//! the pet and its owner, the trip, the chosen filters and the watchlist.
+//! Stored as JSON with a schema version, older versions are migrated step by
+//! step and anything that can not be migrated is discarded rather than half
+//! restored.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+use crate::pet::owner::Owner;
+use crate::pet::profile::PetProfile;
+use crate::travel::destination_filter::Criterion;
+use crate::travel::trip::Trip;
+use crate::travel::watchlist::Watchlist;
+use crate::utils::viewbox::{Point, ViewBox};
+
+/// Version written by this build, bump it and add a migration on every
+/// incompatible change of [`Session`]
+pub const SESSION_VERSION: u32 = 3;
+
+/// Upgrades the state of version `n + 1` to `n + 2`, where `n` is the
+/// position in the list
+type Migration = fn(Value) -> Result<Value, SessionError>;
+
+/// One entry per version after the first
+const MIGRATIONS: [Migration; 2] = [added_watchlist, added_owner];
+
+/// Version 2 added the watchlist, which defaults to empty, the bump only
+/// makes older builds refuse states which have one
+fn added_watchlist(state: Value) -> Result<Value, SessionError> {
+    Ok(state)
+}
+
+/// Version 3 added the owner details, which default to empty as well
+fn added_owner(state: Value) -> Result<Value, SessionError> {
+    Ok(state)
+}
+
+/// Map area in view, without the zoom limits which come with the build
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Viewport {
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl Viewport {
+    pub fn from_viewbox(viewbox: &ViewBox) -> Viewport {
+        Viewport {
+            x: viewbox.top_left.x,
+            y: viewbox.top_left.y,
+            w: viewbox.w,
+            h: viewbox.h,
+        }
+    }
+
+    /// The stored size is fitted into the zoom limits of `viewbox`, which may
+    /// have changed since it was saved
+    pub fn apply(&self, viewbox: &ViewBox) -> ViewBox {
+        let mut applied = ViewBox {
+            top_left: Point {
+                x: self.x,
+                y: self.y,
+            },
+            ..*viewbox
+        };
+        applied.resize(self.w as f32, self.h as f32);
+        applied
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Session {
+    #[serde(default)]
+    pub viewport: Option<Viewport>,
+    /// Country whose view is open
+    #[serde(default)]
+    pub selected_country: Option<String>,
+    /// The pet, its species decides which rules and airline policies apply
+    #[serde(default)]
+    pub profile: PetProfile,
+    /// Printed on the pre-filled certificates
+    #[serde(default)]
+    pub owner: Owner,
+    #[serde(default)]
+    pub trip: Trip,
+    #[serde(default)]
+    pub criteria: Vec<Criterion>,
+    /// Language picked by the user, `None` follows the browser
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub list_view: bool,
+    /// Countries and trips whose requirements the user is told about when they change
+    #[serde(default)]
+    pub watchlist: Watchlist,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    state: Value,
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Malformed(serde_json::Error),
+    /// Written by a newer build, which this one can not read
+    Unsupported(u32),
+    /// A migration could not make sense of the data
+    Migration(u32, String),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Malformed(e) => write!(f, "malformed session: {}", e),
+            SessionError::Unsupported(version) => {
+                write!(f, "session version {} is newer than this app", version)
+            }
+            SessionError::Migration(version, reason) => {
+                write!(f, "can not migrate session version {}: {}", version, reason)
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for SessionError {
+    fn from(e: serde_json::Error) -> SessionError {
+        SessionError::Malformed(e)
+    }
+}
+
+fn migrate(
+    mut state: Value,
+    mut version: u32,
+    migrations: &[Migration],
+) -> Result<Value, SessionError> {
+    let current = migrations.len() as u32 + 1;
+    if version == 0 || version > current {
+        return Err(SessionError::Unsupported(version));
+    }
+    while version < current {
+        state = migrations[version as usize - 1](state)?;
+        version += 1;
+    }
+    Ok(state)
+}
+
+fn upgrade_with(
+    state: Value,
+    version: u32,
+    migrations: &[Migration],
+) -> Result<Session, SessionError> {
+    let state = migrate(state, version, migrations)?;
+    Ok(serde_json::from_value(state)?)
+}
+
+fn restore_with(json: &str, migrations: &[Migration]) -> Result<Session, SessionError> {
+    let envelope: Envelope = serde_json::from_str(json)?;
+    upgrade_with(envelope.state, envelope.version, migrations)
+}
+
+impl Session {
+    pub fn to_json(&self) -> String {
+        let envelope = Envelope {
+            version: SESSION_VERSION,
+            state: serde_json::to_value(self).expect("Session is always serializable"),
+        };
+        serde_json::to_string(&envelope).expect("Session is always serializable")
+    }
+
+    /// Reads a session saved by this or an older build
+    pub fn from_json(json: &str) -> Result<Session, SessionError> {
+        restore_with(json, &MIGRATIONS)
+    }
+
+    /// State of schema `version` written by this or an older build, e.g.
+    /// taken from a backup file
+    pub fn upgrade(state: Value, version: u32) -> Result<Session, SessionError> {
+        upgrade_with(state, version, &MIGRATIONS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pet::profile::Species;
+    use serde_json::json;
+
+    fn session() -> Session {
+        Session {
+            viewport: Some(Viewport {
+                x: 100,
+                y: -50,
+                w: 800,
+                h: 800,
+            }),
+            selected_country: Some("FR".to_string()),
+            profile: PetProfile {
+                species: Species::Cat,
+                ..PetProfile::default()
+            },
+            owner: Owner {
+                name: "Anna Schmidt".to_string(),
+                ..Owner::default()
+            },
+            trip: Trip {
+                origin: Some("DE".to_string()),
+                ..Trip::default()
+            },
+            criteria: vec![Criterion::NoQuarantine],
+            locale: Some("de".to_string()),
+            list_view: true,
+            watchlist: Watchlist {
+                countries: vec!["JP".to_string()],
+                alerts: true,
+                ..Watchlist::default()
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let session = session();
+        let json = session.to_json();
+        assert!(json.starts_with(r#"{"version":3,"#));
+        assert_eq!(Session::from_json(&json).unwrap(), session);
+    }
+
+    #[test]
+    fn missing_fields_take_defaults() {
+        let restored = Session::from_json(r#"{"version":1,"state":{"list_view":true}}"#).unwrap();
+        assert!(restored.list_view);
+        assert_eq!(restored.trip, Trip::default());
+    }
+
+    #[test]
+    fn reads_sessions_of_version_1() {
+        let old = r#"{"version":1,"state":{"selected_country":"JP","list_view":true}}"#;
+        let restored = Session::from_json(old).unwrap();
+        assert_eq!(restored.selected_country.as_deref(), Some("JP"));
+        assert_eq!(restored.watchlist, Watchlist::default());
+        assert_eq!(restored.owner, Owner::default());
+    }
+
+    #[test]
+    fn discards_unreadable_sessions() {
+        assert!(matches!(
+            Session::from_json("not json"),
+            Err(SessionError::Malformed(_))
+        ));
+        assert!(matches!(
+            Session::from_json(r#"{"version":4,"state":{}}"#),
+            Err(SessionError::Unsupported(4))
+        ));
+        assert!(matches!(
+            Session::from_json(r#"{"version":0,"state":{}}"#),
+            Err(SessionError::Unsupported(0))
+        ));
+        assert!(matches!(
+            Session::from_json(r#"{"version":1,"state":{"criteria":["everywhere"]}}"#),
+            Err(SessionError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn migrates_step_by_step() {
+        fn rename_country(mut state: Value) -> Result<Value, SessionError> {
+            let country = state["country"].take();
+            state["selected_country"] = country;
+            Ok(state)
+        }
+        fn reject(_: Value) -> Result<Value, SessionError> {
+            Err(SessionError::Migration(2, "no way back".to_string()))
+        }
+        let old = json!({ "version": 1, "state": { "country": "JP" } }).to_string();
+        let restored = restore_with(&old, &[rename_country]).unwrap();
+        assert_eq!(restored.selected_country.as_deref(), Some("JP"));
+        // from version 2 only the second migration runs
+        let old = json!({ "version": 2, "state": {} }).to_string();
+        assert!(restore_with(&old, &[rename_country, reject]).is_err());
+        let current = json!({ "version": 3, "state": {} }).to_string();
+        assert!(restore_with(&current, &[rename_country, reject]).is_ok());
+    }
+
+    #[test]
+    fn viewport_respects_zoom_limits() {
+        let viewbox = ViewBox {
+            top_left: Point { x: 0, y: 0 },
+            w: 2000,
+            h: 2000,
+            zoom_in_limit: 300,
+            zoom_out_limit: 2000,
+        };
+        let saved = Viewport::from_viewbox(&ViewBox {
+            top_left: Point { x: 10, y: 20 },
+            w: 500,
+            h: 500,
+            ..viewbox
+        });
+        let restored = saved.apply(&viewbox);
+        assert_eq!(restored.top_left, Point { x: 10, y: 20 });
+        assert_eq!(restored.zoom_in_limit, 300);
+        let too_close = Viewport { w: 100, ..saved }.apply(&viewbox);
+        assert_eq!((too_close.w, too_close.h), (300, 1500));
+        let too_far = Viewport {
+            w: 4000,
+            h: 3000,
+            ..saved
+        }
+        .apply(&viewbox);
+        assert_eq!((too_far.w, too_far.h), (2000, 1500));
+    }
+
+    #[test]
+    fn viewport_at_the_zoom_limits_survives_a_reload() {
+        // the map zooms in steps of 5% between 300 and 2000
+        let map = ViewBox {
+            top_left: Point { x: 0, y: 0 },
+            w: 1280,
+            h: 720,
+            zoom_in_limit: 300,
+            zoom_out_limit: 2000,
+        };
+        let mut zoomed_in = map;
+        for _ in 0..100 {
+            zoomed_in.zoom_to_center(1.05);
+        }
+        assert_eq!(zoomed_in.h, 300);
+        assert_eq!(Viewport::from_viewbox(&zoomed_in).apply(&map), zoomed_in);
+
+        let mut zoomed_out = map;
+        for _ in 0..100 {
+            zoomed_out.zoom_to_center(0.95);
+        }
+        assert_eq!(zoomed_out.w, 2000);
+        assert_eq!(Viewport::from_viewbox(&zoomed_out).apply(&map), zoomed_out);
+    }
+}
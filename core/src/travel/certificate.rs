@@ -0,0 +1,407 @@
+//! Official certificates and permit applications pre-filled from the pet
+//! profile, the owner and the trip, so the owner arrives at the vet with the
+//! paperwork mostly done. Values are written the way the forms expect them,
+//! in English with day/month/year dates, only the page around them follows
+//! the user's language. Fields petabroad does not know are flagged for the
+//! owner, those of the official veterinarian are marked as theirs.
+
+use chrono::NaiveDate;
+
+use super::trip::Trip;
+use crate::data::airlines::get_airline_database;
+use crate::data::certificates::{CertificateTemplate, FieldSource};
+use crate::data::labs::find_laboratory;
+use crate::i18n::localizer::{get_localizer, Localizer};
+use crate::i18n::{Arg, Locale, Message};
+use crate::pet::owner::Owner;
+use crate::pet::profile::PetProfile;
+use crate::utils::html::{escape, print_page};
+
+const DATE_FORMAT: &str = "%d/%m/%Y";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    Filled(String),
+    /// Left for the owner to fill in by hand
+    Blank,
+    /// Completed by the official veterinarian
+    Veterinarian,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    pub label: String,
+    pub entry: Entry,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub title: String,
+    pub lines: Vec<Line>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Certificate {
+    pub template: CertificateTemplate,
+    /// Country the certificate is prepared for
+    pub destination: String,
+    pub prepared: NaiveDate,
+    pub parts: Vec<Part>,
+}
+
+/// What a form can be filled from
+struct Sources<'a> {
+    owner: &'a Owner,
+    pet: &'a PetProfile,
+    trip: &'a Trip,
+    destination: &'a str,
+}
+
+impl Sources<'_> {
+    fn value(&self, source: FieldSource) -> Entry {
+        let pet = self.pet;
+        let titer = pet.titer.as_ref();
+        let date = |date: Option<NaiveDate>| date.map(|d| d.format(DATE_FORMAT).to_string());
+        let text = |text: &str| Some(text.trim().to_string()).filter(|t| !t.is_empty());
+        let country = |id: Option<&str>| id.map(|id| get_localizer(Locale::En).country_name(id));
+        let value = match source {
+            FieldSource::OwnerName => text(&self.owner.name),
+            FieldSource::OwnerAddress => text(&self.owner.address),
+            FieldSource::OwnerPhone => text(&self.owner.phone),
+            FieldSource::OwnerEmail => text(&self.owner.email),
+            FieldSource::PetName => text(&pet.name),
+            FieldSource::Species => Some(pet.species.to_string()),
+            FieldSource::Breed => text(&pet.breed),
+            FieldSource::BirthDate => date(pet.birth_date),
+            FieldSource::Microchip => text(&pet.microchip),
+            FieldSource::MicrochipImplanted => date(pet.microchip_implanted),
+            FieldSource::Vaccinations => {
+                let mut dates = pet.rabies_vaccinations.clone();
+                dates.sort();
+                let dates: Vec<String> = dates
+                    .iter()
+                    .map(|d| d.format(DATE_FORMAT).to_string())
+                    .collect();
+                Some(dates.join(", ")).filter(|d| !d.is_empty())
+            }
+            FieldSource::LastVaccination => date(pet.rabies_vaccinations.iter().max().copied()),
+            FieldSource::TiterSampleDate => date(titer.map(|t| t.sample_date)),
+            FieldSource::TiterValue => titer.map(|t| t.iu_per_ml.to_string()),
+            FieldSource::TiterLaboratory => titer
+                .and_then(|t| t.laboratory.as_deref())
+                .and_then(find_laboratory)
+                .map(|l| {
+                    format!(
+                        "{}, {}, {}",
+                        l.name,
+                        l.city,
+                        country(Some(&l.country)).unwrap_or_default()
+                    )
+                }),
+            FieldSource::Origin => country(self.trip.origin.as_deref()),
+            FieldSource::Destination => country(Some(self.destination)),
+            FieldSource::Departure => date(self.trip.departure),
+            FieldSource::Arrival => self
+                .trip
+                .arrival
+                .map(|arrival| arrival.format("%d/%m/%Y %H:%M").to_string())
+                .or_else(|| date(self.trip.arrival_date())),
+            FieldSource::Airline => self
+                .trip
+                .airline
+                .as_deref()
+                .and_then(|iata| get_airline_database().find(iata))
+                .map(|a| format!("{} ({})", a.name, a.iata)),
+            FieldSource::ByHand => None,
+            FieldSource::Veterinarian => return Entry::Veterinarian,
+        };
+        value.map(Entry::Filled).unwrap_or(Entry::Blank)
+    }
+}
+
+impl Certificate {
+    /// `destination` is the country whose view the certificate is made from,
+    /// the trip may not have one yet
+    pub fn fill(
+        template: &CertificateTemplate,
+        destination: &str,
+        owner: &Owner,
+        pet: &PetProfile,
+        trip: &Trip,
+        today: NaiveDate,
+    ) -> Certificate {
+        let sources = Sources {
+            owner,
+            pet,
+            trip,
+            destination,
+        };
+        let parts = template
+            .sections
+            .iter()
+            .map(|section| Part {
+                title: section.title.clone(),
+                lines: section
+                    .fields
+                    .iter()
+                    .map(|field| Line {
+                        label: field.label.clone(),
+                        entry: sources.value(field.source),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Certificate {
+            template: template.clone(),
+            destination: destination.to_string(),
+            prepared: today,
+            parts,
+        }
+    }
+
+    fn lines(&self) -> impl Iterator<Item = (&Part, &Line)> {
+        self.parts
+            .iter()
+            .flat_map(|part| part.lines.iter().map(move |line| (part, line)))
+    }
+
+    /// Fields the owner still has to fill in by hand
+    pub fn blanks(&self) -> impl Iterator<Item = (&Part, &Line)> {
+        self.lines().filter(|(_, line)| line.entry == Entry::Blank)
+    }
+
+    /// Fields filled in and fields to fill in, without the veterinarian's
+    pub fn progress(&self) -> (usize, usize) {
+        let total = self
+            .lines()
+            .filter(|(_, line)| line.entry != Entry::Veterinarian)
+            .count();
+        (total - self.blanks().count(), total)
+    }
+
+    /// Standalone page, it prints without the app's styles or scripts
+    pub fn to_html(&self, localizer: &Localizer) -> String {
+        let template = &self.template;
+        let (filled, total) = self.progress();
+        let mut out = format!(
+            "<h1>{}</h1>\n<p class=\"reference\">{}: <a href=\"{}\">{}</a></p>\n\
+             <p class=\"notice no_print\">{}</p>\n",
+            escape(&template.title),
+            escape(&localizer.tr("certificate-form")),
+            escape(&template.url),
+            escape(&template.reference),
+            escape(
+                &localizer.format(
+                    &Message::new("certificate-prepared")
+                        .arg("date", self.prepared)
+                        .arg("country", Arg::Country(self.destination.clone()))
+                )
+            )
+        );
+
+        out += &format!(
+            "<div class=\"summary no_print\">\n<p><strong>{}</strong></p>\n",
+            escape(
+                &localizer.format(
+                    &Message::new("certificate-progress")
+                        .arg("count", filled)
+                        .arg("total", total)
+                )
+            )
+        );
+        let blanks: Vec<String> = self
+            .blanks()
+            .map(|(part, line)| {
+                format!(
+                    "<li>{} – {}</li>\n",
+                    escape(&part.title),
+                    escape(&line.label)
+                )
+            })
+            .collect();
+        if blanks.is_empty() {
+            out += &format!("<p>{}</p>\n", escape(&localizer.tr("certificate-complete")));
+        } else {
+            out += &format!(
+                "<p>{}</p>\n<ul>\n{}</ul>\n",
+                escape(&localizer.tr("certificate-blanks")),
+                blanks.concat()
+            );
+        }
+        out += "</div>\n";
+
+        for part in &self.parts {
+            out += &format!(
+                "<h2>{}</h2>\n<table class=\"fields\">\n",
+                escape(&part.title)
+            );
+            for line in &part.lines {
+                let cell = match &line.entry {
+                    Entry::Filled(value) => {
+                        format!("<td>{}</td>", escape(value).replace('\n', "<br>"))
+                    }
+                    Entry::Blank => format!(
+                        "<td class=\"blank\"><span class=\"flag no_print\">{}</span></td>",
+                        escape(&localizer.tr("certificate-blank"))
+                    ),
+                    Entry::Veterinarian => format!(
+                        "<td class=\"vet\">{}</td>",
+                        escape(&localizer.tr("certificate-veterinarian"))
+                    ),
+                };
+                out += &format!(
+                    "<tr><th scope=\"row\">{}</th>{}</tr>\n",
+                    escape(&line.label),
+                    cell
+                );
+            }
+            out += "</table>\n";
+        }
+        print_page(localizer, &template.name, CERTIFICATE_CSS, &out)
+    }
+}
+
+const CERTIFICATE_CSS: &str = "
+.reference { color: #444; }
+.notice { border-inline-start: 1mm solid #888; padding-inline-start: 3mm; }
+.summary { background: #fff4d6; padding: 2mm 4mm; }
+.summary ul { margin: 0; }
+.blank { background: #fff4d6; }
+.flag { color: #8a5a00; font-size: 9pt; }
+.vet { color: #666; font-style: italic; font-size: 9pt; }
+@media print {
+  .blank { background: none; }
+  .vet { color: #999; }
+}
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::certificates::find_template;
+    use crate::pet::profile::{Species, TiterResult};
+
+    fn pet() -> PetProfile {
+        PetProfile {
+            name: "Rex".to_string(),
+            species: Species::Dog,
+            breed: "Beagle".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(2022, 4, 2),
+            microchip: "276098106237564".to_string(),
+            rabies_vaccinations: vec![
+                NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+            ],
+            titer: Some(TiterResult {
+                sample_date: NaiveDate::from_ymd_opt(2026, 2, 15).unwrap(),
+                iu_per_ml: 2.5,
+                laboratory: None,
+            }),
+            ..PetProfile::default()
+        }
+    }
+
+    fn certificate(template: &str, owner: &Owner) -> Certificate {
+        let trip = Trip {
+            origin: Some("US".to_string()),
+            departure: NaiveDate::from_ymd_opt(2026, 12, 1),
+            ..Trip::default()
+        };
+        Certificate::fill(
+            find_template(template).unwrap(),
+            "FR",
+            owner,
+            &pet(),
+            &trip,
+            NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(),
+        )
+    }
+
+    fn entry<'a>(certificate: &'a Certificate, label: &str) -> &'a Entry {
+        &certificate
+            .lines()
+            .find(|(_, line)| line.label == label)
+            .unwrap()
+            .1
+            .entry
+    }
+
+    #[test]
+    fn fills_what_is_known() {
+        let owner = Owner {
+            name: "Anna Schmidt".to_string(),
+            address: "Hauptstraße 1\n10115 Berlin".to_string(),
+            ..Owner::default()
+        };
+        let certificate = certificate("eu-annex-iv", &owner);
+        let filled = |value: &str| Entry::Filled(value.to_string());
+        assert_eq!(
+            entry(&certificate, "I.1 Consignor: name"),
+            &filled("Anna Schmidt")
+        );
+        assert_eq!(
+            entry(&certificate, "I.7 Country of origin"),
+            &filled("United States")
+        );
+        assert_eq!(
+            entry(&certificate, "I.9 Country of destination"),
+            &filled("France")
+        );
+        assert_eq!(entry(&certificate, "Species"), &filled("Dog"));
+        assert_eq!(entry(&certificate, "Date of birth"), &filled("02/04/2022"));
+        // the latest vaccination, whatever the order they were entered in
+        assert_eq!(
+            entry(&certificate, "II.2 Date of anti-rabies vaccination"),
+            &filled("10/01/2026")
+        );
+        assert_eq!(entry(&certificate, "Sex"), &Entry::Blank);
+        assert_eq!(
+            entry(&certificate, "I.1 Consignor: telephone"),
+            &Entry::Blank
+        );
+        assert_eq!(
+            entry(&certificate, "II.2 Validity of vaccination"),
+            &Entry::Veterinarian
+        );
+    }
+
+    #[test]
+    fn counts_fields_left_to_the_owner() {
+        let empty = certificate("gb-ahc", &Owner::default());
+        let owner = Owner {
+            name: "Anna Schmidt".to_string(),
+            address: "Berlin".to_string(),
+            phone: "+49 30 1234567".to_string(),
+            email: " ".to_string(),
+        };
+        let filled = certificate("gb-ahc", &owner);
+        let (before, total) = empty.progress();
+        assert_eq!(filled.progress(), (before + 3, total));
+        assert_eq!(empty.blanks().count(), total - before);
+        assert!(filled
+            .blanks()
+            .any(|(part, line)| part.title == "Owner" && line.label == "Email"));
+    }
+
+    #[test]
+    fn html_flags_blank_fields() {
+        let owner = Owner {
+            name: "<b>Anna</b>".to_string(),
+            address: "Hauptstraße 1\n10115 Berlin".to_string(),
+            ..Owner::default()
+        };
+        let certificate = certificate("eu-annex-iv", &owner);
+        let html = certificate.to_html(get_localizer(Locale::En));
+        assert!(html.contains("<title>EU animal health certificate</title>"));
+        assert!(html.contains("&lt;b&gt;Anna&lt;/b&gt;"));
+        assert!(html.contains("Hauptstraße 1<br>10115 Berlin"));
+        assert_eq!(
+            html.matches("<td class=\"blank\">").count(),
+            certificate.blanks().count()
+        );
+        let (filled, total) = certificate.progress();
+        assert!(html.contains(&format!("{} of {} fields", filled, total)));
+        assert!(html.contains("<li>I.28 Identification of the animals – Sex</li>"));
+        assert!(html.contains("href=\"https://eur-lex.europa.eu/eli/reg_impl/2013/577/oj\""));
+    }
+}
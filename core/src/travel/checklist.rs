@@ -0,0 +1,575 @@
+//! Checklist of a trip for vets and border officials who would rather read
+//! paper: the pet, the route, every step the destination asks for with a box
+//! to tick and the rule it comes from, and how the pet's documents measure
+//! up. Built from the same rules, requirements and checks as the country
+//! view, rendered as Markdown or as a standalone HTML page made for printing.
+
+use chrono::{Duration, NaiveDate};
+
+use super::trip::Trip;
+use crate::api::RequirementsResponse;
+use crate::compliance::{microchip_findings, titer_findings, Finding, Status};
+use crate::data::airlines::get_airline_database;
+use crate::data::labs::find_laboratory;
+use crate::data::requirements::Source;
+use crate::i18n::localizer::Localizer;
+use crate::i18n::{Arg, Message};
+use crate::pet::profile::PetProfile;
+use crate::rules::condition::Facts;
+use crate::rules::{get_rule_book, Action, Citation, Outcome, Step};
+use crate::utils::html::{escape as html, print_page};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checklist {
+    pub destination: String,
+    pub pet: PetProfile,
+    pub trip: Trip,
+    /// Day the rules were evaluated for
+    pub generated: NaiveDate,
+    /// Steps which apply or may apply, in the order of the rule book
+    pub steps: Vec<Step>,
+    /// Checks of the pet's microchip and titer result
+    pub findings: Vec<Finding>,
+    pub sources: Vec<Source>,
+}
+
+/// Labelled detail of the pet or the trip, the value is empty where it is
+/// not known and left blank to be filled in by hand
+struct Field {
+    label: String,
+    value: String,
+}
+
+/// Localized line of the steps table
+struct Row {
+    step: String,
+    /// Why the step may not apply
+    note: Option<String>,
+    due: Option<String>,
+    citation: Option<Citation>,
+}
+
+impl Checklist {
+    /// `response` is the destination's requirements as the country view got them
+    pub fn new(
+        response: &RequirementsResponse,
+        pet: &PetProfile,
+        trip: &Trip,
+        today: NaiveDate,
+    ) -> Checklist {
+        let destination = response.country.id.clone();
+        let facts = Facts::new(&destination, pet, trip, today);
+        let steps = get_rule_book()
+            .explain(&facts)
+            .into_iter()
+            .filter(|step| step.outcome != Outcome::NotMet)
+            .collect();
+        let mut findings = vec![];
+        if let Some(requirements) = &response.requirements {
+            if let Some(microchip) = &requirements.microchip {
+                findings.extend(microchip_findings(microchip, pet));
+            }
+            if let Some(titer) = &requirements.titer {
+                findings.extend(titer_findings(titer, pet, trip));
+            }
+        }
+        Checklist {
+            destination,
+            pet: pet.clone(),
+            trip: trip.clone(),
+            generated: today,
+            steps,
+            findings,
+            sources: response.sources.clone(),
+        }
+    }
+
+    fn title(&self, localizer: &Localizer) -> String {
+        localizer.format(
+            &Message::new("checklist-title").arg("country", Arg::Country(self.destination.clone())),
+        )
+    }
+
+    fn generated(&self, localizer: &Localizer) -> String {
+        localizer.format(&Message::new("checklist-generated").arg("date", self.generated))
+    }
+
+    fn pet_fields(&self, localizer: &Localizer) -> Vec<Field> {
+        let pet = &self.pet;
+        let date = |date: Option<NaiveDate>| date.map(|d| localizer.date(d)).unwrap_or_default();
+        let vaccinations: Vec<String> = pet
+            .rabies_vaccinations
+            .iter()
+            .map(|d| localizer.date(*d))
+            .collect();
+        let titer = pet.titer.as_ref();
+        let laboratory = titer
+            .and_then(|t| t.laboratory.as_deref())
+            .and_then(find_laboratory)
+            .map(|l| format!("{}, {}", l.name, l.city));
+        vec![
+            field(localizer, "profile-name", pet.name.clone()),
+            field(
+                localizer,
+                "profile-species",
+                localizer.tr(&format!("species-{}", pet.species.id())),
+            ),
+            field(localizer, "profile-breed", pet.breed.clone()),
+            field(localizer, "profile-birth-date", date(pet.birth_date)),
+            field(
+                localizer,
+                "profile-weight",
+                pet.weight_kg.map(|w| w.to_string()).unwrap_or_default(),
+            ),
+            field(localizer, "profile-microchip", pet.microchip.clone()),
+            field(
+                localizer,
+                "profile-microchip-implanted",
+                date(pet.microchip_implanted),
+            ),
+            field(localizer, "profile-vaccination", vaccinations.join(", ")),
+            field(
+                localizer,
+                "profile-titer-date",
+                date(titer.map(|t| t.sample_date)),
+            ),
+            field(
+                localizer,
+                "profile-titer-value",
+                titer.map(|t| t.iu_per_ml.to_string()).unwrap_or_default(),
+            ),
+            field(
+                localizer,
+                "profile-laboratory",
+                laboratory.unwrap_or_default(),
+            ),
+        ]
+    }
+
+    fn trip_fields(&self, localizer: &Localizer) -> Vec<Field> {
+        let trip = &self.trip;
+        let country = |id: &Option<String>| {
+            id.as_ref()
+                .map(|id| localizer.country_name(id))
+                .unwrap_or_default()
+        };
+        let stopovers: Vec<String> = trip
+            .stopovers
+            .iter()
+            .map(|id| localizer.country_name(id))
+            .collect();
+        let arrival = trip
+            .arrival
+            .map(|arrival| {
+                let time = format!(
+                    "{} {}",
+                    localizer.date(arrival.date()),
+                    arrival.format("%H:%M")
+                );
+                match &trip.arrival_tz {
+                    Some(tz) => format!("{} {}", time, tz),
+                    None => time,
+                }
+            })
+            .unwrap_or_default();
+        let airline = trip
+            .airline
+            .as_deref()
+            .and_then(|iata| get_airline_database().find(iata))
+            .map(|a| format!("{} ({})", a.name, a.iata));
+        vec![
+            field(localizer, "checklist-origin", country(&trip.origin)),
+            field(localizer, "checklist-stopovers", stopovers.join(", ")),
+            field(
+                localizer,
+                "checklist-destination",
+                country(&trip.destination),
+            ),
+            field(
+                localizer,
+                "trip-departure",
+                trip.departure
+                    .map(|d| localizer.date(d))
+                    .unwrap_or_default(),
+            ),
+            field(localizer, "checklist-arrival", arrival),
+            field(localizer, "trip-airline", airline.unwrap_or_default()),
+        ]
+    }
+
+    /// When a step is due, counted back from the arrival
+    fn due(&self, action: &Action) -> Option<Message> {
+        let arrival = self.trip.arrival_date()?;
+        let before = |days: u32| arrival - Duration::days(days as i64);
+        match action {
+            Action::RabiesVaccination { wait_days, .. } | Action::TiterTest { wait_days, .. } => {
+                Some(Message::new("checklist-due-by").arg("date", before(*wait_days)))
+            }
+            Action::AdvanceNotice { days } => {
+                Some(Message::new("checklist-due-by").arg("date", before(*days)))
+            }
+            Action::HealthCertificate { issued_within_days } => {
+                Some(Message::new("checklist-due-from").arg("date", before(*issued_within_days)))
+            }
+            _ => None,
+        }
+    }
+
+    fn rows(&self, localizer: &Localizer) -> Vec<Row> {
+        self.steps
+            .iter()
+            .map(|step| Row {
+                step: localizer.format(&step.action.message()),
+                note: (step.outcome == Outcome::MayApply).then(|| localizer.tr("steps-may-apply")),
+                due: self.due(&step.action).map(|due| localizer.format(&due)),
+                citation: step.source.clone(),
+            })
+            .collect()
+    }
+
+    fn verified(localizer: &Localizer, citation: &Citation) -> String {
+        localizer.format(&Message::new("checklist-verified").arg("date", citation.verified))
+    }
+
+    pub fn to_markdown(&self, localizer: &Localizer) -> String {
+        let mut out = format!(
+            "# {}\n\n{}\n",
+            markdown(&self.title(localizer)),
+            markdown(&self.generated(localizer))
+        );
+        for (title, fields) in [
+            ("checklist-pet", self.pet_fields(localizer)),
+            ("trip-title", self.trip_fields(localizer)),
+        ] {
+            out += &format!("\n## {}\n\n", markdown(&localizer.tr(title)));
+            for field in fields {
+                let value = if field.value.is_empty() {
+                    "\\_\\_\\_\\_\\_\\_\\_\\_".to_string()
+                } else {
+                    markdown(&field.value)
+                };
+                out += &format!("- **{}:** {}\n", markdown(&field.label), value);
+            }
+        }
+
+        out += &format!("\n## {}\n\n", markdown(&localizer.tr("checklist-steps")));
+        let rows = self.rows(localizer);
+        if rows.is_empty() {
+            out += &format!("{}\n", markdown(&localizer.tr("checklist-no-steps")));
+        } else {
+            out += &format!(
+                "| {} | {} | {} | {} |\n| --- | --- | --- | --- |\n",
+                markdown(&localizer.tr("checklist-done")),
+                markdown(&localizer.tr("checklist-step")),
+                markdown(&localizer.tr("checklist-due")),
+                markdown(&localizer.tr("checklist-source")),
+            );
+            for row in rows {
+                let mut step = markdown(&row.step);
+                if let Some(note) = &row.note {
+                    step += &format!(" _{}_", markdown(note));
+                }
+                let source = match &row.citation {
+                    Some(citation) => format!(
+                        "[{}]({}), {}",
+                        markdown(&citation.reference),
+                        markdown_url(&citation.url),
+                        markdown(&Self::verified(localizer, citation))
+                    ),
+                    None => String::new(),
+                };
+                out += &format!(
+                    "| [ ] | {} | {} | {} |\n",
+                    step,
+                    markdown(&row.due.unwrap_or_default()),
+                    source
+                );
+            }
+        }
+
+        if !self.findings.is_empty() {
+            out += &format!("\n## {}\n\n", markdown(&localizer.tr("checklist-findings")));
+            for finding in &self.findings {
+                out += &format!(
+                    "- **{}:** {}\n",
+                    markdown(&localizer.tr(status_id(finding.status))),
+                    markdown(&localizer.format(&finding.message))
+                );
+            }
+        }
+
+        if !self.sources.is_empty() {
+            out += &format!("\n## {}\n\n", markdown(&localizer.tr("checklist-sources")));
+            for source in &self.sources {
+                out += &format!(
+                    "- [{}]({})\n",
+                    markdown(&source.title),
+                    markdown_url(&source.url)
+                );
+            }
+        }
+        out
+    }
+
+    /// Standalone page, it prints without the app's styles or scripts
+    pub fn to_html(&self, localizer: &Localizer) -> String {
+        let title = self.title(localizer);
+        let mut out = format!(
+            "<h1>{}</h1>\n<p class=\"generated\">{}</p>\n",
+            html(&title),
+            html(&self.generated(localizer))
+        );
+        for (title, fields) in [
+            ("checklist-pet", self.pet_fields(localizer)),
+            ("trip-title", self.trip_fields(localizer)),
+        ] {
+            out += &format!(
+                "<h2>{}</h2>\n<table class=\"fields\">\n",
+                html(&localizer.tr(title))
+            );
+            for field in fields {
+                let class = if field.value.is_empty() {
+                    " class=\"blank\""
+                } else {
+                    ""
+                };
+                out += &format!(
+                    "<tr><th scope=\"row\">{}</th><td{}>{}</td></tr>\n",
+                    html(&field.label),
+                    class,
+                    html(&field.value)
+                );
+            }
+            out += "</table>\n";
+        }
+
+        out += &format!("<h2>{}</h2>\n", html(&localizer.tr("checklist-steps")));
+        let rows = self.rows(localizer);
+        if rows.is_empty() {
+            out += &format!("<p>{}</p>\n", html(&localizer.tr("checklist-no-steps")));
+        } else {
+            out += &format!(
+                "<table class=\"steps\">\n<thead><tr><th scope=\"col\">{}</th><th scope=\"col\">{}</th>\
+                 <th scope=\"col\">{}</th><th scope=\"col\">{}</th></tr></thead>\n<tbody>\n",
+                html(&localizer.tr("checklist-done")),
+                html(&localizer.tr("checklist-step")),
+                html(&localizer.tr("checklist-due")),
+                html(&localizer.tr("checklist-source")),
+            );
+            for row in rows {
+                let note = row
+                    .note
+                    .map(|note| format!("<br><em>{}</em>", html(&note)))
+                    .unwrap_or_default();
+                let source = match &row.citation {
+                    Some(citation) => format!(
+                        "<a href=\"{}\">{}</a><br><span class=\"verified\">{}</span>",
+                        html(&citation.url),
+                        html(&citation.reference),
+                        html(&Self::verified(localizer, citation))
+                    ),
+                    None => String::new(),
+                };
+                out += &format!(
+                    "<tr><td class=\"check\"><span class=\"box\"></span></td><td>{}{}</td><td>{}</td><td>{}</td></tr>\n",
+                    html(&row.step),
+                    note,
+                    html(&row.due.unwrap_or_default()),
+                    source
+                );
+            }
+            out += "</tbody>\n</table>\n";
+        }
+
+        if !self.findings.is_empty() {
+            out += &format!(
+                "<h2>{}</h2>\n<ul class=\"findings\">\n",
+                html(&localizer.tr("checklist-findings"))
+            );
+            for finding in &self.findings {
+                out += &format!(
+                    "<li><strong>{}:</strong> {}</li>\n",
+                    html(&localizer.tr(status_id(finding.status))),
+                    html(&localizer.format(&finding.message))
+                );
+            }
+            out += "</ul>\n";
+        }
+
+        if !self.sources.is_empty() {
+            out += &format!(
+                "<h2>{}</h2>\n<ul class=\"sources\">\n",
+                html(&localizer.tr("checklist-sources"))
+            );
+            for source in &self.sources {
+                out += &format!(
+                    "<li><a href=\"{}\">{}</a></li>\n",
+                    html(&source.url),
+                    html(&source.title)
+                );
+            }
+            out += "</ul>\n";
+        }
+        print_page(localizer, &title, CHECKLIST_CSS, &out)
+    }
+}
+
+fn field(localizer: &Localizer, label: &str, value: String) -> Field {
+    Field {
+        label: localizer.tr(label),
+        value,
+    }
+}
+
+fn status_id(status: Status) -> &'static str {
+    match status {
+        Status::Satisfied => "checklist-status-satisfied",
+        Status::Pending => "checklist-status-pending",
+        Status::Violated => "checklist-status-violated",
+    }
+}
+
+/// Escapes text for Markdown, also inside table cells
+fn markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Link destination in angle brackets, so spaces and parentheses do not end
+/// it, with the characters which would still break it or the table escaped
+fn markdown_url(url: &str) -> String {
+    let mut out = String::with_capacity(url.len() + 2);
+    out.push('<');
+    for c in url.chars() {
+        match c {
+            '\\' | '<' | '>' | '|' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out.push('>');
+    out
+}
+
+const CHECKLIST_CSS: &str = "
+.generated { color: #444; }
+.check { width: 8mm; text-align: center; }
+.box { display: inline-block; width: 4mm; height: 4mm; border: 0.4mm solid #000; }
+.verified { color: #444; font-size: 9pt; }
+@media print {
+  .sources a::after { content: \" (\" attr(href) \")\"; }
+}
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::localizer::get_localizer;
+    use crate::i18n::Locale;
+    use crate::pet::profile::Species;
+
+    fn checklist(name: &str) -> Checklist {
+        let pet = PetProfile {
+            name: name.to_string(),
+            species: Species::Dog,
+            microchip: "276098106237564".to_string(),
+            rabies_vaccinations: vec![NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()],
+            ..PetProfile::default()
+        };
+        let trip = Trip {
+            origin: Some("DE".to_string()),
+            destination: Some("JP".to_string()),
+            departure: NaiveDate::from_ymd_opt(2026, 12, 1),
+            ..Trip::default()
+        };
+        let response = RequirementsResponse::from_snapshot("JP").unwrap();
+        Checklist::new(
+            &response,
+            &pet,
+            &trip,
+            NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(),
+        )
+    }
+
+    #[test]
+    fn lists_the_steps_of_the_country_view() {
+        let checklist = checklist("Rex");
+        let kinds: Vec<&str> = checklist.steps.iter().map(|s| s.action.kind()).collect();
+        assert!(kinds.contains(&"microchip"));
+        assert!(kinds.contains(&"titer-test"));
+        assert!(checklist
+            .steps
+            .iter()
+            .all(|step| step.outcome != Outcome::NotMet));
+        assert!(!checklist.findings.is_empty());
+        assert!(!checklist.sources.is_empty());
+    }
+
+    #[test]
+    fn markdown_has_a_box_per_step() {
+        let checklist = checklist("Rex");
+        let markdown = checklist.to_markdown(get_localizer(Locale::En));
+        assert!(markdown.starts_with("# Pet travel checklist: Japan\n"));
+        assert!(markdown.contains("- **Name:** Rex\n"));
+        assert!(markdown.contains("- **Microchip number:** 276098106237564\n"));
+        let boxes = markdown
+            .lines()
+            .filter(|l| l.starts_with("| [ ] |"))
+            .count();
+        assert_eq!(boxes, checklist.steps.len());
+        let titer = markdown.lines().find(|l| l.contains("titer test")).unwrap();
+        // due 180 days before the departure, there is no arrival time
+        assert!(titer.contains("By 04 Jun 2026"), "{}", titer);
+        assert!(titer.contains("](<http"), "{}", titer);
+    }
+
+    #[test]
+    fn html_escapes_user_input() {
+        let checklist = checklist("<b>Rex</b> & Co");
+        let html = checklist.to_html(get_localizer(Locale::En));
+        assert!(html.contains("&lt;b&gt;Rex&lt;/b&gt; &amp; Co"));
+        assert!(!html.contains("<b>Rex"));
+        assert!(html.contains("@media print"));
+        assert_eq!(
+            html.matches("<span class=\"box\"></span>").count(),
+            checklist.steps.len()
+        );
+        // unknown details are left blank to be filled in by hand
+        assert!(html.contains("<td class=\"blank\"></td>"));
+
+        let arabic = checklist.to_html(get_localizer(Locale::Ar));
+        assert!(arabic.contains("<html lang=\"ar\" dir=\"rtl\">"));
+    }
+
+    #[test]
+    fn markdown_escapes_user_input() {
+        assert_eq!(markdown("a|b *c*\nd"), "a\\|b \\*c\\* d");
+        assert!(checklist("Rex | Fido")
+            .to_markdown(get_localizer(Locale::En))
+            .contains("Rex \\| Fido"));
+    }
+
+    #[test]
+    fn markdown_links_keep_their_url_whole() {
+        assert_eq!(
+            markdown_url("https://example.org/a (b)|c<d>"),
+            "<https://example.org/a (b)\\|c\\<d\\>>"
+        );
+        let markdown = checklist("Rex").to_markdown(get_localizer(Locale::En));
+        assert!(markdown.contains("](<https://"), "{}", markdown);
+        assert!(!markdown.contains("](https://"));
+    }
+}
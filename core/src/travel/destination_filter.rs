@@ -0,0 +1,165 @@
+//! Criteria answering "where can I actually go with my pet?", checked against
+//! the bundled datasets. Missing data never counts as a match.
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::airlines::{get_airline_database, RouteQuery, TransportMode};
+use crate::data::breeds::get_breed_registry;
+use crate::data::requirements::get_requirements_database;
+use crate::map_data::get_countries_names;
+use crate::pet::profile::PetProfile;
+use crate::travel::trip::Trip;
+
+/// Bloc whose pet passport the passport criterion is about
+const PASSPORT_BLOC: &str = "EU";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Criterion {
+    NoQuarantine,
+    /// No titer test when coming from the trip origin
+    NoTiterTest,
+    /// The breed is neither banned nor blocked from entry
+    AcceptsBreed,
+    /// Some airline flies the pet in the cabin from the trip origin
+    CabinFromOrigin,
+    EuPetPassport,
+}
+
+impl Criterion {
+    pub const ALL: [Criterion; 5] = [
+        Criterion::NoQuarantine,
+        Criterion::NoTiterTest,
+        Criterion::AcceptsBreed,
+        Criterion::CabinFromOrigin,
+        Criterion::EuPetPassport,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Criterion::NoQuarantine => "no-quarantine",
+            Criterion::NoTiterTest => "no-titer-test",
+            Criterion::AcceptsBreed => "accepts-breed",
+            Criterion::CabinFromOrigin => "cabin-from-origin",
+            Criterion::EuPetPassport => "eu-pet-passport",
+        }
+    }
+
+    /// Checking the criterion needs the trip origin
+    pub fn needs_origin(&self) -> bool {
+        matches!(self, Criterion::NoTiterTest | Criterion::CabinFromOrigin)
+    }
+
+    pub fn matches(&self, country_id: &str, pet: &PetProfile, trip: &Trip) -> bool {
+        let requirements = get_requirements_database().for_country(country_id);
+        let origin = trip.origin.as_deref();
+        match self {
+            Criterion::NoQuarantine => requirements.and_then(|r| r.quarantine_days) == Some(0),
+            Criterion::NoTiterTest => match (requirements, origin) {
+                (Some(r), Some(origin)) => !r.titer.is_some_and(|t| t.applies_to(Some(origin))),
+                _ => false,
+            },
+            Criterion::AcceptsBreed => !get_breed_registry().is_prohibited(country_id, pet),
+            Criterion::CabinFromOrigin => origin.is_some_and(|origin| {
+                let route = RouteQuery {
+                    from: origin,
+                    to: country_id,
+                    date: trip.departure,
+                };
+                get_airline_database()
+                    .carriers_for(&route, pet)
+                    .iter()
+                    .any(|option| option.modes.contains(&TransportMode::Cabin))
+            }),
+            Criterion::EuPetPassport => {
+                get_requirements_database().accepts_passport(PASSPORT_BLOC, country_id)
+            }
+        }
+    }
+}
+
+/// Sorted ids of the map countries meeting all `criteria`
+pub fn matching_countries(criteria: &[Criterion], pet: &PetProfile, trip: &Trip) -> Vec<String> {
+    let mut ids: Vec<String> = get_countries_names()
+        .keys()
+        .filter(|id| criteria.iter().all(|c| c.matches(id, pet, trip)))
+        .map(|id| id.to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trip_from(origin: &str) -> Trip {
+        Trip {
+            origin: Some(origin.to_string()),
+            ..Trip::default()
+        }
+    }
+
+    fn dog(breed: &str, weight_kg: f32) -> PetProfile {
+        PetProfile {
+            breed: breed.to_string(),
+            weight_kg: Some(weight_kg),
+            ..PetProfile::default()
+        }
+    }
+
+    #[test]
+    fn quarantine_and_titer_need_data() {
+        let pet = dog("Beagle", 5.0);
+        let trip = trip_from("US");
+        assert!(Criterion::NoQuarantine.matches("FR", &pet, &trip));
+        assert!(!Criterion::NoQuarantine.matches("AU", &pet, &trip));
+        assert!(!Criterion::NoQuarantine.matches("BR", &pet, &trip));
+        // the US is on the EU list of exempt origins, not on Japan's
+        assert!(Criterion::NoTiterTest.matches("FR", &pet, &trip));
+        assert!(!Criterion::NoTiterTest.matches("JP", &pet, &trip));
+        assert!(!Criterion::NoTiterTest.matches("FR", &pet, &Trip::default()));
+    }
+
+    #[test]
+    fn breed_bans_exclude_countries() {
+        let trip = Trip::default();
+        let blocked = get_breed_registry().prohibited_countries(&dog("Pit Bull Terrier", 20.0));
+        assert!(!blocked.is_empty());
+        for id in &blocked {
+            assert!(!Criterion::AcceptsBreed.matches(id, &dog("Pit Bull Terrier", 20.0), &trip));
+            assert!(Criterion::AcceptsBreed.matches(id, &dog("Beagle", 5.0), &trip));
+        }
+    }
+
+    #[test]
+    fn cabin_needs_an_airline_from_origin() {
+        let small = dog("Beagle", 5.0);
+        let large = dog("Beagle", 30.0);
+        let trip = trip_from("DE");
+        assert!(Criterion::CabinFromOrigin.matches("AT", &small, &trip));
+        assert!(!Criterion::CabinFromOrigin.matches("AT", &large, &trip));
+        assert!(!Criterion::CabinFromOrigin.matches("DE", &small, &trip));
+        assert!(!Criterion::CabinFromOrigin.matches("AT", &small, &Trip::default()));
+    }
+
+    #[test]
+    fn all_criteria_have_to_match() {
+        let pet = dog("Beagle", 5.0);
+        let trip = trip_from("US");
+        let passport = matching_countries(&[Criterion::EuPetPassport], &pet, &trip);
+        assert!(passport.contains(&"CH".to_string()));
+        assert!(passport.contains(&"FR".to_string()));
+        let both = matching_countries(
+            &[Criterion::EuPetPassport, Criterion::NoQuarantine],
+            &pet,
+            &trip,
+        );
+        assert!(both.contains(&"FR".to_string()));
+        assert!(!both.contains(&"CH".to_string()));
+        assert_eq!(
+            matching_countries(&[], &pet, &trip).len(),
+            get_countries_names().len()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod certificate;
+pub mod checklist;
+pub mod destination_filter;
+pub mod tapeworm;
+pub mod trip;
+pub mod watchlist;
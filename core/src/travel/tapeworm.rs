@@ -0,0 +1,216 @@
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+
+use super::trip::Trip;
+use crate::pet::profile::Species;
+
+/// Countries requiring an Echinococcus multilocularis treatment for dogs before arrival
+pub const TAPEWORM_TREATMENT_COUNTRIES: [&str; 5] = ["GB", "IE", "FI", "MT", "NO"];
+
+/// The treatment must be given no more than 120 and no less than 24 hours before arrival
+pub const EARLIEST_HOURS_BEFORE_ARRIVAL: i64 = 120;
+pub const LATEST_HOURS_BEFORE_ARRIVAL: i64 = 24;
+
+pub fn requires_tapeworm_treatment(country_id: &str, species: Species) -> bool {
+    species == Species::Dog && TAPEWORM_TREATMENT_COUNTRIES.contains(&country_id)
+}
+
+/// Time zone the arrival time is most likely given in
+pub fn default_time_zone(country_id: &str) -> Option<Tz> {
+    match country_id {
+        "GB" => Some(Tz::Europe__London),
+        "IE" => Some(Tz::Europe__Dublin),
+        "FI" => Some(Tz::Europe__Helsinki),
+        "MT" => Some(Tz::Europe__Malta),
+        "NO" => Some(Tz::Europe__Oslo),
+        _ => None,
+    }
+}
+
+/// Country a treatment has to be planned for when viewing another one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreatmentStop {
+    pub country: String,
+    /// Whether `Trip::arrival` is the arrival in `country`, the trip only
+    /// keeps the arrival time at its final destination
+    pub timed: bool,
+}
+
+/// Treatment the view of `shown` has to plan: the one for `shown` itself,
+/// or, when `shown` is on the trip, the first stopover or destination which
+/// requires it. Leaving the origin needs no treatment.
+pub fn treatment_stop(trip: &Trip, shown: &str, species: Species) -> Option<TreatmentStop> {
+    let stop = |country: &str| TreatmentStop {
+        country: country.to_string(),
+        timed: trip.destination.as_deref().is_none_or(|d| d == country),
+    };
+    if requires_tapeworm_treatment(shown, species) {
+        return Some(stop(shown));
+    }
+    if !trip.route().contains(&shown) {
+        return None;
+    }
+    trip.stopovers
+        .iter()
+        .chain(&trip.destination)
+        .find(|id| requires_tapeworm_treatment(id, species))
+        .map(|id| stop(id))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreatmentWindow {
+    pub earliest: DateTime<Tz>,
+    pub latest: DateTime<Tz>,
+}
+
+/// Valid vet visit window for an arrival at `arrival` local time in `arrival_tz`,
+/// expressed in the vet's time zone. `None` if the arrival time does not exist
+/// in that zone (skipped by a daylight saving transition).
+pub fn treatment_window(
+    arrival: NaiveDateTime,
+    arrival_tz: Tz,
+    vet_tz: Tz,
+) -> Option<TreatmentWindow> {
+    let arrival = arrival_tz.from_local_datetime(&arrival).earliest()?;
+    Some(TreatmentWindow {
+        earliest: (arrival - Duration::hours(EARLIEST_HOURS_BEFORE_ARRIVAL)).with_timezone(&vet_tz),
+        latest: (arrival - Duration::hours(LATEST_HOURS_BEFORE_ARRIVAL)).with_timezone(&vet_tz),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn only_dogs_need_treatment() {
+        assert!(requires_tapeworm_treatment("GB", Species::Dog));
+        assert!(!requires_tapeworm_treatment("GB", Species::Cat));
+        assert!(!requires_tapeworm_treatment("FR", Species::Dog));
+    }
+
+    fn trip(origin: &str, stopovers: &[&str], destination: &str) -> Trip {
+        Trip {
+            origin: Some(origin.to_string()),
+            stopovers: stopovers.iter().map(|s| s.to_string()).collect(),
+            destination: Some(destination.to_string()),
+            ..Trip::default()
+        }
+    }
+
+    #[test]
+    fn leaving_the_origin_needs_no_treatment() {
+        let trip = trip("GB", &[], "FR");
+        assert_eq!(treatment_stop(&trip, "FR", Species::Dog), None);
+        // the view of the origin itself still tells how to enter it
+        assert_eq!(
+            treatment_stop(&trip, "GB", Species::Dog),
+            Some(TreatmentStop {
+                country: "GB".to_string(),
+                timed: false
+            })
+        );
+    }
+
+    #[test]
+    fn destination_is_timed_by_the_trip_arrival() {
+        let trip = trip("FR", &[], "GB");
+        let stop = Some(TreatmentStop {
+            country: "GB".to_string(),
+            timed: true,
+        });
+        assert_eq!(treatment_stop(&trip, "FR", Species::Dog), stop);
+        assert_eq!(treatment_stop(&trip, "GB", Species::Dog), stop);
+        assert_eq!(treatment_stop(&trip, "FR", Species::Cat), None);
+        assert_eq!(treatment_stop(&trip, "DE", Species::Dog), None);
+    }
+
+    #[test]
+    fn stopover_has_no_arrival_time() {
+        let trip = trip("FR", &["IE"], "US");
+        let stop = Some(TreatmentStop {
+            country: "IE".to_string(),
+            timed: false,
+        });
+        assert_eq!(treatment_stop(&trip, "FR", Species::Dog), stop);
+        assert_eq!(treatment_stop(&trip, "US", Species::Dog), stop);
+        assert_eq!(treatment_stop(&trip, "IE", Species::Dog), stop);
+    }
+
+    #[test]
+    fn country_off_the_trip_is_timed_without_a_destination() {
+        assert!(
+            treatment_stop(&Trip::default(), "NO", Species::Dog)
+                .unwrap()
+                .timed
+        );
+        assert!(
+            !treatment_stop(&trip("FR", &[], "DE"), "NO", Species::Dog)
+                .unwrap()
+                .timed
+        );
+    }
+
+    #[test]
+    fn window_in_arrival_time_zone() {
+        let window = treatment_window(
+            local(2024, 3, 10, 14, 0),
+            Tz::Europe__London,
+            Tz::Europe__London,
+        )
+        .unwrap();
+        assert_eq!(window.earliest.naive_local(), local(2024, 3, 5, 14, 0));
+        assert_eq!(window.latest.naive_local(), local(2024, 3, 9, 14, 0));
+    }
+
+    #[test]
+    fn window_converted_to_vet_time_zone() {
+        // vet in New York, 5 hours behind London in January
+        let window = treatment_window(
+            local(2024, 1, 20, 8, 0),
+            Tz::Europe__London,
+            Tz::America__New_York,
+        )
+        .unwrap();
+        assert_eq!(window.earliest.naive_local(), local(2024, 1, 15, 3, 0));
+        assert_eq!(window.latest.naive_local(), local(2024, 1, 19, 3, 0));
+    }
+
+    #[test]
+    fn window_counts_real_hours_across_dst_change() {
+        // clocks go forward in Helsinki on 31 March 2024, 120 hours before is 09:00, not 10:00
+        let window = treatment_window(
+            local(2024, 4, 2, 10, 0),
+            Tz::Europe__Helsinki,
+            Tz::Europe__Helsinki,
+        )
+        .unwrap();
+        assert_eq!(window.earliest.naive_local(), local(2024, 3, 28, 9, 0));
+        assert_eq!(window.latest.naive_local(), local(2024, 4, 1, 10, 0));
+    }
+
+    #[test]
+    fn nonexistent_arrival_time_has_no_window() {
+        assert!(treatment_window(
+            local(2024, 3, 31, 1, 30),
+            Tz::Europe__London,
+            Tz::Europe__London
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn window_is_four_days_long() {
+        let window =
+            treatment_window(local(2024, 6, 1, 12, 0), Tz::Europe__Oslo, Tz::Asia__Tokyo).unwrap();
+        assert_eq!(window.latest - window.earliest, Duration::hours(96));
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Trip {
+    /// Country ids as used in `map_data`
+    pub origin: Option<String>,
+    pub destination: Option<String>,
+    #[serde(default)]
+    pub stopovers: Vec<String>,
+    pub departure: Option<NaiveDate>,
+    /// Scheduled arrival, local time at the destination
+    pub arrival: Option<NaiveDateTime>,
+    /// IANA time zone name of `arrival`
+    pub arrival_tz: Option<String>,
+    /// IATA code of the chosen carrier
+    pub airline: Option<String>,
+}
+
+impl Trip {
+    /// Country ids from origin through stopovers to destination
+    pub fn route(&self) -> Vec<&str> {
+        self.origin
+            .iter()
+            .chain(self.stopovers.iter())
+            .chain(self.destination.iter())
+            .map(|id| id.as_str())
+            .collect()
+    }
+
+    /// Day of arrival, falling back to the departure day when no arrival is scheduled
+    pub fn arrival_date(&self) -> Option<NaiveDate> {
+        self.arrival.map(|a| a.date()).or(self.departure)
+    }
+}
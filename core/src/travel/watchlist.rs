@@ -0,0 +1,240 @@
+//! Countries and trips the user keeps an eye on while preparing. The
+//! requirements of every watched country are remembered as the user last saw
+//! them, each data refresh compares them with the server's and lists what
+//! changed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::trip::Trip;
+use crate::client::{DataClient, Origin, ResponseCache, Transport};
+use crate::data::history::{changes, FieldChange};
+use crate::data::requirements::CountryRequirements;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Watchlist {
+    /// Country ids as used in `map_data`
+    #[serde(default)]
+    pub countries: Vec<String>,
+    /// Trips whose whole route is watched
+    #[serde(default)]
+    pub trips: Vec<Trip>,
+    /// Requirements of each watched country as the user last saw them,
+    /// `None` where there was no data on the country
+    #[serde(default)]
+    pub seen: BTreeMap<String, Option<CountryRequirements>>,
+    /// Also alert through the browser's notifications
+    #[serde(default)]
+    pub alerts: bool,
+}
+
+/// Requirements of a watched country which changed since the user last saw them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notice {
+    pub country: String,
+    pub changes: Vec<FieldChange>,
+    /// Requirements now, remembered as seen once the notice is dismissed
+    pub requirements: Option<CountryRequirements>,
+}
+
+impl Watchlist {
+    pub fn is_empty(&self) -> bool {
+        self.countries.is_empty() && self.trips.is_empty()
+    }
+
+    /// Countries watched on their own or on the route of a trip, each once
+    pub fn watched(&self) -> Vec<&str> {
+        let trips = self.trips.iter().flat_map(|trip| trip.route());
+        let ids: BTreeSet<&str> = self
+            .countries
+            .iter()
+            .map(|id| id.as_str())
+            .chain(trips)
+            .collect();
+        ids.into_iter().collect()
+    }
+
+    pub fn watches_country(&self, id: &str) -> bool {
+        self.countries.iter().any(|c| c == id)
+    }
+
+    pub fn toggle_country(&mut self, id: &str) {
+        if self.watches_country(id) {
+            self.countries.retain(|c| c != id);
+        } else {
+            self.countries.push(id.to_string());
+        }
+        self.forget_unwatched();
+    }
+
+    pub fn watches_trip(&self, trip: &Trip) -> bool {
+        self.trips.contains(trip)
+    }
+
+    /// Trips without any country on their route are not watched
+    pub fn toggle_trip(&mut self, trip: &Trip) {
+        if self.watches_trip(trip) {
+            self.trips.retain(|t| t != trip);
+        } else if !trip.route().is_empty() {
+            self.trips.push(trip.clone());
+        }
+        self.forget_unwatched();
+    }
+
+    /// Watched trips passing through `country`
+    pub fn trips_through<'a>(&'a self, country: &'a str) -> impl Iterator<Item = &'a Trip> {
+        self.trips
+            .iter()
+            .filter(move |trip| trip.route().contains(&country))
+    }
+
+    /// A country watched again later starts over rather than reporting
+    /// everything changed while it was not watched
+    fn forget_unwatched(&mut self) {
+        let watched: BTreeSet<String> = self.watched().into_iter().map(String::from).collect();
+        self.seen.retain(|id, _| watched.contains(id));
+    }
+
+    /// Requirements of every watched country as the server has them now.
+    /// Countries the server could not be asked about are left out, cached
+    /// or compiled-in data may be older than what the user already saw.
+    pub async fn fetch<T: Transport, C: ResponseCache>(
+        &self,
+        client: &DataClient<T, C>,
+    ) -> Vec<(String, Option<CountryRequirements>)> {
+        let mut current = vec![];
+        for id in self.watched() {
+            if let Ok(fetched) = client.requirements(id).await {
+                if fetched.origin == Origin::Server {
+                    current.push((id.to_string(), fetched.data.requirements));
+                }
+            }
+        }
+        current
+    }
+
+    /// Compares fetched requirements with those last seen. Countries seen for
+    /// the first time are only remembered, countries which are no longer
+    /// watched are skipped.
+    pub fn compare(&mut self, current: Vec<(String, Option<CountryRequirements>)>) -> Vec<Notice> {
+        let watched: BTreeSet<String> = self.watched().into_iter().map(String::from).collect();
+        let mut notices = vec![];
+        for (id, requirements) in current {
+            if !watched.contains(&id) {
+                continue;
+            }
+            let seen = match self.seen.get(&id) {
+                Some(seen) => seen,
+                None => {
+                    self.seen.insert(id, requirements);
+                    continue;
+                }
+            };
+            let changes = changes(seen.as_ref(), requirements.as_ref());
+            if !changes.is_empty() {
+                notices.push(Notice {
+                    country: id,
+                    changes,
+                    requirements,
+                });
+            }
+        }
+        notices
+    }
+
+    /// Remembers the requirements of the notice as seen, it is not reported again
+    pub fn dismiss(&mut self, notice: &Notice) {
+        if self.seen.contains_key(&notice.country) {
+            self.seen
+                .insert(notice.country.clone(), notice.requirements.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::requirements::get_requirements_database;
+    use serde_json::json;
+
+    fn trip(origin: &str, destination: &str) -> Trip {
+        Trip {
+            origin: Some(origin.to_string()),
+            destination: Some(destination.to_string()),
+            ..Trip::default()
+        }
+    }
+
+    fn requirements(id: &str) -> Option<CountryRequirements> {
+        get_requirements_database().for_country(id)
+    }
+
+    #[test]
+    fn watches_countries_and_trip_routes() {
+        let mut watchlist = Watchlist::default();
+        assert!(watchlist.is_empty());
+        watchlist.toggle_country("JP");
+        watchlist.toggle_trip(&trip("DE", "JP"));
+        watchlist.toggle_trip(&Trip::default());
+        assert_eq!(watchlist.watched(), vec!["DE", "JP"]);
+        assert_eq!(watchlist.trips.len(), 1);
+        assert_eq!(watchlist.trips_through("DE").count(), 1);
+        assert_eq!(watchlist.trips_through("FR").count(), 0);
+
+        watchlist.compare(vec![
+            ("DE".to_string(), requirements("DE")),
+            ("JP".to_string(), requirements("JP")),
+        ]);
+        assert_eq!(watchlist.seen.len(), 2);
+        // JP stays watched on its own
+        watchlist.toggle_trip(&trip("DE", "JP"));
+        assert_eq!(watchlist.watched(), vec!["JP"]);
+        assert_eq!(watchlist.seen.keys().collect::<Vec<_>>(), vec!["JP"]);
+    }
+
+    #[test]
+    fn notices_changes_until_dismissed() {
+        let mut watchlist = Watchlist::default();
+        watchlist.toggle_country("JP");
+        assert!(watchlist
+            .compare(vec![("JP".to_string(), requirements("JP"))])
+            .is_empty());
+
+        let mut changed = requirements("JP").unwrap();
+        changed.quarantine_days = Some(14);
+        let current = vec![
+            ("JP".to_string(), Some(changed.clone())),
+            ("FR".to_string(), None),
+        ];
+        let notices = watchlist.compare(current.clone());
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].country, "JP");
+        let fields: Vec<&str> = notices[0]
+            .changes
+            .iter()
+            .map(|c| c.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["quarantine_days"]);
+        assert_eq!(notices[0].changes[0].new, json!(14));
+        // still reported on the next refresh, the user has not seen it yet
+        assert_eq!(watchlist.compare(current.clone()), notices);
+
+        watchlist.dismiss(&notices[0]);
+        assert!(watchlist.compare(current).is_empty());
+        assert_eq!(watchlist.seen["JP"], Some(changed));
+    }
+
+    #[test]
+    fn reports_records_appearing_and_going() {
+        let mut watchlist = Watchlist::default();
+        watchlist.toggle_country("JP");
+        watchlist.compare(vec![("JP".to_string(), None)]);
+        let notices = watchlist.compare(vec![("JP".to_string(), requirements("JP"))]);
+        assert_eq!(notices.len(), 1);
+        assert!(notices[0].changes.iter().all(|c| c.old.is_null()));
+
+        watchlist.dismiss(&notices[0]);
+        let notices = watchlist.compare(vec![("JP".to_string(), None)]);
+        assert!(notices[0].changes.iter().all(|c| c.new.is_null()));
+    }
+}
@@ -0,0 +1,81 @@
+//! Standalone HTML pages the user prints, e.g. the trip checklist and the
+//! pre-filled certificates. They carry their own styles and work without the
+//! app's scripts.
+
+use crate::i18n::localizer::Localizer;
+
+/// Shared by every printed page, pages add their own rules after it
+const PRINT_CSS: &str = "
+body { font-family: sans-serif; font-size: 11pt; color: #000; max-width: 190mm; margin: 10mm auto; }
+h1 { font-size: 18pt; margin-bottom: 2mm; }
+h2 { font-size: 13pt; margin: 6mm 0 2mm 0; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: start; vertical-align: top; padding: 1.5mm 2mm; border: 0.3mm solid #888; }
+.fields th { width: 35%; font-weight: normal; color: #444; }
+.blank { height: 6mm; }
+@page { margin: 12mm; }
+@media print {
+  body { margin: 0; }
+  .no_print { display: none; }
+  a { color: #000; text-decoration: none; }
+  tr, li { page-break-inside: avoid; }
+}
+";
+
+/// Escapes text for HTML content and attribute values
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Complete document in the language of `localizer` with a print button,
+/// `title` is plain text and `body` is markup
+pub fn print_page(localizer: &Localizer, title: &str, css: &str, body: &str) -> String {
+    let locale = localizer.locale();
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\" dir=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{}{}</style>\n</head>\n<body>\n\
+         <button class=\"no_print\" onclick=\"window.print()\">{}</button>\n{}</body>\n</html>\n",
+        locale.id(),
+        locale.direction().id(),
+        escape(title),
+        PRINT_CSS,
+        css,
+        escape(&localizer.tr("print-page")),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::localizer::get_localizer;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(
+            escape("<a href=\"x\">Tom & 'Jerry'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn pages_follow_the_locale() {
+        let page = print_page(get_localizer(Locale::Ar), "<x>", ".x {}", "<p>body</p>\n");
+        assert!(page.starts_with("<!DOCTYPE html>\n<html lang=\"ar\" dir=\"rtl\">"));
+        assert!(page.contains("<title>&lt;x&gt;</title>"));
+        assert!(page.contains(".x {}</style>"));
+        assert!(page.ends_with("<p>body</p>\n</body>\n</html>\n"));
+    }
+}
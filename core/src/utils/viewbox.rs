@@ -0,0 +1,160 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewBox {
+    pub top_left: Point,
+    pub w: u32,
+    pub h: u32,
+    pub zoom_in_limit: u32,
+    pub zoom_out_limit: u32,
+}
+
+impl fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.top_left.x, self.top_left.y, self.w, self.h
+        )
+    }
+}
+
+impl ViewBox {
+    /// Sets the size, scaled as a whole where needed to stay within the zoom
+    /// limits, both limits being 0 means there are none
+    pub fn resize(&mut self, w: f32, h: f32) {
+        let (in_limit, out_limit) = (self.zoom_in_limit as f32, self.zoom_out_limit as f32);
+        let fit = if self.zoom_in_limit == 0 && self.zoom_out_limit == 0 {
+            1.0
+        } else if w.min(h) < in_limit {
+            in_limit / w.min(h)
+        } else if w.max(h) > out_limit {
+            out_limit / w.max(h)
+        } else {
+            1.0
+        };
+        self.w = (w * fit).round() as u32;
+        self.h = (h * fit).round() as u32;
+    }
+
+    pub fn zoom_to(&mut self, zoom_center: Point, scale: f32) {
+        self.resize(self.w as f32 / scale, self.h as f32 / scale);
+        self.top_left = zoom_center
+            - Point {
+                x: (self.w / 2) as i32,
+                y: (self.h / 2) as i32,
+            };
+    }
+
+    pub fn zoom_to_center(&mut self, scale: f32) {
+        let zoom_center = self.top_left
+            + Point {
+                x: (self.w / 2) as i32,
+                y: (self.h / 2) as i32,
+            };
+        self.zoom_to(zoom_center, scale);
+    }
+
+    pub fn drag(&mut self, delta: Point) {
+        self.top_left = self.top_left + delta;
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.top_left.x
+            && point.y >= self.top_left.y
+            && point.x <= self.top_left.x + self.w as i32
+            && point.y <= self.top_left.y + self.h as i32
+    }
+
+    /// Moves the view without zooming so that `point` is in the middle
+    pub fn center_on(&mut self, point: Point) {
+        self.top_left = point
+            - Point {
+                x: (self.w / 2) as i32,
+                y: (self.h / 2) as i32,
+            };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewbox(w: u32, h: u32) -> ViewBox {
+        ViewBox {
+            top_left: Point { x: 0, y: 0 },
+            w,
+            h,
+            zoom_in_limit: 100,
+            zoom_out_limit: 2000,
+        }
+    }
+
+    #[test]
+    fn formats_as_svg_attribute() {
+        let mut vb = viewbox(800, 600);
+        vb.drag(Point { x: -10, y: 20 });
+        assert_eq!(vb.to_string(), "-10 20 800 600");
+    }
+
+    #[test]
+    fn zoom_keeps_center() {
+        let mut vb = viewbox(800, 600);
+        vb.zoom_to_center(2.0);
+        assert_eq!((vb.w, vb.h), (400, 300));
+        assert_eq!(vb.top_left, Point { x: 200, y: 150 });
+    }
+
+    #[test]
+    fn centers_on_point_outside() {
+        let mut vb = viewbox(800, 600);
+        let point = Point { x: 1000, y: -100 };
+        assert!(!vb.contains(point));
+        vb.center_on(point);
+        assert!(vb.contains(point));
+        assert_eq!(vb.top_left, Point { x: 600, y: -400 });
+        assert_eq!((vb.w, vb.h), (800, 600));
+    }
+
+    #[test]
+    fn zoom_stops_at_limits() {
+        let mut vb = viewbox(100, 100);
+        vb.zoom_to_center(2.0);
+        assert_eq!((vb.w, vb.h), (100, 100));
+        vb.zoom_to_center(0.5);
+        assert_eq!((vb.w, vb.h), (200, 200));
+
+        let mut vb = viewbox(2000, 1000);
+        vb.zoom_to_center(0.5);
+        assert_eq!((vb.w, vb.h), (2000, 1000));
+    }
+}
@@ -0,0 +1,324 @@
+use petabroad_core::data::airlines::AirlineDatabase;
+use petabroad_core::data::breeds::BreedRegistry;
+use petabroad_core::data::certificates::{templates_from_json, CertificateTemplate};
+use petabroad_core::data::history::History;
+use petabroad_core::data::labs::{laboratories_from_json, Laboratory};
+use petabroad_core::data::regions::Regions;
+use petabroad_core::data::requirements::RequirementsDatabase;
+use petabroad_core::rules::RuleBook;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const REQUIREMENTS: &str = "requirements.json";
+pub const RULES: &str = "rules.json";
+pub const AIRLINES: &str = "airlines.json";
+pub const BREEDS: &str = "breed_restrictions.json";
+pub const LABORATORIES: &str = "titer_labs.json";
+pub const REGIONS: &str = "regions.json";
+pub const HISTORY: &str = "history.json";
+pub const CERTIFICATES: &str = "certificates.json";
+
+/// Every file of the dataset, in the order they are loaded and reported
+pub const FILES: [&str; 8] = [
+    REQUIREMENTS,
+    RULES,
+    AIRLINES,
+    BREEDS,
+    LABORATORIES,
+    REGIONS,
+    HISTORY,
+    CERTIFICATES,
+];
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io {
+        file: PathBuf,
+        error: io::Error,
+    },
+    /// The file is not JSON or does not match the core types
+    Json {
+        file: PathBuf,
+        error: serde_json::Error,
+    },
+}
+
+impl LoadError {
+    pub fn file(&self) -> &Path {
+        match self {
+            LoadError::Io { file, .. } | LoadError::Json { file, .. } => file,
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io { file, error } => write!(f, "{}: {}", file.display(), error),
+            LoadError::Json { file, error } => {
+                write!(f, "{}: malformed document: {}", file.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// The data files of one directory, parsed into the types the app uses
+#[derive(Debug)]
+pub struct Dataset {
+    pub requirements: RequirementsDatabase,
+    pub rules: RuleBook,
+    pub airlines: AirlineDatabase,
+    pub breeds: BreedRegistry,
+    pub laboratories: Vec<Laboratory>,
+    pub regions: Regions,
+    pub history: History,
+    pub certificates: Vec<CertificateTemplate>,
+    /// The files as written, keyed by file name, for the checks the types
+    /// can not do themselves
+    pub documents: BTreeMap<&'static str, Value>,
+}
+
+struct Loader<'a> {
+    dir: &'a Path,
+    documents: BTreeMap<&'static str, Value>,
+    errors: Vec<LoadError>,
+}
+
+impl Loader<'_> {
+    fn parse<T>(
+        &mut self,
+        name: &'static str,
+        parse: fn(&str) -> serde_json::Result<T>,
+    ) -> Option<T> {
+        let file = self.dir.join(name);
+        let text = match fs::read_to_string(&file) {
+            Ok(text) => text,
+            Err(error) => {
+                self.errors.push(LoadError::Io { file, error });
+                return None;
+            }
+        };
+        match serde_json::from_str(&text).and_then(|document| {
+            self.documents.insert(name, document);
+            parse(&text)
+        }) {
+            Ok(parsed) => Some(parsed),
+            Err(error) => {
+                self.errors.push(LoadError::Json { file, error });
+                None
+            }
+        }
+    }
+}
+
+impl Dataset {
+    /// The datasets bundled into the app
+    pub fn bundled_dir() -> PathBuf {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap_or(Path::new(".."));
+        root.join("core").join("data")
+    }
+
+    /// Reads every file of `dir`, reporting all the files which fail rather
+    /// than only the first
+    pub fn load(dir: &Path) -> Result<Dataset, Vec<LoadError>> {
+        let mut loader = Loader {
+            dir,
+            documents: BTreeMap::new(),
+            errors: vec![],
+        };
+        let requirements = loader.parse(REQUIREMENTS, RequirementsDatabase::from_json);
+        let rules = loader.parse(RULES, RuleBook::from_json);
+        let airlines = loader.parse(AIRLINES, AirlineDatabase::from_json);
+        let breeds = loader.parse(BREEDS, BreedRegistry::from_json);
+        let laboratories = loader.parse(LABORATORIES, laboratories_from_json);
+        let regions = loader.parse(REGIONS, Regions::from_json);
+        let history = loader.parse(HISTORY, History::from_json);
+        let certificates = loader.parse(CERTIFICATES, templates_from_json);
+        match (
+            requirements,
+            rules,
+            airlines,
+            breeds,
+            laboratories,
+            regions,
+            history,
+            certificates,
+        ) {
+            (
+                Some(requirements),
+                Some(rules),
+                Some(airlines),
+                Some(breeds),
+                Some(laboratories),
+                Some(regions),
+                Some(history),
+                Some(certificates),
+            ) => Ok(Dataset {
+                requirements,
+                rules,
+                airlines,
+                breeds,
+                laboratories,
+                regions,
+                history,
+                certificates,
+                documents: loader.documents,
+            }),
+            _ => Err(loader.errors),
+        }
+    }
+
+    /// Writes the dataset's requirements to `dir` in the canonical layout,
+    /// copying the other files from `from` unless that is `dir` itself, so
+    /// that `dir` holds a complete dataset
+    pub fn write_requirements(&self, from: &Path, dir: &Path) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        if fs::canonicalize(from)? != fs::canonicalize(dir)? {
+            for file in FILES.iter().filter(|f| **f != REQUIREMENTS) {
+                fs::copy(from.join(file), dir.join(file))?;
+            }
+        }
+        let path = dir.join(REQUIREMENTS);
+        let document = self
+            .documents
+            .get(REQUIREMENTS)
+            .cloned()
+            .unwrap_or_else(|| to_value(&self.requirements));
+        fs::write(&path, to_canonical_json(&document))?;
+        Ok(path)
+    }
+
+    /// The dataset written back out by the core types, keyed by file name.
+    /// Fields of the documents missing here are unknown to the app.
+    pub fn canonical(&self) -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([
+            (REQUIREMENTS, to_value(&self.requirements)),
+            (RULES, to_value(&self.rules)),
+            (AIRLINES, to_value(&self.airlines)),
+            (BREEDS, to_value(&self.breeds)),
+            (
+                LABORATORIES,
+                json!({ "laboratories": to_value(&self.laboratories) }),
+            ),
+            (REGIONS, to_value(&self.regions)),
+            (HISTORY, to_value(&self.history)),
+            (
+                CERTIFICATES,
+                json!({ "certificates": to_value(&self.certificates) }),
+            ),
+        ])
+    }
+}
+
+/// Goes through the JSON text rather than `serde_json::to_value` so that
+/// `f32` fields keep their shortest spelling, `0.2` rather than
+/// `0.20000000298023224`
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_string(value)
+        .and_then(|text| serde_json::from_str(&text))
+        .unwrap_or_else(|e| panic!("Dataset can not be serialized: {}", e))
+}
+
+/// Lines are wrapped at this width where the layout allows it
+const WIDTH: usize = 100;
+
+fn is_scalar(value: &Value) -> bool {
+    !value.is_object() && !value.is_array()
+}
+
+fn scalar(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// One-line form of objects made of scalars only
+fn inline_object(fields: &serde_json::Map<String, Value>) -> Option<String> {
+    if !fields.values().all(is_scalar) {
+        return None;
+    }
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}: {}", scalar(&Value::from(key.as_str())), scalar(value)))
+        .collect();
+    Some(format!("{{ {} }}", fields.join(", ")))
+}
+
+fn write_value(value: &Value, indent: usize, column: usize, out: &mut String) {
+    let pad = "  ".repeat(indent + 1);
+    match value {
+        Value::Object(fields) if fields.is_empty() => out.push_str("{}"),
+        Value::Object(fields) => {
+            match inline_object(fields).filter(|s| column + s.len() <= WIDTH) {
+                Some(inline) => out.push_str(&inline),
+                None => {
+                    out.push_str("{\n");
+                    for (i, (key, value)) in fields.iter().enumerate() {
+                        let key = format!("{}{}: ", pad, scalar(&Value::from(key.as_str())));
+                        out.push_str(&key);
+                        write_value(value, indent + 1, key.len(), out);
+                        out.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+                    }
+                    out.push_str(&"  ".repeat(indent));
+                    out.push('}');
+                }
+            }
+        }
+        Value::Array(items) if items.is_empty() => out.push_str("[]"),
+        Value::Array(items) if items.iter().all(is_scalar) => {
+            let items: Vec<String> = items.iter().map(scalar).collect();
+            let inline = format!("[{}]", items.join(", "));
+            if column + inline.len() <= WIDTH {
+                out.push_str(&inline);
+                return;
+            }
+            // as many items per line as fit
+            out.push('[');
+            let mut line = String::new();
+            for (i, item) in items.iter().enumerate() {
+                let item = if i + 1 < items.len() {
+                    format!("{},", item)
+                } else {
+                    item.clone()
+                };
+                if !line.is_empty() && pad.len() + line.len() + 1 + item.len() > WIDTH {
+                    out.push_str(&format!("\n{}{}", pad, line));
+                    line.clear();
+                }
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(&item);
+            }
+            out.push_str(&format!("\n{}{}\n{}]", pad, line, "  ".repeat(indent)));
+        }
+        Value::Array(items) => {
+            out.push_str("[\n");
+            for (i, item) in items.iter().enumerate() {
+                out.push_str(&pad);
+                write_value(item, indent + 1, pad.len(), out);
+                out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+            }
+            out.push_str(&"  ".repeat(indent));
+            out.push(']');
+        }
+        scalar_value => out.push_str(&scalar(scalar_value)),
+    }
+}
+
+/// The layout data files are written in: keys sorted, lists of ids packed
+/// into lines and small records on one line, so that a changed value shows
+/// up as a changed line in version control
+pub fn to_canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, 0, 0, &mut out);
+    out.push('\n');
+    out
+}
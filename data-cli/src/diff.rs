@@ -0,0 +1,266 @@
+//! Change summaries of data files for review, in terms of the records
+//! rather than of the lines which moved.
+
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Fields identifying the objects of a list, so that reordering a list is
+/// not reported as a change of every entry
+const KEYS: [&str; 2] = ["id", "iata"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added {
+        path: String,
+        value: Value,
+    },
+    Removed {
+        path: String,
+        value: Value,
+    },
+    Changed {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {}: {}", path, value),
+            Change::Removed { path, value } => write!(f, "- {}: {}", path, value),
+            Change::Changed { path, old, new } => write!(f, "~ {}: {} → {}", path, old, new),
+        }
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// The key of a list entry, if it has one
+pub(crate) fn key_of(value: &Value) -> Option<String> {
+    KEYS.iter()
+        .find_map(|key| value.get(key))
+        .map(|key| match key {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+}
+
+/// Changes turning `old` into `new`. Lists of strings, mostly country ids,
+/// are compared as sets and lists of keyed objects by key, other lists
+/// position by position.
+pub fn diff(old: &Value, new: &Value) -> Vec<Change> {
+    let mut changes = vec![];
+    diff_at("", old, new, &mut changes);
+    changes
+}
+
+fn diff_at(path: &str, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, value) in old {
+                match new.get(key) {
+                    Some(new) => diff_at(&child(path, key), value, new, changes),
+                    None => changes.push(Change::Removed {
+                        path: child(path, key),
+                        value: value.clone(),
+                    }),
+                }
+            }
+            for (key, value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+                changes.push(Change::Added {
+                    path: child(path, key),
+                    value: value.clone(),
+                });
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => diff_lists(path, old, new, changes),
+        (old, new) if old != new => changes.push(Change::Changed {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn find_keyed<'a>(list: &'a [Value], key: &str) -> Option<&'a Value> {
+    list.iter().find(|v| key_of(v).as_deref() == Some(key))
+}
+
+fn diff_lists(path: &str, old: &[Value], new: &[Value], changes: &mut Vec<Change>) {
+    let ids = old.iter().chain(new).all(|v| v.is_string());
+    let keyed = old.iter().chain(new).all(|v| key_of(v).is_some());
+    if ids {
+        for value in old.iter().filter(|v| !new.contains(v)) {
+            changes.push(Change::Removed {
+                path: path.to_string(),
+                value: value.clone(),
+            });
+        }
+        for value in new.iter().filter(|v| !old.contains(v)) {
+            changes.push(Change::Added {
+                path: path.to_string(),
+                value: value.clone(),
+            });
+        }
+    } else if keyed {
+        for value in old {
+            let key = key_of(value).unwrap_or_default();
+            let path = format!("{}[{}]", path, key);
+            match find_keyed(new, &key) {
+                Some(new) => diff_at(&path, value, new, changes),
+                None => changes.push(Change::Removed {
+                    path,
+                    value: value.clone(),
+                }),
+            }
+        }
+        for value in new {
+            let key = key_of(value).unwrap_or_default();
+            if find_keyed(old, &key).is_none() {
+                changes.push(Change::Added {
+                    path: format!("{}[{}]", path, key),
+                    value: value.clone(),
+                });
+            }
+        }
+    } else {
+        for (i, (old, new)) in old.iter().zip(new).enumerate() {
+            diff_at(&format!("{}[{}]", path, i), old, new, changes);
+        }
+        for (i, value) in old.iter().enumerate().skip(new.len()) {
+            changes.push(Change::Removed {
+                path: format!("{}[{}]", path, i),
+                value: value.clone(),
+            });
+        }
+        for (i, value) in new.iter().enumerate().skip(old.len()) {
+            changes.push(Change::Added {
+                path: format!("{}[{}]", path, i),
+                value: value.clone(),
+            });
+        }
+    }
+}
+
+/// Changes of one file, `None` standing for a file which does not exist on
+/// that side
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDiff {
+    pub name: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+    pub changes: Vec<Change>,
+}
+
+impl FileDiff {
+    pub fn new(name: &str, old: Option<Value>, new: Option<Value>) -> FileDiff {
+        let changes = match (&old, &new) {
+            (Some(old), Some(new)) => diff(old, new),
+            _ => vec![],
+        };
+        FileDiff {
+            name: name.to_string(),
+            old,
+            new,
+            changes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.old.is_some() == self.new.is_some() && self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for FileDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.old, &self.new) {
+            (None, Some(_)) => writeln!(f, "{}: added", self.name),
+            (Some(_), None) => writeln!(f, "{}: removed", self.name),
+            _ => {
+                let count =
+                    |pick: fn(&Change) -> bool| self.changes.iter().filter(|c| pick(c)).count();
+                writeln!(
+                    f,
+                    "{}: {} added, {} removed, {} changed",
+                    self.name,
+                    count(|c| matches!(c, Change::Added { .. })),
+                    count(|c| matches!(c, Change::Removed { .. })),
+                    count(|c| matches!(c, Change::Changed { .. }))
+                )?;
+                for change in &self.changes {
+                    writeln!(f, "  {}", change)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn read(path: &Path) -> io::Result<Option<Value>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let text = fs::read_to_string(path)?;
+    serde_json::from_str(&text).map(Some).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+fn json_files(dir: &Path) -> io::Result<BTreeSet<String>> {
+    let mut names = BTreeSet::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.ends_with(".json") {
+            names.insert(name);
+        }
+    }
+    Ok(names)
+}
+
+/// Compares two data files, or every JSON file of two data directories,
+/// leaving out the files without changes
+pub fn diff_paths(old: &Path, new: &Path) -> io::Result<Vec<FileDiff>> {
+    if old.is_dir() && new.is_dir() {
+        let mut names = json_files(old)?;
+        names.extend(json_files(new)?);
+        let mut diffs = vec![];
+        for name in names {
+            let diff = FileDiff::new(&name, read(&old.join(&name))?, read(&new.join(&name))?);
+            if !diff.is_empty() {
+                diffs.push(diff);
+            }
+        }
+        return Ok(diffs);
+    }
+    let name = new
+        .file_name()
+        .or_else(|| old.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let load = |path: &Path| {
+        read(path)?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: no such file", path.display()),
+            )
+        })
+    };
+    let diff = FileDiff::new(&name, Some(load(old)?), Some(load(new)?));
+    Ok(if diff.is_empty() { vec![] } else { vec![diff] })
+}
@@ -0,0 +1,501 @@
+//! Reads requirement sheets kept by researchers in spreadsheets, exported as
+//! CSV with a header row, into the requirements dataset.
+//!
+//! Columns may come in any order, headers are matched ignoring case with
+//! spaces read as underscores, and empty cells leave the field unset so that
+//! the bloc's value applies. Values separated by `;` form lists.
+//!
+//! | Column                         | Field                             | Values                                   |
+//! |--------------------------------|-----------------------------------|------------------------------------------|
+//! | `country`                      | country id, required              | name, alias or id as used on the map     |
+//! | `microchip_iso`                | `microchip.iso_required`          | yes / no                                 |
+//! | `microchip_before_vaccination` | `microchip.before_vaccination`    | yes / no, yes when empty                 |
+//! | `titer_min_iu`                 | `titer.min_iu_per_ml`             | decimal, e.g. `0.5`                      |
+//! | `titer_methods`                | `titer.methods`                   | `favn`, `rffit`, `elisa`                 |
+//! | `titer_wait_days`              | `titer.wait_days`                 | days                                     |
+//! | `titer_validity_days`          | `titer.validity_days`             | days, empty while boosters are kept up   |
+//! | `titer_lab_approval`           | `titer.lab_approval`              | bloc id or country, the country if empty |
+//! | `titer_exempt_origins`         | `titer.exempt_origins`            | countries                                |
+//! | `quarantine_days`              | `quarantine_days`                 | days                                     |
+//! | `import_permit`                | `import_permit`                   | yes / no                                 |
+//! | `rabies_status`                | `rabies_status`                   | free, controlled, high incidence         |
+//! | `verified_on`                  | `verified.date`                   | `YYYY-MM-DD`                             |
+//! | `verified_by`                  | `verified.verifier`               | name, required with `verified_on`        |
+//! | `source_titles`                | `sources[].title`                 | titles, one per URL                      |
+//! | `source_urls`                  | `sources[].url`                   | `https://` URLs                          |
+//!
+//! The titer columns are all required once one of them is filled in, except
+//! for the validity, approval and exempt origins.
+
+use chrono::NaiveDate;
+use petabroad_core::data::requirements::{
+    CountryRequirements, MicrochipRequirement, RabiesStatus, RequirementsDatabase, Source,
+    TiterMethod, TiterRequirement, Verification,
+};
+use petabroad_core::map_data::{get_countries_aliases, get_countries_names};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+
+use crate::dataset::{Dataset, REQUIREMENTS};
+
+pub const COLUMNS: [&str; 16] = [
+    "country",
+    "microchip_iso",
+    "microchip_before_vaccination",
+    "titer_min_iu",
+    "titer_methods",
+    "titer_wait_days",
+    "titer_validity_days",
+    "titer_lab_approval",
+    "titer_exempt_origins",
+    "quarantine_days",
+    "import_permit",
+    "rabies_status",
+    "verified_on",
+    "verified_by",
+    "source_titles",
+    "source_urls",
+];
+
+/// A problem with a line of the sheet, the header being line 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowError {
+    pub line: u64,
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.column {
+            Some(column) => write!(f, "line {}, {}: {}", self.line, column, self.message),
+            None => write!(f, "line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+/// A country's row of the sheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedCountry {
+    pub line: u64,
+    pub id: String,
+    pub requirements: CountryRequirements,
+    pub sources: Vec<Source>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Import {
+    pub countries: Vec<ImportedCountry>,
+    /// Rows with errors are left out of `countries`
+    pub errors: Vec<RowError>,
+}
+
+impl Import {
+    /// Replaces the records of the imported countries in `db`
+    pub fn apply(&self, db: &mut RequirementsDatabase) {
+        for country in &self.countries {
+            db.insert_country(
+                &country.id,
+                country.requirements.clone(),
+                country.sources.clone(),
+            );
+        }
+    }
+
+    /// Applies the import to the dataset, its requirements document then
+    /// being the one to write out
+    pub fn apply_to(&self, dataset: &mut Dataset) {
+        self.apply(&mut dataset.requirements);
+        let document = dataset.canonical().remove(REQUIREMENTS).unwrap_or_default();
+        dataset.documents.insert(REQUIREMENTS, document);
+    }
+}
+
+/// Lowercased with accents, punctuation and `St.` spelled out, the form the
+/// aliases of `map_data` are keyed by
+fn normalize(name: &str) -> String {
+    let name: String = name
+        .to_lowercase()
+        .replace('&', " and ")
+        .chars()
+        .filter_map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => Some('a'),
+            'é' | 'è' | 'ê' | 'ë' => Some('e'),
+            'í' | 'ì' | 'î' | 'ï' => Some('i'),
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' => Some('o'),
+            'ú' | 'ù' | 'û' | 'ü' => Some('u'),
+            'ç' => Some('c'),
+            'ñ' => Some('n'),
+            'š' => Some('s'),
+            '\'' | '’' | '.' => None,
+            c if c.is_alphanumeric() => Some(c),
+            _ => Some(' '),
+        })
+        .collect();
+    name.split_whitespace()
+        .map(|word| match word {
+            "st" => "saint",
+            word => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Names of the map without the territory qualifier, `Reunion (France)` read
+/// as `Reunion`
+fn without_qualifier(name: &str) -> &str {
+    name.split(" (").next().unwrap_or(name)
+}
+
+/// Map id of a country given by id, alias or name
+pub fn resolve_country(name: &str) -> Result<&'static str, String> {
+    let names = get_countries_names();
+    let trimmed = name.trim();
+    if let Some((id, _)) = names.get_key_value(trimmed.to_uppercase().as_str()) {
+        return Ok(id);
+    }
+    let normalized = normalize(trimmed);
+    if let Some(id) = get_countries_aliases().get(normalized.as_str()) {
+        return Ok(id);
+    }
+    let mut matches: Vec<&str> = names
+        .iter()
+        .filter(|(_, n)| {
+            normalize(n) == normalized || normalize(without_qualifier(n)) == normalized
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    matches.sort_unstable();
+    match matches.as_slice() {
+        [id] => Ok(id),
+        [] => Err(format!("unknown country \"{}\"", trimmed)),
+        ids => Err(format!(
+            "\"{}\" could be any of {}",
+            trimmed,
+            ids.join(", ")
+        )),
+    }
+}
+
+fn list(cell: &str) -> impl Iterator<Item = &str> {
+    cell.split(';').map(|s| s.trim()).filter(|s| !s.is_empty())
+}
+
+/// Cells of one row by column name, keeping the errors met reading them
+struct Row<'a> {
+    line: u64,
+    cells: HashMap<&'a str, &'a str>,
+    errors: Vec<RowError>,
+}
+
+impl<'a> Row<'a> {
+    fn error(&mut self, column: &str, message: String) {
+        self.errors.push(RowError {
+            line: self.line,
+            column: Some(column.to_string()),
+            message,
+        });
+    }
+
+    fn cell(&self, column: &str) -> Option<&'a str> {
+        self.cells.get(column).copied().filter(|c| !c.is_empty())
+    }
+
+    fn parse<T>(
+        &mut self,
+        column: &str,
+        expected: &str,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Option<T> {
+        let cell = self.cell(column)?;
+        let parsed = parse(cell);
+        if parsed.is_none() {
+            self.error(column, format!("expected {}, got \"{}\"", expected, cell));
+        }
+        parsed
+    }
+
+    fn yes_no(&mut self, column: &str) -> Option<bool> {
+        self.parse(column, "yes or no", |cell| {
+            match cell.to_lowercase().as_str() {
+                "yes" | "y" | "true" | "1" => Some(true),
+                "no" | "n" | "false" | "0" => Some(false),
+                _ => None,
+            }
+        })
+    }
+
+    fn days(&mut self, column: &str) -> Option<u32> {
+        self.parse(column, "a number of days", |cell| cell.parse().ok())
+    }
+
+    fn required<T>(&mut self, column: &str, value: Option<T>, with: &str) -> Option<T> {
+        if value.is_none() && self.cell(column).is_none() {
+            self.error(column, format!("required with {}", with));
+        }
+        value
+    }
+
+    fn countries(&mut self, column: &str) -> Vec<String> {
+        let mut ids = vec![];
+        for name in list(self.cell(column).unwrap_or_default()) {
+            match resolve_country(name) {
+                Ok(id) => ids.push(id.to_string()),
+                Err(e) => self.error(column, e),
+            }
+        }
+        ids
+    }
+
+    fn microchip(&mut self) -> Option<MicrochipRequirement> {
+        let iso_required = self.yes_no("microchip_iso");
+        let before_vaccination = self.yes_no("microchip_before_vaccination");
+        if before_vaccination.is_some() {
+            self.required(
+                "microchip_iso",
+                iso_required,
+                "microchip_before_vaccination",
+            );
+        }
+        Some(MicrochipRequirement {
+            iso_required: iso_required?,
+            before_vaccination: before_vaccination.unwrap_or(true),
+        })
+    }
+
+    fn titer(&mut self, country_id: &str, blocs: &[&str]) -> Option<TiterRequirement> {
+        let filled = COLUMNS
+            .iter()
+            .filter(|c| c.starts_with("titer_"))
+            .find(|c| self.cell(c).is_some())
+            .copied()?;
+        let min_iu_per_ml = self.parse("titer_min_iu", "a decimal number", |cell| {
+            cell.replace(',', ".")
+                .parse::<f32>()
+                .ok()
+                .filter(|v| *v > 0.0)
+        });
+        let min_iu_per_ml = self.required("titer_min_iu", min_iu_per_ml, filled);
+        let mut methods = vec![];
+        for method in list(self.cell("titer_methods").unwrap_or_default()) {
+            match method.to_lowercase().as_str() {
+                "favn" => methods.push(TiterMethod::Favn),
+                "rffit" => methods.push(TiterMethod::Rffit),
+                "elisa" => methods.push(TiterMethod::Elisa),
+                _ => {
+                    let message = format!("expected favn, rffit or elisa, got \"{}\"", method);
+                    self.error("titer_methods", message)
+                }
+            }
+        }
+        if self.cell("titer_methods").is_none() {
+            self.error("titer_methods", format!("required with {}", filled));
+        }
+        let wait_days = self.days("titer_wait_days");
+        let wait_days = self.required("titer_wait_days", wait_days, filled);
+        let validity_days = self.days("titer_validity_days");
+        let lab_approval = match self.cell("titer_lab_approval") {
+            None => country_id.to_string(),
+            Some(bloc) if blocs.contains(&bloc) => bloc.to_string(),
+            Some(country) => match resolve_country(country) {
+                Ok(id) => id.to_string(),
+                Err(e) => {
+                    self.error("titer_lab_approval", e);
+                    String::new()
+                }
+            },
+        };
+        let exempt_origins = self.countries("titer_exempt_origins");
+        Some(TiterRequirement {
+            min_iu_per_ml: min_iu_per_ml?,
+            methods,
+            wait_days: wait_days?,
+            validity_days,
+            lab_approval,
+            exempt_origins,
+        })
+    }
+
+    fn verified(&mut self) -> Option<Verification> {
+        let date = self.parse("verified_on", "a date as YYYY-MM-DD", |cell| {
+            NaiveDate::parse_from_str(cell, "%Y-%m-%d").ok()
+        });
+        let verifier = self.cell("verified_by").map(|v| v.to_string());
+        match (date, verifier) {
+            (Some(date), Some(verifier)) => Some(Verification { date, verifier }),
+            (Some(_), None) => {
+                self.error("verified_by", "required with verified_on".to_string());
+                None
+            }
+            (None, Some(_)) if self.cell("verified_on").is_none() => {
+                self.error("verified_on", "required with verified_by".to_string());
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn sources(&mut self) -> Vec<Source> {
+        let titles: Vec<String> = list(self.cell("source_titles").unwrap_or_default())
+            .map(|s| s.to_string())
+            .collect();
+        let urls: Vec<String> = list(self.cell("source_urls").unwrap_or_default())
+            .map(|s| s.to_string())
+            .collect();
+        if titles.len() != urls.len() {
+            let message = format!("{} titles for {} URLs", titles.len(), urls.len());
+            self.error("source_titles", message);
+            return vec![];
+        }
+        for url in urls
+            .iter()
+            .filter(|u| !u.starts_with("https://") && !u.starts_with("http://"))
+        {
+            let message = format!("expected a web address, got \"{}\"", url);
+            self.error("source_urls", message);
+        }
+        titles
+            .into_iter()
+            .zip(urls)
+            .map(|(title, url)| Source { title, url })
+            .collect()
+    }
+
+    fn rabies_status(&mut self) -> Option<RabiesStatus> {
+        self.parse(
+            "rabies_status",
+            "free, controlled or high incidence",
+            |cell| match normalize(cell).as_str() {
+                "free" => Some(RabiesStatus::Free),
+                "controlled" => Some(RabiesStatus::Controlled),
+                "high incidence" => Some(RabiesStatus::HighIncidence),
+                _ => None,
+            },
+        )
+    }
+}
+
+/// The column a header cell names
+fn column(header: &str) -> String {
+    header.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// Reads a sheet, `blocs` being the bloc ids a titer approval may name.
+/// Fields may be separated by commas or, as spreadsheets in many locales
+/// export them, by semicolons.
+pub fn read_sheet(mut reader: impl io::Read, blocs: &[&str]) -> Import {
+    let mut import = Import::default();
+    let mut text = String::new();
+    if let Err(e) = reader.read_to_string(&mut text) {
+        import.errors.push(RowError {
+            line: 0,
+            column: None,
+            message: e.to_string(),
+        });
+        return import;
+    }
+    let header_line = text.lines().next().unwrap_or_default();
+    let delimiter = if header_line.contains(';') && !header_line.contains(',') {
+        b';'
+    } else {
+        b','
+    };
+    let mut csv = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+
+    let headers: Vec<String> = match csv.headers() {
+        Ok(headers) => headers.iter().map(column).collect(),
+        Err(e) => {
+            import.errors.push(RowError {
+                line: 1,
+                column: None,
+                message: e.to_string(),
+            });
+            return import;
+        }
+    };
+    for header in headers.iter().filter(|h| !COLUMNS.contains(&h.as_str())) {
+        import.errors.push(RowError {
+            line: 1,
+            column: Some(header.clone()),
+            message: "unknown column".to_string(),
+        });
+    }
+    if !headers.iter().any(|h| h == "country") {
+        import.errors.push(RowError {
+            line: 1,
+            column: None,
+            message: "no country column".to_string(),
+        });
+    }
+    if !import.errors.is_empty() {
+        return import;
+    }
+
+    let mut lines: HashMap<String, u64> = HashMap::new();
+    for record in csv.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                import.errors.push(RowError {
+                    line: e.position().map_or(0, |p| p.line()),
+                    column: None,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |p| p.line());
+        if record.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+        let mut row = Row {
+            line,
+            cells: headers
+                .iter()
+                .map(|h| h.as_str())
+                .zip(record.iter())
+                .collect(),
+            errors: vec![],
+        };
+        let id = match row.cell("country").map(resolve_country) {
+            Some(Ok(id)) => id,
+            Some(Err(e)) => {
+                row.error("country", e);
+                import.errors.append(&mut row.errors);
+                continue;
+            }
+            None => {
+                row.error("country", "required".to_string());
+                import.errors.append(&mut row.errors);
+                continue;
+            }
+        };
+        if let Some(first) = lines.insert(id.to_string(), line) {
+            row.error("country", format!("{} is already on line {}", id, first));
+        }
+        let requirements = CountryRequirements {
+            microchip: row.microchip(),
+            titer: row.titer(id, blocs),
+            quarantine_days: row.days("quarantine_days"),
+            import_permit: row.yes_no("import_permit"),
+            rabies_status: row.rabies_status(),
+            verified: row.verified(),
+        };
+        let sources = row.sources();
+        if row.errors.is_empty() {
+            import.countries.push(ImportedCountry {
+                line,
+                id: id.to_string(),
+                requirements,
+                sources,
+            });
+        } else {
+            import.errors.append(&mut row.errors);
+        }
+    }
+    import
+}
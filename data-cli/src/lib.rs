@@ -0,0 +1,60 @@
+//! Maintenance tooling for the datasets in `core/data`: schema and
+//! referential checks, lints, coverage statistics and change summaries for
+//! review, all runnable from a terminal without the web frontend.
+
+pub mod dataset;
+pub mod diff;
+pub mod import;
+pub mod lint;
+pub mod stats;
+pub mod validate;
+
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    /// The dataset is broken and must not be shipped
+    Error,
+}
+
+/// A problem found in one of the data files
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub file: String,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn error(file: &str, message: impl Into<String>) -> Finding {
+        Finding {
+            severity: Severity::Error,
+            file: file.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(file: &str, message: impl Into<String>) -> Finding {
+        Finding {
+            severity: Severity::Warning,
+            file: file.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: {}: {}", severity, self.file, self.message)
+    }
+}
+
+/// Any of the findings blocks the dataset
+pub fn has_errors(findings: &[Finding]) -> bool {
+    findings.iter().any(|f| f.severity == Severity::Error)
+}
@@ -0,0 +1,291 @@
+//! Doubts about a dataset which loads and validates: gaps in species
+//! coverage, checks against the sources which are due, members disagreeing
+//! with their blocs and passport countries the map does not have.
+
+use chrono::NaiveDate;
+use petabroad_core::data::freshness::{Freshness, FreshnessReport};
+use petabroad_core::data::requirements::CountryRequirements;
+use petabroad_core::map_data::get_countries_names;
+use petabroad_core::pet::profile::Species;
+use petabroad_core::rules::condition::{Facts, Truth};
+use petabroad_core::rules::Rule;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::dataset::{Dataset, REQUIREMENTS, RULES};
+use crate::Finding;
+
+pub fn lint(dataset: &Dataset, today: NaiveDate, stale_after_days: u32) -> Vec<Finding> {
+    let mut findings = vec![];
+    species_coverage(dataset, &mut findings);
+    freshness(dataset, today, stale_after_days, &mut findings);
+    bloc_conflicts(dataset, &mut findings);
+    passport_countries_off_map(dataset, &mut findings);
+    findings
+}
+
+fn species_name(species: Species) -> &'static str {
+    match species {
+        Species::Dog => "dog",
+        Species::Cat => "cat",
+        Species::Ferret => "ferret",
+    }
+}
+
+/// Every country with requirements or rules should have steps for every
+/// species, otherwise owners of the missing ones are told nothing
+fn species_coverage(dataset: &Dataset, findings: &mut Vec<Finding>) {
+    let db = &dataset.requirements;
+    let ids: BTreeSet<&str> = db
+        .countries()
+        .map(|(id, _)| id)
+        .chain(
+            db.blocs()
+                .flat_map(|(_, bloc)| bloc.members.iter().map(|m| m.as_str())),
+        )
+        .chain(dataset.rules.countries().map(|(id, _)| id))
+        .collect();
+    for id in ids {
+        if db.for_country(id).is_none() {
+            findings.push(Finding::warning(
+                REQUIREMENTS,
+                format!("{}: has rules but no requirements record", id),
+            ));
+        }
+        let rules = dataset.rules.rules_in(id, db);
+        if rules.is_empty() {
+            findings.push(Finding::warning(
+                RULES,
+                format!("{}: has requirements but no rules", id),
+            ));
+            continue;
+        }
+        let uncovered: Vec<&str> = Species::ALL
+            .into_iter()
+            .filter(|species| !covers(&rules, id, *species))
+            .map(species_name)
+            .collect();
+        if !uncovered.is_empty() {
+            findings.push(Finding::warning(
+                RULES,
+                format!("{}: no rules for {}", id, uncovered.join(", ")),
+            ));
+        }
+    }
+}
+
+/// Some rule of the country may apply to a pet of the species we know
+/// nothing else about
+fn covers(rules: &[&Rule], destination: &str, species: Species) -> bool {
+    let facts = Facts {
+        destination: destination.to_string(),
+        origin: None,
+        species,
+        age_weeks: None,
+        vaccinated: None,
+        transit: None,
+    };
+    rules
+        .iter()
+        .any(|rule| rule.when.evaluate(&facts) != Truth::False)
+}
+
+fn freshness(
+    dataset: &Dataset,
+    today: NaiveDate,
+    stale_after_days: u32,
+    findings: &mut Vec<Finding>,
+) {
+    let report = FreshnessReport::of_database(&dataset.requirements, today, stale_after_days);
+    for entry in report.needs_attention() {
+        match (&entry.verified, entry.freshness) {
+            (None, _) => findings.push(Finding::warning(
+                REQUIREMENTS,
+                format!("{}: never verified", entry.id),
+            )),
+            (Some(verified), Freshness::Stale) => findings.push(Finding::warning(
+                REQUIREMENTS,
+                format!(
+                    "{}: verified {} days ago on {}, due after {}",
+                    entry.id,
+                    entry.age_days.unwrap_or_default(),
+                    verified.date,
+                    stale_after_days
+                ),
+            )),
+            _ => {}
+        }
+        if entry.sources == 0 {
+            findings.push(Finding::warning(
+                REQUIREMENTS,
+                format!("{}: cites no sources", entry.id),
+            ));
+        }
+    }
+
+    let owners = dataset
+        .rules
+        .blocs()
+        .map(|(id, rules)| ("blocs", id, rules))
+        .chain(
+            dataset
+                .rules
+                .countries()
+                .map(|(id, rules)| ("countries", id, rules)),
+        );
+    let mut rules: Vec<_> = owners
+        .flat_map(|(kind, id, rules)| rules.iter().map(move |rule| (kind, id, rule)))
+        .collect();
+    rules.sort_by_key(|(kind, id, rule)| (*kind, *id, rule.id.as_str()));
+    for (kind, id, rule) in rules {
+        let path = format!("{}.{}[{}]", kind, id, rule.id);
+        match &rule.source {
+            None => findings.push(Finding::warning(
+                RULES,
+                format!("{}: cites no source", path),
+            )),
+            Some(source) if (today - source.verified).num_days() > stale_after_days as i64 => {
+                findings.push(Finding::warning(
+                    RULES,
+                    format!(
+                        "{}: citation verified {} days ago on {}, due after {}",
+                        path,
+                        (today - source.verified).num_days(),
+                        source.verified,
+                        stale_after_days
+                    ),
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn differs<T: PartialEq>(own: &Option<T>, bloc: &Option<T>) -> bool {
+    own.is_some() && bloc.is_some() && own != bloc
+}
+
+/// Fields a member sets itself which its bloc sets differently
+fn overridden_fields(own: &CountryRequirements, bloc: &CountryRequirements) -> Vec<&'static str> {
+    [
+        ("microchip", differs(&own.microchip, &bloc.microchip)),
+        ("titer", differs(&own.titer, &bloc.titer)),
+        (
+            "quarantine_days",
+            differs(&own.quarantine_days, &bloc.quarantine_days),
+        ),
+        (
+            "import_permit",
+            differs(&own.import_permit, &bloc.import_permit),
+        ),
+        (
+            "rabies_status",
+            differs(&own.rabies_status, &bloc.rabies_status),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, differs)| *differs)
+    .map(|(field, _)| field)
+    .collect()
+}
+
+/// Members in several blocs, members also listed as passport countries of
+/// their own bloc and members deviating from the bloc's requirements or
+/// replacing its rules with different steps
+fn bloc_conflicts(dataset: &Dataset, findings: &mut Vec<Finding>) {
+    let db = &dataset.requirements;
+    let mut blocs_of: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut blocs: Vec<_> = db.blocs().collect();
+    blocs.sort_by_key(|(id, _)| *id);
+    for (bloc_id, bloc) in &blocs {
+        for member in &bloc.members {
+            blocs_of.entry(member).or_default().push(bloc_id);
+            if bloc.passport_countries.contains(member) {
+                findings.push(Finding::warning(
+                    REQUIREMENTS,
+                    format!(
+                        "blocs.{}: member {} is also a passport country",
+                        bloc_id, member
+                    ),
+                ));
+            }
+        }
+    }
+    for (member, ids) in &blocs_of {
+        if ids.len() > 1 {
+            findings.push(Finding::error(
+                REQUIREMENTS,
+                format!("{}: member of several blocs: {}", member, ids.join(", ")),
+            ));
+        }
+    }
+
+    let mut countries: Vec<_> = db.countries().collect();
+    countries.sort_by_key(|(id, _)| *id);
+    for (id, own) in countries {
+        for (bloc_id, bloc) in blocs
+            .iter()
+            .filter(|(_, bloc)| bloc.members.iter().any(|m| m == id))
+        {
+            let fields = overridden_fields(own, &bloc.requirements);
+            if !fields.is_empty() {
+                findings.push(Finding::warning(
+                    REQUIREMENTS,
+                    format!(
+                        "{}: overrides {} of bloc {}",
+                        id,
+                        fields.join(", "),
+                        bloc_id
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut countries: Vec<_> = dataset.rules.countries().collect();
+    countries.sort_by_key(|(id, _)| *id);
+    for (id, own) in countries {
+        for (bloc_id, bloc_rules) in dataset
+            .rules
+            .blocs()
+            .filter(|(bloc_id, _)| db.is_member(bloc_id, id))
+        {
+            for rule in own {
+                let replaced = bloc_rules.iter().find(|r| r.id == rule.id);
+                if let Some(replaced) = replaced.filter(|r| r.then.kind() != rule.then.kind()) {
+                    findings.push(Finding::warning(
+                        RULES,
+                        format!(
+                            "countries.{}[{}]: replaces the {} step of bloc {} with {}",
+                            id,
+                            rule.id,
+                            replaced.then.kind(),
+                            bloc_id,
+                            rule.then.kind()
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Passport countries can not be picked when they are not on the map, they
+/// are still kept as the regulation lists them
+fn passport_countries_off_map(dataset: &Dataset, findings: &mut Vec<Finding>) {
+    let names = get_countries_names();
+    let mut blocs: Vec<_> = dataset.requirements.blocs().collect();
+    blocs.sort_by_key(|(id, _)| *id);
+    for (bloc_id, bloc) in blocs {
+        for id in &bloc.passport_countries {
+            if !names.contains_key(id.as_str()) {
+                findings.push(Finding::warning(
+                    REQUIREMENTS,
+                    format!(
+                        "blocs.{}.passport_countries: {} is not on the map",
+                        bloc_id, id
+                    ),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::{NaiveDate, Utc};
+use petabroad_core::data::freshness::DEFAULT_STALE_AFTER_DAYS;
+use petabroad_data::dataset::Dataset;
+use petabroad_data::diff::diff_paths;
+use petabroad_data::import::read_sheet;
+use petabroad_data::lint::lint;
+use petabroad_data::stats::Stats;
+use petabroad_data::validate::{load_finding, validate, validate_dir};
+use petabroad_data::{has_errors, Finding};
+use std::env;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: petabroad-data [--data <dir>] <command>
+
+Commands:
+  validate                  check the files parse and only refer to known
+                            countries, blocs and breeds
+  lint [--today <date>] [--stale-after <days>]
+                            flag missing species coverage, records due for
+                            verification and bloc/member conflicts
+  stats                     coverage of the map per region
+  diff <old> <new>          summarise the changes between two data files or
+                            data directories
+  import <sheet.csv> [--out <dir>]
+                            read a requirements sheet into requirements.json
+                            of the data directory, or of a copy of it in
+                            <dir>; see the import module for the columns
+
+Options:
+  --data <dir>              data directory, core/data of the source tree by
+                            default
+
+Exit status is 1 when validate or lint find errors, diff finds changes or
+import rejects the sheet, and 2 on bad usage or unreadable files.";
+
+struct Args {
+    data: PathBuf,
+    out: Option<PathBuf>,
+    today: NaiveDate,
+    stale_after_days: u32,
+    command: Vec<String>,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        data: Dataset::bundled_dir(),
+        out: None,
+        today: Utc::now().date_naive(),
+        stale_after_days: DEFAULT_STALE_AFTER_DAYS,
+        command: vec![],
+    };
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} needs a value", name));
+        match arg.as_str() {
+            "--data" => parsed.data = PathBuf::from(value("--data")?),
+            "--out" => parsed.out = Some(PathBuf::from(value("--out")?)),
+            "--today" => {
+                let date = value("--today")?;
+                parsed.today = date
+                    .parse()
+                    .map_err(|_| format!("--today expects YYYY-MM-DD, got {}", date))?;
+            }
+            "--stale-after" => {
+                let days = value("--stale-after")?;
+                parsed.stale_after_days = days
+                    .parse()
+                    .map_err(|_| format!("--stale-after expects days, got {}", days))?;
+            }
+            option if option.starts_with("--") => return Err(format!("unknown option {}", option)),
+            _ => parsed.command.push(arg),
+        }
+    }
+    Ok(parsed)
+}
+
+fn report(findings: &[Finding]) -> ExitCode {
+    for finding in findings {
+        println!("{}", finding);
+    }
+    if has_errors(findings) {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn load(dir: &Path) -> Result<Dataset, ExitCode> {
+    Dataset::load(dir).map_err(|errors| {
+        for error in &errors {
+            println!("{}", load_finding(error));
+        }
+        ExitCode::from(1)
+    })
+}
+
+fn import(args: &Args, sheet: &str) -> ExitCode {
+    let mut dataset = match load(&args.data) {
+        Ok(dataset) => dataset,
+        Err(code) => return code,
+    };
+    let file = match File::open(sheet) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("{}: {}", sheet, e);
+            return ExitCode::from(2);
+        }
+    };
+    let blocs: Vec<String> = dataset
+        .requirements
+        .blocs()
+        .map(|(id, _)| id.to_string())
+        .collect();
+    let blocs: Vec<&str> = blocs.iter().map(|id| id.as_str()).collect();
+    let import = read_sheet(file, &blocs);
+    if !import.errors.is_empty() {
+        for error in &import.errors {
+            println!("{}: {}", sheet, error);
+        }
+        return ExitCode::from(1);
+    }
+    import.apply_to(&mut dataset);
+    let findings = validate(&dataset);
+    if has_errors(&findings) {
+        return report(&findings);
+    }
+    let out = args.out.as_deref().unwrap_or(&args.data);
+    match dataset.write_requirements(&args.data, out) {
+        Ok(path) => {
+            let ids: Vec<&str> = import.countries.iter().map(|c| c.id.as_str()).collect();
+            println!("Imported {} countries into {}", ids.len(), path.display());
+            if !ids.is_empty() {
+                println!("{}", ids.join(", "));
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}: {}", out.display(), e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: Args) -> ExitCode {
+    let command: Vec<&str> = args.command.iter().map(|s| s.as_str()).collect();
+    match command.as_slice() {
+        ["validate"] => {
+            let findings = validate_dir(&args.data);
+            if findings.is_empty() {
+                println!("{}: valid", args.data.display());
+            }
+            report(&findings)
+        }
+        ["lint"] => match load(&args.data) {
+            Ok(dataset) => {
+                let findings = lint(&dataset, args.today, args.stale_after_days);
+                if findings.is_empty() {
+                    println!("{}: nothing to report", args.data.display());
+                }
+                report(&findings)
+            }
+            Err(code) => code,
+        },
+        ["stats"] => match load(&args.data) {
+            Ok(dataset) => {
+                print!("{}", Stats::new(&dataset));
+                ExitCode::SUCCESS
+            }
+            Err(code) => code,
+        },
+        ["diff", old, new] => match diff_paths(Path::new(old), Path::new(new)) {
+            Ok(diffs) if diffs.is_empty() => {
+                println!("No changes");
+                ExitCode::SUCCESS
+            }
+            Ok(diffs) => {
+                for diff in diffs {
+                    print!("{}", diff);
+                }
+                ExitCode::from(1)
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::from(2)
+            }
+        },
+        ["import", sheet] => import(&args, sheet),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    match parse_args(args.into_iter()) {
+        Ok(args) => run(args),
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
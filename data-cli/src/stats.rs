@@ -0,0 +1,139 @@
+//! How much of the map each region's data covers.
+
+use petabroad_core::map_data::get_countries_names;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::dataset::Dataset;
+
+/// Region id for map countries the regions dataset does not list
+pub const UNASSIGNED: &str = "unassigned";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionStats {
+    pub region: String,
+    /// Countries on the map
+    pub countries: usize,
+    /// With requirements of their own or of a bloc
+    pub requirements: usize,
+    /// With rules producing steps
+    pub rules: usize,
+    /// Served by at least one airline
+    pub airlines: usize,
+    pub breed_restrictions: usize,
+    /// Titer test laboratories located in the region
+    pub laboratories: usize,
+}
+
+impl RegionStats {
+    fn add(&mut self, other: &RegionStats) {
+        self.countries += other.countries;
+        self.requirements += other.requirements;
+        self.rules += other.rules;
+        self.airlines += other.airlines;
+        self.breed_restrictions += other.breed_restrictions;
+        self.laboratories += other.laboratories;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    /// Ordered by region id, map countries without a region last
+    pub regions: Vec<RegionStats>,
+    pub total: RegionStats,
+}
+
+impl Stats {
+    pub fn new(dataset: &Dataset) -> Stats {
+        let names = get_countries_names();
+        let served: HashSet<&str> = dataset
+            .airlines
+            .airlines()
+            .iter()
+            .flat_map(|a| a.countries.iter().chain(&a.cargo_only_countries))
+            .map(|id| id.as_str())
+            .collect();
+        let restricted: HashSet<&str> = dataset.breeds.countries().map(|(id, _)| id).collect();
+        let stats_of = |region: &str, ids: &[&str]| RegionStats {
+            region: region.to_string(),
+            countries: ids.len(),
+            requirements: ids
+                .iter()
+                .filter(|id| dataset.requirements.for_country(id).is_some())
+                .count(),
+            rules: ids
+                .iter()
+                .filter(|id| !dataset.rules.rules_in(id, &dataset.requirements).is_empty())
+                .count(),
+            airlines: ids.iter().filter(|id| served.contains(*id)).count(),
+            breed_restrictions: ids.iter().filter(|id| restricted.contains(*id)).count(),
+            laboratories: dataset
+                .laboratories
+                .iter()
+                .filter(|lab| ids.contains(&lab.country.as_str()))
+                .count(),
+        };
+
+        let mut regions: Vec<RegionStats> = dataset
+            .regions
+            .regions()
+            .map(|(region, members)| {
+                let ids: Vec<&str> = members
+                    .iter()
+                    .map(|id| id.as_str())
+                    .filter(|id| names.contains_key(id))
+                    .collect();
+                stats_of(region, &ids)
+            })
+            .collect();
+        let mut unassigned: Vec<&str> = names
+            .keys()
+            .copied()
+            .filter(|id| dataset.regions.region_of(id).is_none())
+            .collect();
+        unassigned.sort_unstable();
+        if !unassigned.is_empty() {
+            regions.push(stats_of(UNASSIGNED, &unassigned));
+        }
+        let mut total = RegionStats {
+            region: "total".to_string(),
+            ..RegionStats::default()
+        };
+        for region in &regions {
+            total.add(region);
+        }
+        Stats { regions, total }
+    }
+}
+
+fn share(count: usize, of: usize) -> String {
+    if of == 0 {
+        return count.to_string();
+    }
+    format!("{} ({}%)", count, count * 100 / of)
+}
+
+/// Plain text table for terminals and review comments
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12} {:>9} {:>13} {:>13} {:>13} {:>13} {:>5}",
+            "region", "countries", "requirements", "rules", "airlines", "breeds", "labs"
+        )?;
+        for row in self.regions.iter().chain([&self.total]) {
+            writeln!(
+                f,
+                "{:<12} {:>9} {:>13} {:>13} {:>13} {:>13} {:>5}",
+                row.region,
+                row.countries,
+                share(row.requirements, row.countries),
+                share(row.rules, row.countries),
+                share(row.airlines, row.countries),
+                share(row.breed_restrictions, row.countries),
+                row.laboratories
+            )?;
+        }
+        Ok(())
+    }
+}
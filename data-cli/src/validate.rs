@@ -0,0 +1,384 @@
+//! Checks the data files can be read by the app and only refer to countries,
+//! blocs and breeds which exist.
+
+use petabroad_core::data::requirements::CountryRequirements;
+use petabroad_core::map_data::get_countries_names;
+use petabroad_core::rules::condition::Condition;
+use petabroad_core::rules::Rule;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use crate::dataset::{
+    Dataset, LoadError, AIRLINES, BREEDS, CERTIFICATES, HISTORY, LABORATORIES, REGIONS,
+    REQUIREMENTS, RULES,
+};
+use crate::diff::key_of;
+use crate::Finding;
+
+/// Loads the dataset in `dir` and checks it, a dataset which can not be
+/// loaded is reported by its malformed files
+pub fn validate_dir(dir: &Path) -> Vec<Finding> {
+    match Dataset::load(dir) {
+        Ok(dataset) => validate(&dataset),
+        Err(errors) => errors.iter().map(load_finding).collect(),
+    }
+}
+
+pub fn load_finding(error: &LoadError) -> Finding {
+    let file = error
+        .file()
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let message = match error {
+        LoadError::Io { error, .. } => error.to_string(),
+        LoadError::Json { error, .. } => format!("malformed document: {}", error),
+    };
+    Finding::error(&file, message)
+}
+
+pub fn validate(dataset: &Dataset) -> Vec<Finding> {
+    let mut checker = Checker {
+        names: get_countries_names(),
+        blocs: dataset.requirements.blocs().map(|(id, _)| id).collect(),
+        findings: vec![],
+    };
+    checker.unknown_fields(dataset);
+    checker.requirements(dataset);
+    checker.rules(dataset);
+    checker.airlines(dataset);
+    checker.breeds(dataset);
+    checker.laboratories(dataset);
+    checker.regions(dataset);
+    checker.history(dataset);
+    checker.certificates(dataset);
+    checker.findings
+}
+
+struct Checker<'a> {
+    names: HashMap<&'static str, &'static str>,
+    blocs: HashSet<&'a str>,
+    findings: Vec<Finding>,
+}
+
+impl Checker<'_> {
+    fn error(&mut self, file: &str, message: String) {
+        self.findings.push(Finding::error(file, message));
+    }
+
+    fn countries<'s>(&mut self, file: &str, path: &str, ids: impl IntoIterator<Item = &'s String>) {
+        for id in ids {
+            if !self.names.contains_key(id.as_str()) {
+                self.error(file, format!("{}: unknown country {}", path, id));
+            }
+        }
+    }
+
+    /// Authorities are blocs or countries
+    fn authority(&mut self, file: &str, path: &str, id: &str) {
+        if !self.blocs.contains(id) && !self.names.contains_key(id) {
+            self.error(file, format!("{}: unknown bloc or country {}", path, id));
+        }
+    }
+
+    fn unknown_fields(&mut self, dataset: &Dataset) {
+        let canonical = dataset.canonical();
+        for (file, document) in &dataset.documents {
+            let mut unknown = vec![];
+            unknown_fields(document, &canonical[file], "", &mut unknown);
+            for path in unknown {
+                self.error(file, format!("{}: unknown field", path));
+            }
+        }
+    }
+
+    fn record(&mut self, file: &str, path: &str, record: &CountryRequirements) {
+        if let Some(titer) = &record.titer {
+            self.countries(
+                file,
+                &format!("{}.titer.exempt_origins", path),
+                &titer.exempt_origins,
+            );
+            self.authority(
+                file,
+                &format!("{}.titer.lab_approval", path),
+                &titer.lab_approval,
+            );
+        }
+    }
+
+    fn requirements(&mut self, dataset: &Dataset) {
+        let db = &dataset.requirements;
+        for (id, bloc) in sorted(db.blocs()) {
+            let path = format!("blocs.{}", id);
+            self.countries(REQUIREMENTS, &format!("{}.members", path), &bloc.members);
+            // passport countries mirror the regulation, those off the map
+            // are only linted
+            self.record(
+                REQUIREMENTS,
+                &format!("{}.requirements", path),
+                &bloc.requirements,
+            );
+        }
+        for (id, record) in sorted(db.countries()) {
+            let path = format!("countries.{}", id);
+            if !self.names.contains_key(id) {
+                self.error(REQUIREMENTS, format!("{}: unknown country", path));
+            }
+            self.record(REQUIREMENTS, &path, record);
+        }
+        let records: HashSet<&str> = db
+            .blocs()
+            .map(|(id, _)| id)
+            .chain(db.countries().map(|(id, _)| id))
+            .collect();
+        if let Some(Value::Object(sources)) = dataset.documents[REQUIREMENTS].get("sources") {
+            for id in sources.keys() {
+                if !records.contains(id.as_str()) {
+                    self.error(
+                        REQUIREMENTS,
+                        format!("sources.{}: no bloc or country record", id),
+                    );
+                }
+            }
+        }
+    }
+
+    fn rule_list(&mut self, path: &str, rules: &[Rule]) {
+        let mut seen = HashSet::new();
+        for rule in rules {
+            if !seen.insert(rule.id.as_str()) {
+                self.error(RULES, format!("{}: duplicate rule {}", path, rule.id));
+            }
+            self.condition(&format!("{}[{}].when", path, rule.id), &rule.when);
+        }
+    }
+
+    fn condition(&mut self, path: &str, condition: &Condition) {
+        match condition {
+            Condition::All(conditions) | Condition::Any(conditions) => {
+                for c in conditions {
+                    self.condition(path, c);
+                }
+            }
+            Condition::Not(c) => self.condition(path, c),
+            Condition::OriginIn(ids) | Condition::TransitThrough(ids) => {
+                self.countries(RULES, path, ids)
+            }
+            Condition::OriginInBloc(bloc) => {
+                if !self.blocs.contains(bloc.as_str()) {
+                    self.error(RULES, format!("{}: unknown bloc {}", path, bloc));
+                }
+            }
+            Condition::Always
+            | Condition::OriginRabiesStatus(_)
+            | Condition::TiterExemptOrigin
+            | Condition::Species(_)
+            | Condition::AgeUnderWeeks(_)
+            | Condition::Vaccinated
+            | Condition::Transit => {}
+        }
+    }
+
+    fn rules(&mut self, dataset: &Dataset) {
+        for (id, rules) in sorted(dataset.rules.blocs()) {
+            let path = format!("blocs.{}", id);
+            if !self.blocs.contains(id) {
+                self.error(RULES, format!("{}: no such bloc in {}", path, REQUIREMENTS));
+            }
+            self.rule_list(&path, rules);
+        }
+        for (id, rules) in sorted(dataset.rules.countries()) {
+            let path = format!("countries.{}", id);
+            if !self.names.contains_key(id) {
+                self.error(RULES, format!("{}: unknown country", path));
+            }
+            self.rule_list(&path, rules);
+        }
+    }
+
+    fn airlines(&mut self, dataset: &Dataset) {
+        let mut seen = HashSet::new();
+        for airline in dataset.airlines.airlines() {
+            if !seen.insert(airline.iata.as_str()) {
+                self.error(AIRLINES, format!("duplicate airline {}", airline.iata));
+            }
+            self.countries(
+                AIRLINES,
+                &format!("{}.countries", airline.iata),
+                &airline.countries,
+            );
+            self.countries(
+                AIRLINES,
+                &format!("{}.cargo_only_countries", airline.iata),
+                &airline.cargo_only_countries,
+            );
+        }
+    }
+
+    fn breeds(&mut self, dataset: &Dataset) {
+        let mut breeds = HashSet::new();
+        for breed in dataset.breeds.breeds() {
+            if !breeds.insert(breed.id.as_str()) {
+                self.error(BREEDS, format!("duplicate breed {}", breed.id));
+            }
+        }
+        for (id, restrictions) in sorted(dataset.breeds.countries()) {
+            let path = format!("countries.{}", id);
+            if !self.names.contains_key(id) {
+                self.error(BREEDS, format!("{}: unknown country", path));
+            }
+            for restriction in restrictions {
+                if !breeds.contains(restriction.breed.as_str()) {
+                    self.error(
+                        BREEDS,
+                        format!("{}: unknown breed {}", path, restriction.breed),
+                    );
+                }
+            }
+        }
+    }
+
+    fn laboratories(&mut self, dataset: &Dataset) {
+        let mut seen = HashSet::new();
+        for lab in &dataset.laboratories {
+            if !seen.insert(lab.id.as_str()) {
+                self.error(LABORATORIES, format!("duplicate laboratory {}", lab.id));
+            }
+            if !self.names.contains_key(lab.country.as_str()) {
+                self.error(
+                    LABORATORIES,
+                    format!("{}.country: unknown country {}", lab.id, lab.country),
+                );
+            }
+            for authority in &lab.approved_by {
+                self.authority(LABORATORIES, &format!("{}.approved_by", lab.id), authority);
+            }
+        }
+    }
+
+    /// Versions are dated in order and the latest one stands for the record
+    /// in requirements.json
+    fn history(&mut self, dataset: &Dataset) {
+        for (id, versions) in dataset.history.records() {
+            let path = format!("records.{}", id);
+            if dataset.requirements.record(id).is_none() {
+                self.error(
+                    HISTORY,
+                    format!("{}: no bloc or country record in {}", path, REQUIREMENTS),
+                );
+            }
+            for (i, version) in versions.iter().enumerate() {
+                let path = format!("{}[{}]", path, i);
+                if i > 0 && versions[i - 1].effective >= version.effective {
+                    self.error(
+                        HISTORY,
+                        format!(
+                            "{}: effective {} is not after the version before",
+                            path, version.effective
+                        ),
+                    );
+                }
+                let latest = i + 1 == versions.len();
+                match &version.requirements {
+                    Some(_) if latest => self.error(
+                        HISTORY,
+                        format!(
+                            "{}: the latest version is the record in {}, it has no requirements of its own",
+                            path, REQUIREMENTS
+                        ),
+                    ),
+                    Some(record) => self.record(HISTORY, &format!("{}.requirements", path), record),
+                    None if !latest => self.error(
+                        HISTORY,
+                        format!("{}: requirements are only left out for the latest version", path),
+                    ),
+                    None => {}
+                }
+            }
+        }
+    }
+
+    fn certificates(&mut self, dataset: &Dataset) {
+        let mut seen = HashSet::new();
+        for template in &dataset.certificates {
+            let id = &template.id;
+            if !seen.insert(id.as_str()) {
+                self.error(CERTIFICATES, format!("duplicate certificate {}", id));
+            }
+            if template.destinations.is_empty() {
+                self.error(CERTIFICATES, format!("{}.destinations: no destination", id));
+            }
+            for destination in &template.destinations {
+                self.authority(CERTIFICATES, &format!("{}.destinations", id), destination);
+            }
+            for origin in &template.passport_origins {
+                self.authority(CERTIFICATES, &format!("{}.passport_origins", id), origin);
+            }
+            for (i, section) in template.sections.iter().enumerate() {
+                if section.fields.is_empty() {
+                    self.error(CERTIFICATES, format!("{}.sections[{}]: no fields", id, i));
+                }
+            }
+        }
+    }
+
+    fn regions(&mut self, dataset: &Dataset) {
+        let mut region_of: BTreeMap<&str, &str> = BTreeMap::new();
+        for (region, members) in dataset.regions.regions() {
+            self.countries(REGIONS, &format!("regions.{}", region), members);
+            for id in members {
+                if let Some(other) = region_of.insert(id, region) {
+                    self.error(
+                        REGIONS,
+                        format!("{} is in both {} and {}", id, other, region),
+                    );
+                }
+            }
+        }
+        let mut missing: Vec<&str> = self
+            .names
+            .keys()
+            .copied()
+            .filter(|id| !region_of.contains_key(id))
+            .collect();
+        missing.sort_unstable();
+        for id in missing {
+            self.error(REGIONS, format!("{} is in no region", id));
+        }
+    }
+}
+
+/// Entries of a hash map ordered by key, so that reports are stable
+fn sorted<'a, T: ?Sized>(entries: impl Iterator<Item = (&'a str, &'a T)>) -> Vec<(&'a str, &'a T)> {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by_key(|(id, _)| *id);
+    entries
+}
+
+/// Paths of the fields of `document` which the app drops when reading it
+fn unknown_fields(document: &Value, known: &Value, path: &str, unknown: &mut Vec<String>) {
+    match (document, known) {
+        (Value::Object(fields), Value::Object(known)) => {
+            for (key, value) in fields {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match known.get(key) {
+                    Some(known) => unknown_fields(value, known, &path, unknown),
+                    None => unknown.push(path),
+                }
+            }
+        }
+        (Value::Array(items), Value::Array(known)) => {
+            for (i, (item, known)) in items.iter().zip(known).enumerate() {
+                let key = key_of(item).unwrap_or_else(|| i.to_string());
+                unknown_fields(item, known, &format!("{}[{}]", path, key), unknown);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,53 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_petabroad-data"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn validates_the_bundled_dataset() {
+    let output = run(&["validate"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).ends_with("data: valid\n"));
+}
+
+#[test]
+fn lints_with_a_fixed_date() {
+    let output = run(&["lint", "--today", "2026-09-20", "--stale-after", "365"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(!stdout(&output).contains("verified"));
+}
+
+#[test]
+fn prints_stats_table() {
+    let output = run(&["stats"]);
+    assert_eq!(output.status.code(), Some(0));
+    let text = stdout(&output);
+    assert!(text.contains("europe"));
+    assert!(text.lines().last().unwrap().starts_with("total"));
+}
+
+#[test]
+fn diff_exit_status_follows_diff() {
+    let data = concat!(env!("CARGO_MANIFEST_DIR"), "/../core/data");
+    let same = run(&["diff", data, data]);
+    assert_eq!(same.status.code(), Some(0));
+    assert_eq!(stdout(&same), "No changes\n");
+    let missing = run(&["diff", data, "/nonexistent/requirements.json"]);
+    assert_eq!(missing.status.code(), Some(2));
+}
+
+#[test]
+fn rejects_bad_usage() {
+    assert_eq!(run(&[]).status.code(), Some(2));
+    assert_eq!(run(&["lint", "--today", "tomorrow"]).status.code(), Some(2));
+    assert_eq!(run(&["frobnicate"]).status.code(), Some(2));
+    assert_eq!(run(&["--help"]).status.code(), Some(0));
+}
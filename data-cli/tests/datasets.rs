@@ -0,0 +1,244 @@
+use chrono::NaiveDate;
+use petabroad_data::dataset::{Dataset, CERTIFICATES, FILES, HISTORY, REQUIREMENTS, RULES};
+use petabroad_data::diff::{diff, diff_paths, Change};
+use petabroad_data::lint::lint;
+use petabroad_data::stats::Stats;
+use petabroad_data::validate::{validate, validate_dir};
+use petabroad_data::{has_errors, Finding, Severity};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+/// Copy of the bundled dataset with `edit` applied to one of its files
+fn edited_dataset(name: &str, file: &str, edit: impl FnOnce(&mut Value)) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("petabroad-data-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for f in FILES {
+        fs::copy(Dataset::bundled_dir().join(f), dir.join(f)).unwrap();
+    }
+    let path = dir.join(file);
+    let mut document: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    edit(&mut document);
+    fs::write(&path, serde_json::to_string_pretty(&document).unwrap()).unwrap();
+    dir
+}
+
+fn messages(findings: &[Finding]) -> Vec<String> {
+    findings.iter().map(|f| f.to_string()).collect()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn bundled_dataset_is_valid() {
+    let findings = validate_dir(&Dataset::bundled_dir());
+    assert!(findings.is_empty(), "{:#?}", messages(&findings));
+}
+
+#[test]
+fn reports_unknown_ids_and_fields() {
+    let dir = edited_dataset("references", RULES, |rules| {
+        let jp = rules["countries"]["JP"].as_array_mut().unwrap();
+        jp[0]["when"] = json!({ "origin_in": ["US", "QQ"] });
+        jp[1]["whenn"] = json!("always");
+        jp.push(jp[0].clone());
+        rules["blocs"]["ASEAN"] = json!([]);
+    });
+    let findings = validate_dir(&dir);
+    assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    let messages = messages(&findings);
+    for expected in [
+        "error: rules.json: countries.JP[rabies-vaccination].whenn: unknown field",
+        "error: rules.json: countries.JP[microchip].when: unknown country QQ",
+        "error: rules.json: countries.JP: duplicate rule microchip",
+        "error: rules.json: blocs.ASEAN: no such bloc in requirements.json",
+    ] {
+        assert!(
+            messages.iter().any(|m| m == expected),
+            "{} in {:#?}",
+            expected,
+            messages
+        );
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reports_every_malformed_file() {
+    let dir = edited_dataset("malformed", REQUIREMENTS, |requirements| {
+        requirements["countries"]["JP"]["quarantine_days"] = json!("none");
+    });
+    fs::remove_file(dir.join("titer_labs.json")).unwrap();
+    let findings = validate_dir(&dir);
+    assert_eq!(findings.len(), 2, "{:#?}", messages(&findings));
+    assert_eq!(findings[0].file, REQUIREMENTS);
+    assert!(findings[0].message.starts_with("malformed document"));
+    assert_eq!(findings[1].file, "titer_labs.json");
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reports_history_out_of_order() {
+    let dir = edited_dataset("history", HISTORY, |history| {
+        let gb = history["records"]["GB"].as_array_mut().unwrap();
+        gb[0]["requirements"]["titer"]["exempt_origins"] = json!(["QQ"]);
+        gb.push(json!({ "effective": "2011-01-01", "summary": "x", "requirements": {} }));
+        history["records"]["BR"] = json!([{ "effective": "2020-01-01", "summary": "y" }]);
+    });
+    let messages = messages(&validate_dir(&dir));
+    assert_eq!(
+        messages,
+        [
+            "error: history.json: records.BR: no bloc or country record in requirements.json",
+            "error: history.json: records.GB[0].requirements.titer.exempt_origins: unknown country QQ",
+            "error: history.json: records.GB[1]: requirements are only left out for the latest version",
+            "error: history.json: records.GB[2]: effective 2011-01-01 is not after the version before",
+            "error: history.json: records.GB[2]: the latest version is the record in requirements.json, it has no requirements of its own",
+        ]
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reports_unknown_certificate_destinations() {
+    let dir = edited_dataset("certificates", CERTIFICATES, |certificates| {
+        let templates = certificates["certificates"].as_array_mut().unwrap();
+        templates[0]["destinations"] = json!(["EU", "QQ"]);
+        templates[1]["sections"][0]["fields"] = json!([]);
+        let duplicate = templates[2].clone();
+        templates.push(duplicate);
+    });
+    let messages = messages(&validate_dir(&dir));
+    assert_eq!(
+        messages,
+        [
+            "error: certificates.json: eu-annex-iv.destinations: unknown bloc or country QQ",
+            "error: certificates.json: gb-ahc.sections[0]: no fields",
+            "error: certificates.json: duplicate certificate au-import-permit",
+        ]
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn lints_bundled_dataset() {
+    let dataset = Dataset::load(&Dataset::bundled_dir()).unwrap();
+    let findings = lint(&dataset, date(2026, 9, 20), 180);
+    assert!(!has_errors(&findings));
+    let messages = messages(&findings);
+    assert!(messages.contains(&"warning: rules.json: NO: no rules for cat, ferret".to_string()));
+    assert!(messages.contains(
+        &"warning: requirements.json: blocs.EU.passport_countries: GI is not on the map"
+            .to_string()
+    ));
+    assert!(messages.contains(
+        &"warning: requirements.json: SG: verified 222 days ago on 2026-02-10, due after 180"
+            .to_string()
+    ));
+    assert!(
+        !messages.iter().any(|m| m.contains("JP")),
+        "{:#?}",
+        messages
+    );
+}
+
+#[test]
+fn lints_bloc_conflicts() {
+    let dir = edited_dataset("conflicts", REQUIREMENTS, |requirements| {
+        requirements["blocs"]["NORDIC"] = json!({
+            "name": "Nordic",
+            "members": ["FI", "NO"],
+            "requirements": {},
+            "passport_countries": ["NO"]
+        });
+        requirements["countries"]["FI"] = json!({ "quarantine_days": 30 });
+        requirements["sources"]["NORDIC"] = json!([]);
+        requirements["sources"]["FI"] = json!([]);
+    });
+    let dataset = Dataset::load(&dir).unwrap();
+    assert!(validate(&dataset).is_empty());
+    let findings = lint(&dataset, date(2026, 9, 20), 180);
+    assert!(has_errors(&findings));
+    let messages = messages(&findings);
+    for expected in [
+        "error: requirements.json: FI: member of several blocs: EU, NORDIC",
+        "warning: requirements.json: blocs.NORDIC: member NO is also a passport country",
+        "warning: requirements.json: FI: overrides quarantine_days of bloc EU",
+        "warning: requirements.json: FI: never verified",
+        "warning: requirements.json: FI: cites no sources",
+    ] {
+        assert!(
+            messages.iter().any(|m| m == expected),
+            "{} in {:#?}",
+            expected,
+            messages
+        );
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn stats_cover_every_map_country() {
+    let dataset = Dataset::load(&Dataset::bundled_dir()).unwrap();
+    let stats = Stats::new(&dataset);
+    assert_eq!(stats.total.countries, 228);
+    assert!(stats.regions.iter().all(|r| r.region != "unassigned"));
+    let europe = stats.regions.iter().find(|r| r.region == "europe").unwrap();
+    assert!(europe.requirements >= 27);
+    assert_eq!(
+        stats.total.laboratories,
+        dataset.laboratories.len(),
+        "every laboratory is in some region"
+    );
+    assert!(stats.to_string().starts_with("region "));
+}
+
+#[test]
+fn diff_matches_list_entries_by_key() {
+    let old = json!({
+        "countries": { "JP": [{ "id": "a", "days": 1 }, { "id": "b" }] },
+        "members": ["FR", "DE"],
+        "points": [[1, 2]]
+    });
+    let new = json!({
+        "countries": { "JP": [{ "id": "c" }, { "id": "a", "days": 2 }] },
+        "members": ["DE", "AT"],
+        "points": [[1, 3]],
+        "name": "x"
+    });
+    let changes = diff(&old, &new);
+    let lines: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        lines,
+        [
+            "~ countries.JP[a].days: 1 → 2",
+            "- countries.JP[b]: {\"id\":\"b\"}",
+            "+ countries.JP[c]: {\"id\":\"c\"}",
+            "- members: \"FR\"",
+            "+ members: \"AT\"",
+            "~ points[0][1]: 2 → 3",
+            "+ name: \"x\"",
+        ]
+    );
+    assert!(matches!(changes[0], Change::Changed { .. }));
+    assert!(diff(&json!({ "a": ["FR", "DE"] }), &json!({ "a": ["DE", "FR"] })).is_empty());
+}
+
+#[test]
+fn diff_of_data_directories() {
+    let dir = edited_dataset("diff", REQUIREMENTS, |requirements| {
+        requirements["countries"]["JP"]["quarantine_days"] = json!(14);
+    });
+    let diffs = diff_paths(&Dataset::bundled_dir(), &dir).unwrap();
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(
+        diffs[0].to_string(),
+        "requirements.json: 0 added, 0 removed, 1 changed\n  ~ countries.JP.quarantine_days: 0 → 14\n"
+    );
+    assert!(diff_paths(&Dataset::bundled_dir(), &Dataset::bundled_dir())
+        .unwrap()
+        .is_empty());
+    fs::remove_dir_all(dir).unwrap();
+}
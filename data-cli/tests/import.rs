@@ -0,0 +1,190 @@
+use chrono::NaiveDate;
+use petabroad_core::data::requirements::{RabiesStatus, TiterMethod};
+use petabroad_data::dataset::{to_canonical_json, Dataset, REQUIREMENTS};
+use petabroad_data::import::{read_sheet, resolve_country};
+use petabroad_data::validate::validate;
+use std::fs;
+use std::process::Command;
+
+const SHEET: &str = "\
+Country,Microchip ISO,Titer min IU,Titer methods,Titer wait days,Titer exempt origins,Quarantine days,Import permit,Rabies status,Verified on,Verified by,Source titles,Source URLs
+Republic of Korea,yes,0.5,FAVN;RFFIT,0,,0,no,Controlled,2026-10-01,Research desk,Act on the Prevention of Contagious Animal Diseases,https://www.qia.go.kr
+Côte d'Ivoire,no,,,,,,,high incidence,,,,
+U.S.A.,,,,,,,no,,,,,
+Japan,yes,0.5,favn,180,\"Australia; New Zealand\",0,,free,2026-10-01,Research desk,,
+";
+
+#[test]
+fn resolves_names_aliases_and_ids() {
+    for (name, id) in [
+        ("Japan", "JP"),
+        ("jp", "JP"),
+        ("  south korea ", "KR"),
+        ("United States of America", "US"),
+        ("U.S.A.", "US"),
+        ("Côte d'Ivoire", "CI"),
+        ("Cote dIvoire", "CI"),
+        ("Ivory Coast", "CI"),
+        ("France", "FR"),
+        ("French Guiana", "GF"),
+        ("Netherlands", "NL"),
+        ("Bonaire", "BQBO"),
+        ("Reunion", "RE"),
+        ("St. Lucia", "LC"),
+        ("Trinidad & Tobago", "TT"),
+        ("Russia", "RU"),
+    ] {
+        assert_eq!(resolve_country(name), Ok(id), "{}", name);
+    }
+    assert_eq!(
+        resolve_country("Atlantis"),
+        Err("unknown country \"Atlantis\"".to_string())
+    );
+}
+
+#[test]
+fn reads_rows_into_requirements() {
+    let import = read_sheet(SHEET.as_bytes(), &["EU"]);
+    assert!(import.errors.is_empty(), "{:#?}", import.errors);
+    let ids: Vec<&str> = import.countries.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["KR", "CI", "US", "JP"]);
+
+    let kr = &import.countries[0];
+    assert_eq!(kr.line, 2);
+    assert!(kr.requirements.microchip.as_ref().unwrap().iso_required);
+    assert!(
+        kr.requirements
+            .microchip
+            .as_ref()
+            .unwrap()
+            .before_vaccination
+    );
+    let titer = kr.requirements.titer.as_ref().unwrap();
+    assert_eq!(titer.methods, [TiterMethod::Favn, TiterMethod::Rffit]);
+    assert_eq!(titer.lab_approval, "KR");
+    assert_eq!(kr.requirements.import_permit, Some(false));
+    assert_eq!(
+        kr.requirements.rabies_status,
+        Some(RabiesStatus::Controlled)
+    );
+    let verified = kr.requirements.verified.as_ref().unwrap();
+    assert_eq!(verified.date, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+    assert_eq!(verified.verifier, "Research desk");
+    assert_eq!(kr.sources[0].url, "https://www.qia.go.kr");
+
+    let ci = &import.countries[1];
+    assert!(ci.requirements.titer.is_none());
+    assert_eq!(ci.requirements.quarantine_days, None);
+    assert_eq!(
+        ci.requirements.rabies_status,
+        Some(RabiesStatus::HighIncidence)
+    );
+    assert_eq!(
+        import.countries[3]
+            .requirements
+            .titer
+            .as_ref()
+            .unwrap()
+            .exempt_origins,
+        ["AU", "NZ"]
+    );
+}
+
+#[test]
+fn reports_row_errors_with_line_numbers() {
+    let sheet = "\
+country;titer_min_iu;titer_wait_days;import_permit;verified_on;source_titles;source_urls
+Japan;0.5;90;maybe;;;
+\"Atlantis
+(lost)\";;;;;;
+New Zealand;;ninety;;2026-13-01;Act;ftp://example.org
+Japan;;;;;;
+;;;;;;
+";
+    let import = read_sheet(sheet.as_bytes(), &[]);
+    let errors: Vec<String> = import.errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        [
+            "line 2, titer_methods: required with titer_min_iu",
+            "line 2, import_permit: expected yes or no, got \"maybe\"",
+            "line 3, country: unknown country \"Atlantis\n(lost)\"",
+            "line 5, titer_min_iu: required with titer_wait_days",
+            "line 5, titer_methods: required with titer_wait_days",
+            "line 5, titer_wait_days: expected a number of days, got \"ninety\"",
+            "line 5, verified_on: expected a date as YYYY-MM-DD, got \"2026-13-01\"",
+            "line 5, source_urls: expected a web address, got \"ftp://example.org\"",
+            "line 6, country: JP is already on line 2",
+        ]
+    );
+    assert!(import.countries.is_empty());
+}
+
+#[test]
+fn rejects_unknown_columns() {
+    let import = read_sheet("country,quarantine\nJapan,0\n".as_bytes(), &[]);
+    assert_eq!(
+        import.errors[0].to_string(),
+        "line 1, quarantine: unknown column"
+    );
+    let import = read_sheet("name\nJapan\n".as_bytes(), &[]);
+    assert!(import
+        .errors
+        .iter()
+        .any(|e| e.to_string() == "line 1: no country column"));
+}
+
+#[test]
+fn imported_dataset_validates() {
+    let mut dataset = Dataset::load(&Dataset::bundled_dir()).unwrap();
+    let import = read_sheet(SHEET.as_bytes(), &["EU"]);
+    import.apply_to(&mut dataset);
+    assert!(validate(&dataset).is_empty());
+    let jp = dataset.requirements.for_country("JP").unwrap();
+    assert_eq!(jp.titer.unwrap().exempt_origins, ["AU", "NZ"]);
+    assert!(dataset.requirements.sources("JP").is_empty());
+    assert_eq!(dataset.requirements.sources("KR").len(), 1);
+}
+
+#[test]
+fn bundled_requirements_are_in_canonical_layout() {
+    let dir = Dataset::bundled_dir();
+    let dataset = Dataset::load(&dir).unwrap();
+    let written = fs::read_to_string(dir.join(REQUIREMENTS)).unwrap();
+    assert_eq!(
+        to_canonical_json(&dataset.canonical()[REQUIREMENTS]),
+        written
+    );
+}
+
+#[test]
+fn import_command_writes_a_complete_dataset() {
+    let dir = std::env::temp_dir().join(format!("petabroad-import-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let sheet = dir.join("sheet.csv");
+    fs::write(&sheet, SHEET).unwrap();
+    let out = dir.join("data");
+    let run = |sheet: &str| {
+        Command::new(env!("CARGO_BIN_EXE_petabroad-data"))
+            .args(["import", sheet, "--out", out.to_str().unwrap()])
+            .output()
+            .unwrap()
+    };
+
+    let output = run(sheet.to_str().unwrap());
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("KR, CI, US, JP"));
+    let imported = Dataset::load(&out).unwrap();
+    assert!(validate(&imported).is_empty());
+    assert!(imported.requirements.for_country("KR").is_some());
+
+    let bad = dir.join("bad.csv");
+    fs::write(&bad, "country,quarantine_days\nJapan,soon\n").unwrap();
+    let before = fs::read_to_string(out.join(REQUIREMENTS)).unwrap();
+    let output = run(bad.to_str().unwrap());
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .contains("bad.csv: line 2, quarantine_days: expected a number of days, got \"soon\""));
+    assert_eq!(fs::read_to_string(out.join(REQUIREMENTS)).unwrap(), before);
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,96 @@
+use petabroad_core::client::{CachedResponse, DataClient, HttpResponse, ResponseCache, Transport};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Cache, Request, RequestCache, RequestInit, Response};
+
+/// Backend root, override at build time with `PETABROAD_API_URL`
+const DEFAULT_API_URL: &str = "http://127.0.0.1:8080";
+const CACHE_NAME: &str = "petabroad-api-v1";
+
+fn js_error(e: JsValue) -> String {
+    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+}
+
+/// `fetch` with the browser's own HTTP cache bypassed, revalidation is done by `DataClient`
+pub struct FetchTransport;
+
+impl Transport for FetchTransport {
+    async fn get(&self, url: &str, if_none_match: Option<&str>) -> Result<HttpResponse, String> {
+        let window = web_sys::window().ok_or("No window")?;
+        let init = RequestInit::new();
+        init.set_method("GET");
+        init.set_cache(RequestCache::NoStore);
+        let request = Request::new_with_str_and_init(url, &init).map_err(js_error)?;
+        if let Some(etag) = if_none_match {
+            request
+                .headers()
+                .set("If-None-Match", etag)
+                .map_err(js_error)?;
+        }
+        let response: Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(js_error)?
+            .dyn_into()
+            .map_err(js_error)?;
+        let etag = response.headers().get("ETag").ok().flatten();
+        let body = JsFuture::from(response.text().map_err(js_error)?)
+            .await
+            .map_err(js_error)?
+            .as_string()
+            .unwrap_or_default();
+        Ok(HttpResponse {
+            status: response.status(),
+            etag,
+            body,
+        })
+    }
+}
+
+/// Keeps `CachedResponse`s in Cache Storage, unavailable outside secure contexts
+pub struct BrowserCache;
+
+impl BrowserCache {
+    async fn open() -> Option<Cache> {
+        let caches = web_sys::window()?.caches().ok()?;
+        JsFuture::from(caches.open(CACHE_NAME))
+            .await
+            .ok()?
+            .dyn_into()
+            .ok()
+    }
+}
+
+impl ResponseCache for BrowserCache {
+    async fn load(&self, url: &str) -> Option<CachedResponse> {
+        let cache = BrowserCache::open().await?;
+        let response: Response = JsFuture::from(cache.match_with_str(url))
+            .await
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        let text = JsFuture::from(response.text().ok()?)
+            .await
+            .ok()?
+            .as_string()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    async fn store(&self, url: &str, response: &CachedResponse) {
+        let cache = match BrowserCache::open().await {
+            Some(cache) => cache,
+            None => return,
+        };
+        let body = serde_json::to_string(response).expect("CachedResponse is serializable");
+        if let Ok(entry) = Response::new_with_opt_str(Some(&body)) {
+            let _ = JsFuture::from(cache.put_with_str(url, &entry)).await;
+        }
+    }
+}
+
+pub fn data_client() -> DataClient<FetchTransport, BrowserCache> {
+    DataClient::new(
+        option_env!("PETABROAD_API_URL").unwrap_or(DEFAULT_API_URL),
+        FetchTransport,
+        BrowserCache,
+    )
+}
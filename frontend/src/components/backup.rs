@@ -0,0 +1,209 @@
+use crate::utils::export::download;
+use chrono::Local;
+use petabroad_core::backup::{Backup, BackupError, ImportMode, Kept};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::session::Session;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::File;
+use yew::{html, Callback, ChangeData, Component, ComponentLink, Html, Properties, ShouldRender};
+
+pub enum Msg {
+    Export,
+    File(ChangeData),
+    /// Text of the picked file, `None` if the browser could not read it
+    Read(Option<String>),
+    Apply(ImportMode),
+    Cancel,
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    /// Current state, saved to the file and merged with a restored one
+    pub session: Session,
+    pub locale: Locale,
+    pub onimport: Option<Callback<Session>>,
+}
+
+enum Status {
+    /// Read and checked, waiting for the user to merge or replace
+    Pending(Box<Backup>),
+    Imported(ImportMode, Vec<Kept>),
+    Failed(BackupError),
+    Unreadable,
+}
+
+/// Saves the state to a file and restores it, e.g. on another device
+pub struct BackupComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+    status: Option<Status>,
+}
+
+async fn read_text(file: File) -> Option<String> {
+    JsFuture::from(file.text()).await.ok()?.as_string()
+}
+
+impl BackupComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn export(&self) {
+        let today = Local::now().date_naive();
+        let json = Backup::new(self.props.session.clone(), today).to_json();
+        let file_name = format!("petabroad-backup-{}.json", today.format("%Y-%m-%d"));
+        if let Err(e) = download(&file_name, "application/json", &json) {
+            crate::log!(&format!("Unable to save backup: {:?}", e));
+        }
+    }
+
+    fn view_status(&self) -> Html {
+        let localizer = self.localizer();
+        match &self.status {
+            None => html! {},
+            Some(Status::Pending(backup)) => {
+                let onmerge = self.link.callback(|_| Msg::Apply(ImportMode::Merge));
+                let onreplace = self.link.callback(|_| Msg::Apply(ImportMode::Replace));
+                let oncancel = self.link.callback(|_| Msg::Cancel);
+                let found = Message::new("backup-found").arg("date", backup.exported);
+                html! {
+                    <div class="backup_status">
+                        <p>{localizer.format(&found)}</p>
+                        <button class="pet_profile_add_button" onclick={onmerge}>
+                            {localizer.tr("backup-merge")}
+                        </button>
+                        <button class="pet_profile_add_button" onclick={onreplace}>
+                            {localizer.tr("backup-replace")}
+                        </button>
+                        <button class="pet_profile_add_button" onclick={oncancel}>
+                            {localizer.tr("backup-cancel")}
+                        </button>
+                    </div>
+                }
+            }
+            Some(Status::Imported(mode, kept)) => {
+                let done = match mode {
+                    ImportMode::Merge => "backup-merged",
+                    ImportMode::Replace => "backup-replaced",
+                };
+                html! {
+                    <div class="backup_status" role="status">
+                        <p>{localizer.tr(done)}</p>
+                        { for kept.iter().map(|k| html! { <p>{localizer.format(&k.message())}</p> }) }
+                    </div>
+                }
+            }
+            Some(Status::Failed(error)) => {
+                let problems = match error {
+                    BackupError::Invalid(problems) => html! {
+                        <ul>
+                            { for problems.iter().map(|p| html! { <li>{localizer.format(&p.message())}</li> }) }
+                        </ul>
+                    },
+                    _ => html! {},
+                };
+                html! {
+                    <div class="backup_status backup_error" role="alert">
+                        <p>{localizer.format(&error.message())}</p>
+                        { problems }
+                    </div>
+                }
+            }
+            Some(Status::Unreadable) => html! {
+                <div class="backup_status backup_error" role="alert">
+                    <p>{localizer.tr("backup-error-read")}</p>
+                </div>
+            },
+        }
+    }
+}
+
+impl Component for BackupComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        BackupComponent {
+            props,
+            link,
+            status: None,
+        }
+    }
+
+    fn view(&self) -> Html {
+        let onexport = self.link.callback(|_| Msg::Export);
+        let onfile = self.link.callback(Msg::File);
+        let localizer = self.localizer();
+        html! {
+            <div id="backup">
+                <h2 class="caption">{localizer.tr("backup-title")}</h2>
+                <p class="backup_hint">{localizer.tr("backup-hint")}</p>
+                <button class="pet_profile_add_button" onclick={onexport}>
+                    {localizer.tr("backup-export")}
+                </button>
+                <label class="pet_profile_field">
+                    {localizer.tr("backup-import")}
+                    <input type="file" accept="application/json,.json" onchange={onfile} />
+                </label>
+                { self.view_status() }
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Export => {
+                self.export();
+                false
+            }
+            Msg::File(ChangeData::Files(files)) => {
+                if let Some(file) = files.get(0) {
+                    let link = self.link.clone();
+                    spawn_local(async move {
+                        link.send_message(Msg::Read(read_text(file).await));
+                    });
+                }
+                false
+            }
+            Msg::File(_) => false,
+            Msg::Read(Some(json)) => {
+                self.status = Some(match Backup::from_json(&json) {
+                    Ok(backup) => Status::Pending(Box::new(backup)),
+                    Err(e) => {
+                        crate::log!(&format!("Unable to restore backup: {}", e));
+                        Status::Failed(e)
+                    }
+                });
+                true
+            }
+            Msg::Read(None) => {
+                self.status = Some(Status::Unreadable);
+                true
+            }
+            Msg::Apply(mode) => {
+                if let Some(Status::Pending(backup)) = self.status.take() {
+                    let import = backup.apply(&self.props.session, mode);
+                    if let Some(onimport) = self.props.onimport.as_ref() {
+                        onimport.emit(import.session);
+                    }
+                    self.status = Some(Status::Imported(mode, import.kept));
+                }
+                true
+            }
+            Msg::Cancel => {
+                self.status = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
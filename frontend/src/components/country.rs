@@ -2,12 +2,13 @@ use wasm_bindgen::JsCast;
 use yew::{
     html,
     web_sys::{SvgElement, SvgPathElement},
-    Callback, Component, ComponentLink, Html, MouseEvent, Properties, ShouldRender,
+    Callback, Component, ComponentLink, FocusEvent, Html, MouseEvent, Properties, ShouldRender,
 };
 
 pub enum Msg {
     Click(MouseEvent),
     Highlight(MouseEvent),
+    Focus,
 }
 
 #[derive(PartialEq, Clone, Properties)]
@@ -19,6 +20,26 @@ pub struct Props {
     pub translate_y: i32,
     pub onclick: Option<Callback<String>>,
     pub onhover: Option<Callback<SvgElement>>,
+    #[prop_or_default]
+    pub prohibited: bool,
+    /// Whether the country meets the filters, `None` while no filter is set
+    #[prop_or_default]
+    pub matched: Option<bool>,
+    /// The requirements changed since the day picked on the map
+    #[prop_or_default]
+    pub changed: bool,
+    /// Reachable with the keyboard, the map passes this to the countries
+    /// it shows but not e.g. the outline in the country view
+    #[prop_or_default]
+    pub focusable: bool,
+    /// The one country of the map in the tab order
+    #[prop_or_default]
+    pub focused: bool,
+    /// Accessible name, may say more than `name`
+    #[prop_or_default]
+    pub label: String,
+    #[prop_or_default]
+    pub onfocus: Option<Callback<String>>,
 }
 
 pub struct CountryComponent {
@@ -37,14 +58,37 @@ impl Component for CountryComponent {
     fn view(&self) -> Html {
         let onmouseenter = self.link.callback(|e: MouseEvent| Msg::Highlight(e));
         let onclick = self.link.callback(|e: MouseEvent| Msg::Click(e));
+        let onfocus = self.link.callback(|_: FocusEvent| Msg::Focus);
         let transform = format!(
             "translate({}, {})",
             self.props.translate_x.clone(),
             self.props.translate_y.clone()
         );
+        let mut class = "country".to_string();
+        if self.props.prohibited {
+            class.push_str(" country_prohibited");
+        }
+        if self.props.changed {
+            class.push_str(" country_changed");
+        }
+        match self.props.matched {
+            Some(true) => class.push_str(" country_matched"),
+            Some(false) => class.push_str(" country_dimmed"),
+            None => {}
+        }
+        if !self.props.focusable {
+            return html! {
+                <path class={class} id={self.props.id.clone()} name={self.props.name.clone()} d={self.props.path.clone()}
+                      onmouseenter={onmouseenter} onclick={onclick} transform={transform}>
+                </path>
+            };
+        }
+        // roving tabindex: the map is a single tab stop, arrow keys move within
+        let tabindex = if self.props.focused { "0" } else { "-1" };
         html! {
-            <path class="country" id={self.props.id.clone()} name={self.props.name.clone()} d={self.props.path.clone()}
-                  onmouseenter={onmouseenter} onclick={onclick} transform={transform}>
+            <path class={class} id={self.props.id.clone()} name={self.props.name.clone()} d={self.props.path.clone()}
+                  role="button" aria-label={self.props.label.clone()} tabindex={tabindex}
+                  onmouseenter={onmouseenter} onclick={onclick} onfocus={onfocus} transform={transform}>
             </path>
         }
     }
@@ -59,8 +103,8 @@ impl Component for CountryComponent {
                     .expect("Unable to convert target country element to SvgElement")
                     .id()
                     .to_string();
-                if self.props.onclick != None {
-                    self.props.onclick.as_ref().unwrap().emit(country_id);
+                if let Some(onclick) = self.props.onclick.as_ref() {
+                    onclick.emit(country_id);
                 }
                 false
             }
@@ -70,11 +114,17 @@ impl Component for CountryComponent {
                     .expect("Unable to get EventTarget in CountryComponent update")
                     .dyn_into::<SvgElement>()
                     .expect("Unable to convert target country SVG to SvgElement");
-                if self.props.onhover != None {
-                    self.props.onhover.as_ref().unwrap().emit(target);
+                if let Some(onhover) = self.props.onhover.as_ref() {
+                    onhover.emit(target);
                 }
                 true
             }
+            Msg::Focus => {
+                if let Some(onfocus) = self.props.onfocus.as_ref() {
+                    onfocus.emit(self.props.id.clone());
+                }
+                false
+            }
         }
     }
 
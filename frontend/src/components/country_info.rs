@@ -1,52 +1,539 @@
-use wasm_bindgen::JsCast;
-use yew::{
-    html,
-    utils::document,
-    web_sys::HtmlDivElement,
-    Component, ComponentLink, Html, Properties, ShouldRender,
-};
+use super::explanation::ExplanationComponent;
+use super::icon::icon;
 use super::info_block::InfoBlockComponent;
+use crate::client::data_client;
+use crate::utils::export::{download, open_page};
+use chrono::{Local, NaiveDate};
+use petabroad_core::api::RequirementsResponse;
+use petabroad_core::client::{FetchError, Fetched, Origin};
+use petabroad_core::compliance::{microchip_findings, titer_findings, Finding, Status};
+use petabroad_core::data::airlines::{get_airline_database, RouteQuery};
+use petabroad_core::data::breeds::{get_breed_registry, BreedRestriction, RestrictionLevel};
+use petabroad_core::data::certificates::{find_template, templates_for};
+use petabroad_core::data::requirements::{
+    CountryRequirements, MicrochipRequirement, TiterRequirement,
+};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Arg, Locale, Message};
+use petabroad_core::pet::owner::Owner;
+use petabroad_core::pet::profile::PetProfile;
+use petabroad_core::rules::condition::Facts;
+use petabroad_core::rules::{get_rule_book, Action, Outcome, Step};
+use petabroad_core::travel::certificate::Certificate;
+use petabroad_core::travel::checklist::Checklist;
+use petabroad_core::travel::trip::Trip;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Callback, Children, Component, ComponentLink, Html, Properties, ShouldRender};
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct Props {
     pub id: String,
     pub name: String,
+    pub profile: PetProfile,
+    /// Printed on the pre-filled certificates
+    #[prop_or_default]
+    pub owner: Owner,
+    pub trip: Trip,
+    pub locale: Locale,
+    /// Extra blocks the enclosing view decides to show, e.g. calculators
+    #[prop_or_default]
+    pub children: Children,
+    /// Emits the effective requirements once loaded, `None` when there is no
+    /// data on the country
+    #[prop_or_default]
+    pub onrequirements: Option<Callback<Option<CountryRequirements>>>,
+    /// Shows the requirements in effect on that day rather than the current ones
+    #[prop_or_default]
+    pub as_of: Option<NaiveDate>,
+}
+
+type RequirementsResult = Result<Fetched<RequirementsResponse>, FetchError>;
+
+pub enum Msg {
+    /// Response for a country on a day, `None` for today
+    Requirements(String, Option<NaiveDate>, Box<RequirementsResult>),
+    PrintChecklist,
+    DownloadChecklist,
+    /// Template id of the certificate to print
+    PrintCertificate(String),
 }
 
 pub struct CountryInfoComponent {
     props: Props,
     link: ComponentLink<Self>,
+    /// `None` while loading
+    requirements: Option<RequirementsResult>,
+}
+
+fn view_finding(localizer: &Localizer, finding: &Finding) -> Html {
+    let (class, icon_name) = match finding.status {
+        Status::Satisfied => ("country_info_finding", "check"),
+        Status::Pending => ("country_info_finding country_info_finding_pending", "clock"),
+        Status::Violated => (
+            "country_info_finding country_info_finding_violated",
+            "close",
+        ),
+    };
+    html! {
+        <li class={class}>
+            { icon(icon_name) }
+            {localizer.format(&finding.message)}
+        </li>
+    }
+}
+
+impl CountryInfoComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn view_findings(&self, findings: &[Finding]) -> Html {
+        html! {
+            <ul class="country_info_findings">
+                { for findings.iter().map(|f| view_finding(self.localizer(), f)) }
+            </ul>
+        }
+    }
+
+    fn view_breed_restriction(&self, restriction: &BreedRestriction) -> Html {
+        let (class, icon_name, title) = if restriction.is_blocking() {
+            (
+                "country_info_warning country_info_warning_blocking",
+                "ban",
+                Message::new("breed-blocked")
+                    .arg("breed", self.props.profile.breed.as_str())
+                    .arg("country", self.props.name.as_str()),
+            )
+        } else {
+            let id = match restriction.level {
+                RestrictionLevel::Banned => "breed-banned",
+                RestrictionLevel::Restricted => "breed-restricted",
+            };
+            let place = restriction
+                .region
+                .clone()
+                .unwrap_or_else(|| self.props.name.clone());
+            (
+                "country_info_warning",
+                "warning",
+                Message::new(id)
+                    .arg("breed", self.props.profile.breed.as_str())
+                    .arg("place", place),
+            )
+        };
+        html! {
+            <div class={class}>
+                { icon(icon_name) }
+                <div class="country_info_warning_text">
+                    <strong>{self.localizer().format(&title)}</strong>
+                    <p>{restriction.note.clone()}</p>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_breed_restrictions(&self) -> Html {
+        let restrictions =
+            get_breed_registry().restrictions_for(&self.props.id, &self.props.profile);
+        html! {
+            { for restrictions.iter().map(|r| self.view_breed_restriction(r)) }
+        }
+    }
+
+    /// Outcome of every rule of the country for the pet and the trip, on the
+    /// day the requirements are shown for
+    fn explain(&self) -> Vec<Step> {
+        let day = match self.props.as_of {
+            Some(date) => date,
+            None => Local::now().date_naive(),
+        };
+        let facts = Facts::new(&self.props.id, &self.props.profile, &self.props.trip, day);
+        get_rule_book().explain(&facts)
+    }
+
+    /// The rule behind a requirement block, if there is one
+    fn explanation(&self, kind: &str) -> Option<Step> {
+        self.explain()
+            .into_iter()
+            .find(|step| step.action.kind() == kind)
+    }
+
+    fn view_step(&self, step: &Step) -> Html {
+        let localizer = self.localizer();
+        let class = match step.action {
+            Action::EntryRefused => "country_info_step country_info_step_refused",
+            _ => "country_info_step",
+        };
+        html! {
+            <li class={class}>
+                {localizer.format(&step.action.message())}
+                {
+                    if step.outcome == Outcome::MayApply {
+                        html! { <span class="country_info_step_uncertain">{localizer.tr("steps-may-apply")}</span> }
+                    } else {
+                        html! {}
+                    }
+                }
+                <ExplanationComponent step={step.clone()} locale={self.props.locale} />
+            </li>
+        }
+    }
+
+    fn view_steps(&self) -> Html {
+        // the rule book has no history, its steps are today's
+        if self.props.as_of.is_some() || !get_rule_book().has_rules(&self.props.id) {
+            return html! {};
+        }
+        let localizer = self.localizer();
+        let (steps, not_required): (Vec<Step>, Vec<Step>) = self
+            .explain()
+            .into_iter()
+            .partition(|step| step.outcome != Outcome::NotMet);
+        html! {
+            <InfoBlockComponent id={format!("{}_steps", self.props.id)}
+                                title={localizer.tr("steps-title")}>
+                <ol class="country_info_steps">
+                    { for steps.iter().map(|step| self.view_step(step)) }
+                </ol>
+                {
+                    if not_required.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <details class="country_info_not_required">
+                                <summary>{localizer.tr("steps-not-required")}</summary>
+                                <ul class="country_info_steps">
+                                    { for not_required.iter().map(|step| self.view_step(step)) }
+                                </ul>
+                            </details>
+                        }
+                    }
+                }
+            </InfoBlockComponent>
+        }
+    }
+
+    fn view_microchip(&self, requirement: &MicrochipRequirement) -> Html {
+        let findings = microchip_findings(requirement, &self.props.profile);
+        let localizer = self.localizer();
+        let description = if requirement.iso_required {
+            localizer.tr("microchip-iso-required")
+        } else {
+            localizer.tr("microchip-iso-expected")
+        };
+        html! {
+            <InfoBlockComponent id={format!("{}_microchip", self.props.id)}
+                                title={localizer.tr("microchip-title")}
+                                explanation={self.explanation("microchip")}
+                                locale={self.props.locale}>
+                <p>
+                    {description}
+                    {
+                        if requirement.before_vaccination {
+                            format!(" {}", localizer.tr("microchip-before-vaccination"))
+                        } else {
+                            String::new()
+                        }
+                    }
+                </p>
+                { self.view_findings(&findings) }
+            </InfoBlockComponent>
+        }
+    }
+
+    fn view_titer(&self, requirement: &TiterRequirement) -> Html {
+        let findings = titer_findings(requirement, &self.props.profile, &self.props.trip);
+        let localizer = self.localizer();
+        let methods: Vec<String> = requirement.methods.iter().map(|m| m.to_string()).collect();
+        let summary = Message::new("titer-summary")
+            .arg("min", requirement.min_iu_per_ml)
+            .arg("methods", localizer.list_or(&methods))
+            .arg("days", requirement.wait_days)
+            .arg("approval", requirement.lab_approval.as_str());
+        html! {
+            <InfoBlockComponent id={format!("{}_titer", self.props.id)}
+                                title={localizer.tr("titer-title")}
+                                explanation={self.explanation("titer-test")}
+                                locale={self.props.locale}>
+                <p>{localizer.format(&summary)}</p>
+                { self.view_findings(&findings) }
+            </InfoBlockComponent>
+        }
+    }
+
+    fn fetch_requirements(&mut self) {
+        self.requirements = None;
+        let id = self.props.id.clone();
+        let as_of = self.props.as_of;
+        let link = self.link.clone();
+        spawn_local(async move {
+            let client = data_client();
+            let result = match as_of {
+                Some(date) => client.requirements_on(&id, date).await,
+                None => client.requirements(&id).await,
+            };
+            link.send_message(Msg::Requirements(id, as_of, Box::new(result)));
+        });
+    }
+
+    fn view_freshness(&self, fetched: &Fetched<RequirementsResponse>) -> Html {
+        if let Some(date) = self.props.as_of {
+            let message = Message::new("history-as-of").arg("date", date);
+            return html! {
+                <div class="country_info_stale">
+                    { icon("clock") }
+                    {self.localizer().format(&message)}
+                </div>
+            };
+        }
+        let message = match (fetched.origin, fetched.fetched_at) {
+            (Origin::Server, _) => return html! {},
+            (Origin::Cache, Some(at)) => {
+                Message::new("requirements-saved").arg("date", at.date_naive())
+            }
+            _ => Message::new("requirements-bundled"),
+        };
+        html! {
+            <div class="country_info_stale">
+                { icon("offline") }
+                {self.localizer().format(&message)}
+            </div>
+        }
+    }
+
+    fn view_requirements(&self) -> Html {
+        let localizer = self.localizer();
+        let fetched = match &self.requirements {
+            None => {
+                return html! {
+                    <div class="country_info_loading">
+                        { icon("spinner") }
+                        {localizer.tr("requirements-loading")}
+                    </div>
+                }
+            }
+            Some(Err(FetchError::NotFound)) => None,
+            Some(Err(e)) => {
+                return html! {
+                    <InfoBlockComponent id={format!("{}_requirements", self.props.id)}
+                                        title={localizer.tr("requirements-title")}>
+                        <p>{localizer.format(&Message::new("requirements-failed").arg("reason", e.to_string()))}</p>
+                    </InfoBlockComponent>
+                }
+            }
+            Some(Ok(fetched)) => Some(fetched),
+        };
+        match fetched.and_then(|f| f.data.requirements.as_ref().map(|r| (f, r))) {
+            Some((fetched, requirements)) => html! {
+                <>
+                    { self.view_freshness(fetched) }
+                    { for requirements.microchip.iter().map(|m| self.view_microchip(m)) }
+                    { for requirements.titer.iter().map(|t| self.view_titer(t)) }
+                </>
+            },
+            None => html! {
+                <InfoBlockComponent id={format!("{}_requirements", self.props.id)}
+                                    title={localizer.tr("requirements-title")}>
+                    <p>{localizer.tr("requirements-missing")}</p>
+                </InfoBlockComponent>
+            },
+        }
+    }
+
+    /// Checklist of what the view shows, `None` until the requirements are loaded
+    fn checklist(&self) -> Option<Checklist> {
+        match &self.requirements {
+            Some(Ok(fetched)) => Some(Checklist::new(
+                &fetched.data,
+                &self.props.profile,
+                &self.props.trip,
+                Local::now().date_naive(),
+            )),
+            _ => None,
+        }
+    }
+
+    fn view_export(&self) -> Html {
+        // past requirements have no steps to check off
+        if self.props.as_of.is_some() || !matches!(self.requirements, Some(Ok(_))) {
+            return html! {};
+        }
+        let onprint = self.link.callback(|_| Msg::PrintChecklist);
+        let ondownload = self.link.callback(|_| Msg::DownloadChecklist);
+        let localizer = self.localizer();
+        html! {
+            <InfoBlockComponent id={format!("{}_checklist", self.props.id)}
+                                title={localizer.tr("checklist-export-title")}>
+                <button class="country_view_trip_button" onclick={onprint}>
+                    {localizer.tr("checklist-export-print")}
+                </button>
+                <button class="country_view_trip_button" onclick={ondownload}>
+                    {localizer.tr("checklist-export-markdown")}
+                </button>
+            </InfoBlockComponent>
+        }
+    }
+
+    /// Official forms needed to enter the country, pre-filled to print
+    fn view_certificates(&self) -> Html {
+        let templates = templates_for(&self.props.id, self.props.trip.origin.as_deref());
+        if self.props.as_of.is_some() || templates.is_empty() {
+            return html! {};
+        }
+        let localizer = self.localizer();
+        let buttons = templates.into_iter().map(|template| {
+            let id = template.id.clone();
+            let onclick = self
+                .link
+                .callback(move |_| Msg::PrintCertificate(id.clone()));
+            html! {
+                <button class="country_view_trip_button" onclick={onclick}>
+                    {template.name.clone()}
+                </button>
+            }
+        });
+        html! {
+            <InfoBlockComponent id={format!("{}_certificates", self.props.id)}
+                                title={localizer.tr("certificate-export-title")}>
+                <p>{localizer.tr("certificate-export-hint")}</p>
+                { for buttons }
+            </InfoBlockComponent>
+        }
+    }
+
+    fn view_carriers(&self) -> Html {
+        let origin = match self.props.trip.origin.as_ref() {
+            Some(origin) if *origin != self.props.id => origin,
+            _ => return html! {},
+        };
+        let localizer = self.localizer();
+        let title = Message::new("carriers-title").arg("country", Arg::Country(origin.clone()));
+        let route = RouteQuery {
+            from: origin,
+            to: &self.props.id,
+            date: self.props.trip.departure,
+        };
+        let options = get_airline_database().carriers_for(&route, &self.props.profile);
+        html! {
+            <div class="country_info_block">
+                <div class="country_info_block_text">
+                    <strong>{localizer.format(&title)}</strong>
+                    {
+                        if options.is_empty() {
+                            html! { <p>{localizer.tr("carriers-none")}</p> }
+                        } else {
+                            html! {
+                                <ul class="country_info_carriers">
+                                {
+                                    for options.iter().map(|option| {
+                                        let modes: Vec<String> = option.modes.iter()
+                                            .map(|m| localizer.tr(&format!("transport-mode-{}", m)))
+                                            .collect();
+                                        html! {
+                                            <li>{format!("{} ({}): {}", option.airline.name, option.airline.iata, modes.join(", "))}</li>
+                                        }
+                                    })
+                                }
+                                </ul>
+                            }
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
 }
 
 impl Component for CountryInfoComponent {
     type Properties = Props;
-    type Message = ();
+    type Message = Msg;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        CountryInfoComponent { props, link }
+        let mut component = CountryInfoComponent {
+            props,
+            link,
+            requirements: None,
+        };
+        component.fetch_requirements();
+        component
     }
 
     fn view(&self) -> Html {
         html! {
             <div id="country_view_desc">
                 <h1 class="caption country_info_caption">{self.props.name.clone()}</h1>
-                <InfoBlockComponent id={self.props.id.clone()} />
-                <InfoBlockComponent id={self.props.id.clone()} />
-                <InfoBlockComponent id={self.props.id.clone()} />
+                { self.view_breed_restrictions() }
+                { self.view_carriers() }
+                { self.view_steps() }
+                { self.view_requirements() }
+                { self.view_export() }
+                { self.view_certificates() }
+                { self.props.children.clone() }
             </div>
         }
     }
 
-    fn rendered(&mut self, first_render: bool) {
-    }
-
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            // a response for a country or a day which is no longer shown
+            Msg::Requirements(id, as_of, _) if id != self.props.id || as_of != self.props.as_of => {
+                false
+            }
+            Msg::Requirements(_, _, result) => {
+                if let Some(onrequirements) = self.props.onrequirements.as_ref() {
+                    let requirements = (*result)
+                        .as_ref()
+                        .ok()
+                        .and_then(|fetched| fetched.data.requirements.clone());
+                    onrequirements.emit(requirements);
+                }
+                self.requirements = Some(*result);
+                true
+            }
+            Msg::PrintChecklist => {
+                if let Some(checklist) = self.checklist() {
+                    if let Err(e) = open_page(&checklist.to_html(self.localizer())) {
+                        crate::log!(&format!("Unable to open checklist: {:?}", e));
+                    }
+                }
+                false
+            }
+            Msg::DownloadChecklist => {
+                if let Some(checklist) = self.checklist() {
+                    let file_name = format!("petabroad-checklist-{}.md", self.props.id);
+                    let markdown = checklist.to_markdown(self.localizer());
+                    if let Err(e) = download(&file_name, "text/markdown;charset=utf-8", &markdown) {
+                        crate::log!(&format!("Unable to save checklist: {:?}", e));
+                    }
+                }
+                false
+            }
+            Msg::PrintCertificate(id) => {
+                if let Some(template) = find_template(&id) {
+                    let certificate = Certificate::fill(
+                        template,
+                        &self.props.id,
+                        &self.props.owner,
+                        &self.props.profile,
+                        &self.props.trip,
+                        Local::now().date_naive(),
+                    );
+                    if let Err(e) = open_page(&certificate.to_html(self.localizer())) {
+                        crate::log!(&format!("Unable to open certificate: {:?}", e));
+                    }
+                }
+                false
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if props != self.props {
+            let refetch = props.id != self.props.id || props.as_of != self.props.as_of;
             self.props = props;
+            if refetch {
+                self.fetch_requirements();
+            }
             true
         } else {
             false
@@ -0,0 +1,206 @@
+use petabroad_core::data::overview::{overview_rows, sort_rows, Column, OverviewRow};
+use petabroad_core::data::requirements::RabiesStatus;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+
+pub enum Msg {
+    Sort(Column),
+    Filter(String),
+    Open(&'static str),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    /// Trip origin, decides whether the titer test applies
+    pub origin: Option<String>,
+    pub locale: Locale,
+    pub oncountryclick: Option<Callback<String>>,
+}
+
+/// Every country with its headline requirements, for those who can not or
+/// would rather not use the map
+pub struct CountryTableComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+    rows: Vec<OverviewRow>,
+    column: Column,
+    ascending: bool,
+    filter: String,
+}
+
+impl CountryTableComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn build_rows(&mut self) {
+        let localizer = self.localizer();
+        self.rows = overview_rows(
+            |id| localizer.country_name(id),
+            self.props.origin.as_deref(),
+        );
+        sort_rows(&mut self.rows, self.column, self.ascending);
+    }
+
+    fn column_title(column: Column) -> &'static str {
+        match column {
+            Column::Country => "table-country",
+            Column::Quarantine => "table-quarantine",
+            Column::Titer => "table-titer",
+            Column::Permit => "table-permit",
+            Column::Rabies => "table-rabies",
+        }
+    }
+
+    fn view_header(&self, column: Column) -> Html {
+        let onclick = self.link.callback(move |_| Msg::Sort(column));
+        let sort = if column != self.column {
+            "none"
+        } else if self.ascending {
+            "ascending"
+        } else {
+            "descending"
+        };
+        let arrow = match sort {
+            "ascending" => "▲",
+            "descending" => "▼",
+            _ => "",
+        };
+        html! {
+            <th scope="col" aria-sort={sort}>
+                <button class="country_table_sort" onclick={onclick}>
+                    {self.localizer().tr(Self::column_title(column))}
+                    <span aria-hidden="true">{arrow}</span>
+                </button>
+            </th>
+        }
+    }
+
+    fn yes_no(&self, value: Option<bool>) -> String {
+        match value {
+            Some(true) => self.localizer().tr("table-yes"),
+            Some(false) => self.localizer().tr("table-no"),
+            None => self.localizer().tr("table-unknown"),
+        }
+    }
+
+    fn view_row(&self, row: &OverviewRow) -> Html {
+        let id = row.id;
+        let onclick = self.link.callback(move |_| Msg::Open(id));
+        let localizer = self.localizer();
+        let quarantine = match row.quarantine_days {
+            Some(0) => localizer.tr("table-quarantine-none"),
+            Some(days) => {
+                localizer.format(&Message::new("table-quarantine-days").arg("days", days))
+            }
+            None => localizer.tr("table-unknown"),
+        };
+        let rabies = match row.rabies_status {
+            Some(RabiesStatus::Free) => localizer.tr("rabies-free"),
+            Some(RabiesStatus::Controlled) => localizer.tr("rabies-controlled"),
+            Some(RabiesStatus::HighIncidence) => localizer.tr("rabies-high-incidence"),
+            None => localizer.tr("table-unknown"),
+        };
+        html! {
+            <tr class="country_table_row" onclick={onclick}>
+                <th scope="row">
+                    // rows are not focusable, the button makes the country reachable by keyboard
+                    <button class="country_table_open" id={format!("country_table_{}", id)}>
+                        {row.name.clone()}
+                    </button>
+                </th>
+                <td>{quarantine}</td>
+                <td>{self.yes_no(row.titer_required)}</td>
+                <td>{self.yes_no(row.import_permit)}</td>
+                <td>{rabies}</td>
+            </tr>
+        }
+    }
+}
+
+impl Component for CountryTableComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut component = CountryTableComponent {
+            props,
+            link,
+            rows: vec![],
+            column: Column::Country,
+            ascending: true,
+            filter: "".to_string(),
+        };
+        component.build_rows();
+        component
+    }
+
+    fn view(&self) -> Html {
+        let onfilter = self.link.callback(|e: InputData| Msg::Filter(e.value));
+        let localizer = self.localizer();
+        let rows: Vec<&OverviewRow> = self
+            .rows
+            .iter()
+            .filter(|row| row.matches(&self.filter))
+            .collect();
+        html! {
+            <div id="country_table">
+                <input type="search" id="country_table_filter" value={self.filter.clone()}
+                       oninput={onfilter} placeholder={localizer.tr("table-filter")}
+                       aria-label={localizer.tr("table-filter")} />
+                <table>
+                    <caption>
+                        {localizer.format(&Message::new("table-caption").arg("count", rows.len()))}
+                    </caption>
+                    <thead>
+                        <tr>
+                            { for Column::ALL.iter().map(|column| self.view_header(*column)) }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for rows.iter().map(|row| self.view_row(row)) }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Sort(column) => {
+                if column == self.column {
+                    self.ascending = !self.ascending;
+                } else {
+                    self.column = column;
+                    self.ascending = true;
+                }
+                sort_rows(&mut self.rows, self.column, self.ascending);
+                true
+            }
+            Msg::Filter(filter) => {
+                self.filter = filter;
+                true
+            }
+            Msg::Open(id) => {
+                if let Some(oncountryclick) = self.props.oncountryclick.as_ref() {
+                    oncountryclick.emit(id.to_string());
+                }
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            let rebuild = props.locale != self.props.locale || props.origin != self.props.origin;
+            self.props = props;
+            if rebuild {
+                self.build_rows();
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -1,19 +1,45 @@
 use super::country::CountryComponent;
 use super::country_info::CountryInfoComponent;
-use crate::utils::viewbox::{Point, ViewBox};
+use super::history::HistoryComponent;
+use super::icon::icon;
+use super::tapeworm::TapewormComponent;
+use chrono::{Local, NaiveDate};
+use petabroad_core::data::freshness::{age_days, Freshness, DEFAULT_STALE_AFTER_DAYS};
+use petabroad_core::data::requirements::{CountryRequirements, Verification};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::pet::owner::Owner;
+use petabroad_core::pet::profile::PetProfile;
+use petabroad_core::travel::tapeworm::treatment_stop;
+use petabroad_core::travel::trip::Trip;
+use petabroad_core::utils::viewbox::{Point, ViewBox};
 use wasm_bindgen::JsCast;
 use yew::{
     html,
     utils::document,
-    web_sys::{HtmlDivElement, SvgGraphicsElement, SvgRect},
-    Component, ComponentLink, Html, Properties, ShouldRender,
+    web_sys::{HtmlDivElement, HtmlElement, SvgGraphicsElement, SvgRect},
+    Callback, Component, ComponentLink, Html, KeyboardEvent, Properties, ShouldRender,
 };
 
 const COUNTRY_VIEW_SCALE: f32 = 0.7;
 
+/// Verification age in days after which a warning is shown, override at
+/// build time with `PETABROAD_STALE_AFTER_DAYS`
+fn stale_after_days() -> u32 {
+    option_env!("PETABROAD_STALE_AFTER_DAYS")
+        .and_then(|days| days.parse().ok())
+        .unwrap_or(DEFAULT_STALE_AFTER_DAYS)
+}
+
 pub enum Msg {
     CloseView,
+    Key(KeyboardEvent),
     OnTransform,
+    SetOrigin,
+    SetDestination,
+    AddStopover,
+    ToggleWatch,
+    Requirements(Option<CountryRequirements>),
 }
 
 #[derive(PartialEq, Clone, Properties)]
@@ -21,6 +47,27 @@ pub struct Props {
     pub id: String,
     pub name: String,
     pub path: String,
+    pub profile: PetProfile,
+    #[prop_or_default]
+    pub owner: Owner,
+    pub trip: Trip,
+    pub locale: Locale,
+    pub onsetorigin: Option<Callback<String>>,
+    pub onsetdestination: Option<Callback<String>>,
+    pub onaddstopover: Option<Callback<String>>,
+    pub ontripchange: Option<Callback<Trip>>,
+    /// Emits the id of the country whose view was closed
+    #[prop_or_default]
+    pub onclose: Option<Callback<String>>,
+    /// Day picked on the map, the view shows the requirements of that day
+    #[prop_or_default]
+    pub as_of: Option<NaiveDate>,
+    /// The country is on the watchlist
+    #[prop_or_default]
+    pub watched: bool,
+    /// Emits the id of the country to add to or remove from the watchlist
+    #[prop_or_default]
+    pub onwatch: Option<Callback<String>>,
 }
 
 pub struct CountryViewComponent {
@@ -30,16 +77,28 @@ pub struct CountryViewComponent {
     country_translate_y: i32,
     transformed: bool,
     view_box: ViewBox,
+    /// Verification of the requirements record, `None` while loading or if
+    /// there is no record
+    verification: Option<Option<Verification>>,
 }
 
 impl CountryViewComponent {
     fn toggle_visibility(&self, visible: bool) {
-        document()
+        if let Some(el) = document()
             .get_element_by_id("country_view")
             .and_then(|t| t.dyn_into::<HtmlDivElement>().ok())
-            .map(|el| {
-                el.set_hidden(!visible);
-            });
+        {
+            el.set_hidden(!visible);
+        }
+    }
+
+    fn focus_close_button(&self) {
+        if let Some(button) = document()
+            .get_element_by_id("country_view_close_button")
+            .and_then(|b| b.dyn_into::<HtmlElement>().ok())
+        {
+            button.focus().expect("Unable to focus close button");
+        }
     }
 
     fn set_viewbox(&mut self, svg_bbox: &SvgRect, country_view: &HtmlDivElement) {
@@ -91,6 +150,59 @@ impl CountryViewComponent {
         self.set_viewbox(&svg_bbox, &country_view);
         self.transformed = true;
     }
+
+    fn view_freshness(&self, localizer: &Localizer) -> Html {
+        // past records are not re-verified
+        if self.props.as_of.is_some() {
+            return html! {};
+        }
+        let verified = match &self.verification {
+            Some(Some(verified)) => verified,
+            Some(None) => {
+                return html! {
+                    <div class="freshness_badge freshness_badge_stale">
+                        { icon("warning") }{" "}{localizer.tr("freshness-unverified")}
+                    </div>
+                }
+            }
+            None => return html! {},
+        };
+        let today = Local::now().date_naive();
+        let badge = Message::new("freshness-verified")
+            .arg("date", verified.date)
+            .arg("verifier", verified.verifier.as_str());
+        if Freshness::of(Some(verified), today, stale_after_days()) == Freshness::Stale {
+            let warning = Message::new("freshness-stale").arg("days", age_days(verified, today));
+            html! {
+                <>
+                    <div class="freshness_badge freshness_badge_stale">
+                        { icon("warning") }{" "}{localizer.format(&badge)}
+                    </div>
+                    <div class="freshness_banner" role="alert">
+                        { icon("warning") }
+                        {localizer.format(&warning)}
+                    </div>
+                </>
+            }
+        } else {
+            html! {
+                <div class="freshness_badge">{ icon("check") }{" "}{localizer.format(&badge)}</div>
+            }
+        }
+    }
+
+    fn view_tapeworm(&self) -> Html {
+        let stop = treatment_stop(&self.props.trip, &self.props.id, self.props.profile.species);
+        match stop {
+            Some(stop) => html! {
+                <TapewormComponent country_id={stop.country} timed={stop.timed}
+                                   trip={self.props.trip.clone()}
+                                   locale={self.props.locale}
+                                   onchange={self.props.ontripchange.clone()} />
+            },
+            None => html! {},
+        }
+    }
 }
 
 impl Component for CountryViewComponent {
@@ -111,26 +223,62 @@ impl Component for CountryViewComponent {
                 zoom_in_limit: 0,
                 zoom_out_limit: 0,
             },
+            verification: None,
         }
     }
 
     fn view(&self) -> Html {
         let onclose = self.link.callback(|_| Msg::CloseView);
+        let onkeydown = self.link.callback(|e: KeyboardEvent| Msg::Key(e));
+        let onsetorigin = self.link.callback(|_| Msg::SetOrigin);
+        let onsetdestination = self.link.callback(|_| Msg::SetDestination);
+        let onaddstopover = self.link.callback(|_| Msg::AddStopover);
+        let onwatch = self.link.callback(|_| Msg::ToggleWatch);
+        let onrequirements = self.link.callback(Msg::Requirements);
+        let localizer = get_localizer(self.props.locale);
         html! {
-            <div id="country_view">
-                <button id="country_view_close_button" onclick={onclose}>
-                    <i class="fa fa-close" />
+            <div id="country_view" role="dialog" aria-label={self.props.name.clone()} onkeydown={onkeydown}>
+                <button id="country_view_close_button" onclick={onclose}
+                        aria-label={localizer.tr("country-view-close")}
+                        title={localizer.tr("country-view-close")}>
+                    { icon("close") }
                 </button>
+                <div id="country_view_trip_buttons">
+                    <button class="country_view_trip_button" onclick={onsetorigin}>
+                        { icon("plane") }{" "}{localizer.tr("country-fly-from")}
+                    </button>
+                    <button class="country_view_trip_button" onclick={onsetdestination}>
+                        { icon("marker") }{" "}{localizer.tr("country-fly-to")}
+                    </button>
+                    <button class="country_view_trip_button" onclick={onaddstopover}>
+                        { icon("stopover") }{" "}{localizer.tr("country-stop-over")}
+                    </button>
+                    <button class="country_view_trip_button" onclick={onwatch}
+                            aria-pressed={self.props.watched.to_string()}>
+                        { icon("bell") }{" "}{localizer.tr("country-watch")}
+                    </button>
+                </div>
+                { self.view_freshness(localizer) }
                 <div id="country_view_country">
                     <svg viewBox={self.view_box.to_string()} version="1.2"
                          xmlns="http://www.w3.org/2000/svg" id="country_view_country_svg">
                          <CountryComponent id="country_view_country_path" name={self.props.name.clone()}
                                            path={self.props.path.clone()}
-                                           translate_x={self.country_translate_x.clone()}
-                                           translate_y={self.country_translate_y.clone()} />
+                                           translate_x={self.country_translate_x}
+                                           translate_y={self.country_translate_y} />
                     </svg>
                 </div>
-                <CountryInfoComponent id={self.props.id.clone()} name={self.props.name.clone()} />
+                <CountryInfoComponent id={self.props.id.clone()} name={self.props.name.clone()}
+                                      profile={self.props.profile.clone()}
+                                      owner={self.props.owner.clone()}
+                                      trip={self.props.trip.clone()}
+                                      locale={self.props.locale}
+                                      as_of={self.props.as_of}
+                                      onrequirements={onrequirements}>
+                    { self.view_tapeworm() }
+                    <HistoryComponent id={self.props.id.clone()} locale={self.props.locale}
+                                      as_of={self.props.as_of} />
+                </CountryInfoComponent>
             </div>
         }
     }
@@ -140,6 +288,7 @@ impl Component for CountryViewComponent {
             self.toggle_visibility(false);
         } else if !self.transformed {
             self.transform();
+            self.focus_close_button();
             self.link.send_message(Msg::OnTransform);
         }
     }
@@ -148,9 +297,45 @@ impl Component for CountryViewComponent {
         match msg {
             Msg::CloseView => {
                 self.toggle_visibility(false);
+                if let Some(onclose) = self.props.onclose.as_ref() {
+                    onclose.emit(self.props.id.clone());
+                }
+                false
+            }
+            Msg::Key(e) => {
+                if e.key() == "Escape" {
+                    e.prevent_default();
+                    self.link.send_message(Msg::CloseView);
+                }
+                false
+            }
+            Msg::OnTransform => true,
+            Msg::SetOrigin => {
+                if let Some(onsetorigin) = self.props.onsetorigin.as_ref() {
+                    onsetorigin.emit(self.props.id.clone());
+                }
                 false
             }
-            Msg::OnTransform => {
+            Msg::SetDestination => {
+                if let Some(onsetdestination) = self.props.onsetdestination.as_ref() {
+                    onsetdestination.emit(self.props.id.clone());
+                }
+                false
+            }
+            Msg::AddStopover => {
+                if let Some(onaddstopover) = self.props.onaddstopover.as_ref() {
+                    onaddstopover.emit(self.props.id.clone());
+                }
+                false
+            }
+            Msg::ToggleWatch => {
+                if let Some(onwatch) = self.props.onwatch.as_ref() {
+                    onwatch.emit(self.props.id.clone());
+                }
+                false
+            }
+            Msg::Requirements(requirements) => {
+                self.verification = requirements.map(|r| r.verified);
                 true
             }
         }
@@ -158,9 +343,14 @@ impl Component for CountryViewComponent {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if props != self.props {
+            // profile edits re-render the open view but must not pop it up,
+            // neither does clearing the id once the view is closed
+            if props.id != self.props.id && !props.id.is_empty() {
+                self.toggle_visibility(true);
+                self.transformed = false;
+                self.verification = None;
+            }
             self.props = props;
-            self.toggle_visibility(true);
-            self.transformed = false;
             true
         } else {
             false
@@ -0,0 +1,178 @@
+use petabroad_core::data::airlines::get_airline_database;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::pet::crate_size::{
+    airline_modes_for_crate, minimum_internal_dimensions, recommended_crate, Dimensions,
+    PetMeasurements,
+};
+use petabroad_core::pet::profile::PetProfile;
+use petabroad_core::travel::trip::Trip;
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+
+pub enum Msg {
+    Length(String),
+    ElbowHeight(String),
+    Width(String),
+    Height(String),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub profile: PetProfile,
+    pub trip: Trip,
+    pub locale: Locale,
+    pub onchange: Option<Callback<PetProfile>>,
+}
+
+pub struct CrateCalculatorComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+fn dimensions_message(d: &Dimensions) -> Message {
+    Message::new("crate-dimensions")
+        .arg("length", format!("{:.0}", d.length_cm))
+        .arg("width", format!("{:.0}", d.width_cm))
+        .arg("height", format!("{:.0}", d.height_cm))
+}
+
+fn format_measurement(value: f32) -> String {
+    if value > 0.0 {
+        value.to_string()
+    } else {
+        "".to_string()
+    }
+}
+
+impl CrateCalculatorComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn view_field(&self, label_id: &str, value: f32, oninput: Callback<InputData>) -> Html {
+        html! {
+            <label class="pet_profile_field">
+                {self.localizer().tr(label_id)}
+                <input type="number" min="0" step="0.5" value={format_measurement(value)} oninput={oninput} />
+            </label>
+        }
+    }
+
+    fn view_airline_check(&self, measurements: &PetMeasurements) -> Html {
+        let series = match recommended_crate(measurements) {
+            Some(series) => series,
+            None => return html! {},
+        };
+        let airline = match self
+            .props
+            .trip
+            .airline
+            .as_ref()
+            .and_then(|iata| get_airline_database().find(iata))
+        {
+            Some(airline) => airline,
+            None => return html! {},
+        };
+        let localizer = self.localizer();
+        let modes = airline_modes_for_crate(series, airline);
+        if modes.is_empty() {
+            let message = Message::new("crate-airline-rejects")
+                .arg("airline", airline.name.as_str())
+                .arg("series", series.series);
+            html! {
+                <p class="crate_calculator_warning">{localizer.format(&message)}</p>
+            }
+        } else {
+            let modes: Vec<String> = modes
+                .iter()
+                .map(|m| localizer.tr(&format!("transport-mode-{}", m)))
+                .collect();
+            let message = Message::new("crate-airline-accepts")
+                .arg("airline", airline.name.as_str())
+                .arg("modes", modes.join(", "));
+            html! {
+                <p>{localizer.format(&message)}</p>
+            }
+        }
+    }
+
+    fn view_result(&self) -> Html {
+        let measurements = self.props.profile.measurements.unwrap_or_default();
+        if measurements.length_cm <= 0.0 || measurements.height_cm <= 0.0 {
+            return html! {};
+        }
+        let localizer = self.localizer();
+        let minimum = minimum_internal_dimensions(&measurements);
+        html! {
+            <div class="crate_calculator_result">
+                <p>{localizer.format(&Message::new("crate-minimum").arg("size", dimensions_message(&minimum)))}</p>
+                {
+                    match recommended_crate(&measurements) {
+                        Some(series) => {
+                            let message = Message::new("crate-series")
+                                .arg("series", series.series)
+                                .arg("size", dimensions_message(&series.external));
+                            html! { <p>{localizer.format(&message)}</p> }
+                        }
+                        None => html! {
+                            <p class="crate_calculator_warning">{localizer.tr("crate-custom")}</p>
+                        },
+                    }
+                }
+                { self.view_airline_check(&measurements) }
+            </div>
+        }
+    }
+}
+
+impl Component for CrateCalculatorComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        CrateCalculatorComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let m = self.props.profile.measurements.unwrap_or_default();
+        html! {
+            <div id="crate_calculator">
+                <h2 class="caption">{self.localizer().tr("crate-title")}</h2>
+                { self.view_field("crate-length", m.length_cm,
+                                  self.link.callback(|e: InputData| Msg::Length(e.value))) }
+                { self.view_field("crate-elbow-height", m.elbow_height_cm,
+                                  self.link.callback(|e: InputData| Msg::ElbowHeight(e.value))) }
+                { self.view_field("crate-width", m.width_cm,
+                                  self.link.callback(|e: InputData| Msg::Width(e.value))) }
+                { self.view_field("crate-height", m.height_cm,
+                                  self.link.callback(|e: InputData| Msg::Height(e.value))) }
+                { self.view_result() }
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut profile = self.props.profile.clone();
+        let mut m = profile.measurements.unwrap_or_default();
+        match msg {
+            Msg::Length(v) => m.length_cm = v.parse().unwrap_or_default(),
+            Msg::ElbowHeight(v) => m.elbow_height_cm = v.parse().unwrap_or_default(),
+            Msg::Width(v) => m.width_cm = v.parse().unwrap_or_default(),
+            Msg::Height(v) => m.height_cm = v.parse().unwrap_or_default(),
+        }
+        profile.measurements = Some(m);
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(profile);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
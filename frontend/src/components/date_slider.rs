@@ -0,0 +1,96 @@
+use super::icon::icon;
+use chrono::{Duration, NaiveDate};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::Locale;
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+
+pub enum Msg {
+    Slide(String),
+    Today,
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    /// Picked day, `None` for today
+    pub date: Option<NaiveDate>,
+    /// Oldest day the slider goes back to
+    pub first: NaiveDate,
+    pub today: NaiveDate,
+    pub locale: Locale,
+    /// Emits `None` when moved back to today
+    #[prop_or_default]
+    pub onchange: Option<Callback<Option<NaiveDate>>>,
+}
+
+/// Picks the day the map shows the rules of, one step per day
+pub struct DateSliderComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl DateSliderComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn emit(&self, date: Option<NaiveDate>) {
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(date);
+        }
+    }
+}
+
+impl Component for DateSliderComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        DateSliderComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let oninput = self.link.callback(|e: InputData| Msg::Slide(e.value));
+        let ontoday = self.link.callback(|_| Msg::Today);
+        let localizer = self.localizer();
+        let date = self.props.date.unwrap_or(self.props.today);
+        let days = (self.props.today - self.props.first).num_days();
+        let offset = (date - self.props.first).num_days();
+        html! {
+            <div id="date_slider">
+                <label for="date_slider_input">{ icon("clock") }{" "}{localizer.tr("map-date")}</label>
+                <input type="range" id="date_slider_input" min="0" max={days.to_string()} step="1"
+                       value={offset.to_string()} aria-valuetext={localizer.date(date)}
+                       oninput={oninput} />
+                <span id="date_slider_value">{localizer.date(date)}</span>
+                <button id="date_slider_today" onclick={ontoday} disabled={self.props.date.is_none()}>
+                    {localizer.tr("map-date-today")}
+                </button>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Slide(value) => {
+                if let Ok(offset) = value.parse::<i64>() {
+                    let date = self.props.first + Duration::days(offset);
+                    self.emit((date < self.props.today).then_some(date));
+                }
+                false
+            }
+            Msg::Today => {
+                self.emit(None);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
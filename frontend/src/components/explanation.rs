@@ -0,0 +1,121 @@
+use super::icon::icon;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::rules::condition::{Trace, Truth};
+use petabroad_core::rules::{Outcome, Step};
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub step: Step,
+    pub locale: Locale,
+}
+
+/// "Why?" disclosure under a requirement: the conditions of the rule behind
+/// it as evaluated for the pet, the regulation and when it was last verified
+pub struct ExplanationComponent {
+    props: Props,
+}
+
+fn view_trace(localizer: &Localizer, trace: &Trace) -> Html {
+    let (class, icon_name, state) = match trace.truth {
+        Truth::True => ("explanation_condition", "check", "explanation-met"),
+        Truth::False => (
+            "explanation_condition explanation_condition_failed",
+            "close",
+            "explanation-failed",
+        ),
+        Truth::Unknown => (
+            "explanation_condition explanation_condition_unknown",
+            "clock",
+            "explanation-unknown",
+        ),
+    };
+    html! {
+        <li class={class}>
+            { icon(icon_name) }
+            {localizer.format(&trace.message)}
+            <span class="visually_hidden">{format!(" ({})", localizer.tr(state))}</span>
+            {
+                if trace.children.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <ul class="explanation_conditions">
+                            { for trace.children.iter().map(|child| view_trace(localizer, child)) }
+                        </ul>
+                    }
+                }
+            }
+        </li>
+    }
+}
+
+impl ExplanationComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn view_source(&self) -> Html {
+        let localizer = self.localizer();
+        match self.props.step.source.as_ref() {
+            Some(source) => html! {
+                <>
+                    <p class="explanation_source">
+                        {localizer.tr("explanation-source")}{" "}
+                        <a href={source.url.clone()} target="_blank" rel="noopener">
+                            {source.reference.clone()}
+                        </a>
+                    </p>
+                    <p class="explanation_verified">
+                        {localizer.format(&Message::new("explanation-verified").arg("date", source.verified))}
+                    </p>
+                </>
+            },
+            None => {
+                html! { <p class="explanation_source">{localizer.tr("explanation-no-source")}</p> }
+            }
+        }
+    }
+}
+
+impl Component for ExplanationComponent {
+    type Properties = Props;
+    type Message = ();
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        ExplanationComponent { props }
+    }
+
+    fn view(&self) -> Html {
+        let localizer = self.localizer();
+        let outcome = match self.props.step.outcome {
+            Outcome::Applies => "explanation-applies",
+            Outcome::MayApply => "explanation-may-apply",
+            Outcome::NotMet => "explanation-not-met",
+        };
+        html! {
+            <details class="explanation">
+                <summary>{localizer.tr("explanation-toggle")}</summary>
+                <p>{localizer.tr(outcome)}</p>
+                <ul class="explanation_conditions">
+                    { view_trace(localizer, &self.props.step.trace) }
+                </ul>
+                { self.view_source() }
+            </details>
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
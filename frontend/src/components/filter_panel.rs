@@ -0,0 +1,114 @@
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::travel::destination_filter::Criterion;
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+
+pub enum Msg {
+    Toggle(Criterion),
+    Clear,
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub criteria: Vec<Criterion>,
+    /// Countries meeting all criteria, `None` while none is selected
+    pub matches: Option<usize>,
+    /// Whether the trip origin is known, some criteria need it
+    pub has_origin: bool,
+    pub locale: Locale,
+    pub onchange: Option<Callback<Vec<Criterion>>>,
+}
+
+pub struct FilterPanelComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl FilterPanelComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn emit(&self, criteria: Vec<Criterion>) {
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(criteria);
+        }
+    }
+
+    fn view_criterion(&self, criterion: Criterion) -> Html {
+        let onclick = self.link.callback(move |_| Msg::Toggle(criterion));
+        let hint = if criterion.needs_origin() && !self.props.has_origin {
+            html! { <span class="filter_panel_hint">{self.localizer().tr("filter-needs-origin")}</span> }
+        } else {
+            html! {}
+        };
+        html! {
+            <label class="filter_panel_criterion">
+                <input type="checkbox" checked={self.props.criteria.contains(&criterion)}
+                       onclick={onclick} />
+                {self.localizer().tr(&format!("filter-{}", criterion.id()))}
+                {hint}
+            </label>
+        }
+    }
+}
+
+impl Component for FilterPanelComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        FilterPanelComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let onclear = self.link.callback(|_| Msg::Clear);
+        let localizer = self.localizer();
+        let result = match self.props.matches {
+            Some(count) => html! {
+                <p class="filter_panel_result">
+                    {localizer.format(&Message::new("filter-matches").arg("count", count))}
+                    {" "}
+                    <button class="trip_clear_button" onclick={onclear}>{localizer.tr("filter-clear")}</button>
+                </p>
+            },
+            None => html! {},
+        };
+        html! {
+            <div id="filter_panel">
+                <h2 class="caption">{localizer.tr("filter-title")}</h2>
+                { for Criterion::ALL.iter().map(|criterion| self.view_criterion(*criterion)) }
+                {result}
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle(criterion) => {
+                let mut criteria = self.props.criteria.clone();
+                match criteria.iter().position(|c| *c == criterion) {
+                    Some(i) => {
+                        criteria.remove(i);
+                    }
+                    None => criteria.push(criterion),
+                }
+                self.emit(criteria);
+                false
+            }
+            Msg::Clear => {
+                self.emit(vec![]);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
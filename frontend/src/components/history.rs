@@ -0,0 +1,216 @@
+use super::icon::icon;
+use super::info_block::InfoBlockComponent;
+use crate::client::data_client;
+use chrono::NaiveDate;
+use petabroad_core::api::HistoryResponse;
+use petabroad_core::client::{FetchError, Fetched};
+use petabroad_core::data::history::{changes_since, FieldChange, Revision};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use serde_json::Value;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub id: String,
+    pub locale: Locale,
+    /// Day picked on the map, the changes since are summed up first
+    #[prop_or_default]
+    pub as_of: Option<NaiveDate>,
+}
+
+/// Requirement value as read by the user, e.g. country names rather than ids
+fn change_value(localizer: &Localizer, field: &str, value: &Value) -> String {
+    match value {
+        Value::Null => localizer.tr("history-not-set"),
+        Value::Bool(true) => localizer.tr("history-yes"),
+        Value::Bool(false) => localizer.tr("history-no"),
+        Value::Array(items) if items.is_empty() => localizer.tr("history-not-set"),
+        Value::Array(items) => {
+            let items: Vec<String> = items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .map(|item| match field {
+                    "titer.exempt_origins" => localizer.country_name(item),
+                    _ => item.to_uppercase(),
+                })
+                .collect();
+            items.join(", ")
+        }
+        Value::String(status) if field == "rabies_status" => {
+            localizer.tr(&format!("rabies-{}", status.replace('_', "-")))
+        }
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Changed requirements as a table of their values before and after
+pub fn view_changes(localizer: &Localizer, changes: &[FieldChange]) -> Html {
+    html! {
+        <table class="history_changes">
+            <thead>
+                <tr>
+                    <th scope="col">{localizer.tr("history-field")}</th>
+                    <th scope="col">{localizer.tr("history-before")}</th>
+                    <th scope="col">{localizer.tr("history-after")}</th>
+                </tr>
+            </thead>
+            <tbody>
+            {
+                for changes.iter().map(|change| html! {
+                    <tr>
+                        <th scope="row">
+                            {localizer.tr(&format!("history-field-{}", change.field.replace('.', "-")))}
+                        </th>
+                        <td class="history_old">{change_value(localizer, &change.field, &change.old)}</td>
+                        <td class="history_new">{change_value(localizer, &change.field, &change.new)}</td>
+                    </tr>
+                })
+            }
+            </tbody>
+        </table>
+    }
+}
+
+type HistoryResult = Result<Fetched<HistoryResponse>, FetchError>;
+
+pub enum Msg {
+    History(String, HistoryResult),
+}
+
+/// Dated changes of a country's requirements, each as a before and after table
+pub struct HistoryComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+    /// `None` while loading
+    history: Option<HistoryResult>,
+}
+
+impl HistoryComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn fetch_history(&mut self) {
+        self.history = None;
+        let id = self.props.id.clone();
+        let link = self.link.clone();
+        spawn_local(async move {
+            let result = data_client().history(&id).await;
+            link.send_message(Msg::History(id, result));
+        });
+    }
+
+    fn view_revision(&self, revision: &Revision) -> Html {
+        let title = Message::new("history-effective").arg("date", revision.effective);
+        let later = self
+            .props
+            .as_of
+            .is_some_and(|date| revision.effective > date);
+        let class = if later {
+            "history_revision history_revision_later"
+        } else {
+            "history_revision"
+        };
+        html! {
+            <li class={class}>
+                <strong>{self.localizer().format(&title)}</strong>
+                { for revision.summaries.iter().map(|summary| html! { <p>{summary.clone()}</p> }) }
+                { view_changes(self.localizer(), &revision.changes) }
+            </li>
+        }
+    }
+
+    /// Net changes between the day picked on the map and today
+    fn view_since(&self, revisions: &[Revision]) -> Html {
+        let date = match self.props.as_of {
+            Some(date) => date,
+            None => return html! {},
+        };
+        let changes = changes_since(revisions, date);
+        if changes.is_empty() {
+            let message = Message::new("history-unchanged-since").arg("date", date);
+            return html! { <p>{self.localizer().format(&message)}</p> };
+        }
+        let title = Message::new("history-since").arg("date", date);
+        html! {
+            <div class="history_since">
+                <strong>{self.localizer().format(&title)}</strong>
+                { view_changes(self.localizer(), &changes) }
+            </div>
+        }
+    }
+}
+
+impl Component for HistoryComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut component = HistoryComponent {
+            props,
+            link,
+            history: None,
+        };
+        component.fetch_history();
+        component
+    }
+
+    fn view(&self) -> Html {
+        let localizer = self.localizer();
+        let content = match &self.history {
+            None => html! {
+                <p class="country_info_loading">{ icon("spinner") }{localizer.tr("history-loading")}</p>
+            },
+            Some(Err(e)) => html! {
+                <p>{localizer.format(&Message::new("history-failed").arg("reason", e.to_string()))}</p>
+            },
+            Some(Ok(fetched)) if fetched.data.revisions.is_empty() => html! {
+                <p>{localizer.tr("history-none")}</p>
+            },
+            Some(Ok(fetched)) => html! {
+                <>
+                    { self.view_since(&fetched.data.revisions) }
+                    <details class="history" open={self.props.as_of.is_some()}>
+                        <summary>{localizer.tr("history-show")}</summary>
+                        <ol class="history_revisions">
+                            { for fetched.data.revisions.iter().map(|r| self.view_revision(r)) }
+                        </ol>
+                    </details>
+                </>
+            },
+        };
+        html! {
+            <InfoBlockComponent id={format!("{}_history", self.props.id)}
+                                title={localizer.tr("history-title")}>
+                { content }
+            </InfoBlockComponent>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            // a response for a country which is no longer shown
+            Msg::History(id, _) if id != self.props.id => false,
+            Msg::History(_, result) => {
+                self.history = Some(result);
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            let country_changed = props.id != self.props.id;
+            self.props = props;
+            if country_changed {
+                self.fetch_history();
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
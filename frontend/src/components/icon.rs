@@ -0,0 +1,13 @@
+use yew::{html, Html};
+
+/// Sprite copied next to `index.html` by trunk, precached by the service worker
+const ICONS_URL: &str = "icons.svg";
+
+/// Inline reference to a symbol of the bundled icon sprite, sized and coloured by CSS
+pub fn icon(name: &str) -> Html {
+    html! {
+        <svg class={format!("icon icon_{}", name)} aria-hidden="true">
+            <use href={format!("{}#{}", ICONS_URL, name)} />
+        </svg>
+    }
+}
@@ -1,48 +1,53 @@
-use wasm_bindgen::JsCast;
-use yew::{
-    html,
-    utils::document,
-    web_sys::HtmlDivElement,
-    Component, ComponentLink, Html, Properties, ShouldRender,
-};
+use super::explanation::ExplanationComponent;
+use super::icon::icon;
+use petabroad_core::i18n::Locale;
+use petabroad_core::rules::Step;
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct Props {
     pub id: String,
+    pub title: String,
+    #[prop_or_default]
+    pub children: Children,
+    /// Rule outcome behind the requirement, shown as an expandable trace
+    #[prop_or_default]
+    pub explanation: Option<Step>,
+    #[prop_or_default]
+    pub locale: Locale,
 }
 
 pub struct InfoBlockComponent {
     props: Props,
-    link: ComponentLink<Self>,
 }
 
 impl Component for InfoBlockComponent {
     type Properties = Props;
     type Message = ();
 
-    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        InfoBlockComponent { props, link }
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        InfoBlockComponent { props }
     }
 
     fn view(&self) -> Html {
         html! {
             <div class="country_info_block">
                 <div class ="country_info_block_icon">
-                    <img src="http://cdn.onlinewebfonts.com/svg/img_319799.png" class ="country_info_block_icon" />
+                    { icon("info") }
                 </div>
                 <div class="country_info_block_text">
-                {
-                    "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod
-                    tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam."
-                }
+                    <strong>{self.props.title.clone()}</strong>
+                    { self.props.children.clone() }
+                    {
+                        for self.props.explanation.iter().map(|step| html! {
+                            <ExplanationComponent step={step.clone()} locale={self.props.locale} />
+                        })
+                    }
                 </div>
             </div>
         }
     }
 
-    fn rendered(&mut self, first_render: bool) {
-    }
-
     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
         true
     }
@@ -1,17 +1,310 @@
+use super::backup::BackupComponent;
+use super::country_table::CountryTableComponent;
 use super::country_view::CountryViewComponent;
+use super::crate_calculator::CrateCalculatorComponent;
+use super::date_slider::DateSliderComponent;
+use super::filter_panel::FilterPanelComponent;
+use super::icon::icon;
 use super::map::{MapComponent, MAP_ZOOM_MIN};
-use super::map_data::{get_countries_borders, get_countries_names};
-use yew::{html, utils::document, Component, ComponentLink, Html, ShouldRender};
+use super::owner::OwnerComponent;
+use super::pet_profile::PetProfileComponent;
+use super::trip::TripComponent;
+use super::watch_notices::WatchNoticesComponent;
+use super::watchlist::WatchlistComponent;
+use crate::client::data_client;
+use crate::utils::locale::{apply_locale, detect_locale};
+use crate::utils::notify::alert;
+use crate::utils::storage::{load_session, save_session};
+use chrono::{Local, NaiveDate};
+use petabroad_core::data::breeds::get_breed_registry;
+use petabroad_core::data::history::get_history;
+use petabroad_core::data::requirements::get_requirements_database;
+use petabroad_core::data::requirements::CountryRequirements;
+use petabroad_core::i18n::localizer::get_localizer;
+use petabroad_core::i18n::{Arg, Locale, Message};
+use petabroad_core::map_data::get_countries_borders;
+use petabroad_core::pet::owner::Owner;
+use petabroad_core::pet::profile::PetProfile;
+use petabroad_core::session::{Session, Viewport};
+use petabroad_core::travel::destination_filter::{matching_countries, Criterion};
+use petabroad_core::travel::trip::Trip;
+use petabroad_core::travel::watchlist::{Notice, Watchlist};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use yew::services::interval::{IntervalService, IntervalTask};
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{
+    html,
+    utils::document,
+    web_sys::{HtmlElement, SvgElement},
+    ChangeData, Component, ComponentLink, Html, ShouldRender,
+};
+
+/// How often the requirements of watched countries are checked while the app is open
+const WATCHLIST_REFRESH: Duration = Duration::from_secs(60 * 60);
+
+/// The map reports every step of a drag or a zoom, the viewport is saved
+/// once it has not moved for this long
+const VIEWPORT_SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// The map, or the same countries as a table
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ViewMode {
+    Map,
+    List,
+}
 
 pub enum Msg {
     CountryClick(String),
+    CountryViewClosed(String),
+    Viewport(Viewport),
+    SaveViewport,
+    Announce(String),
+    ToggleProfile,
+    ToggleViewMode,
+    ProfileChange(PetProfile),
+    OwnerChange(Owner),
+    TripChange(Trip),
+    CriteriaChange(Vec<Criterion>),
+    SetOrigin(String),
+    SetDestination(String),
+    AddStopover(String),
+    Locale(ChangeData),
+    AsOf(Option<NaiveDate>),
+    WatchlistChange(Watchlist),
+    ToggleWatch(String),
+    RefreshWatchlist,
+    WatchlistFetched(Vec<(String, Option<CountryRequirements>)>),
+    DismissNotice(String),
+    /// State restored from a backup file
+    Import(Box<Session>),
 }
 
 pub struct MainWindowComponent {
     link: ComponentLink<Self>,
     country_view_id: String,
-    country_view_name: String,
     country_view_path: String,
+    profile: PetProfile,
+    owner: Owner,
+    profile_visible: bool,
+    prohibited: Vec<String>,
+    trip: Trip,
+    locale: Locale,
+    /// Content of the live region read out by screen readers
+    announcement: String,
+    view_mode: ViewMode,
+    criteria: Vec<Criterion>,
+    /// Countries meeting `criteria`, `None` without any
+    highlighted: Option<Vec<String>>,
+    viewport: Option<Viewport>,
+    /// Picked in the language switcher rather than detected
+    locale_chosen: bool,
+    /// Country whose view was open when the session was saved, opened once
+    /// the map is rendered
+    restore_country: Option<String>,
+    /// Day picked with the date slider, `None` for today
+    as_of: Option<NaiveDate>,
+    /// Countries whose requirements changed since `as_of`
+    changed: Vec<String>,
+    watchlist: Watchlist,
+    /// Changes of watched countries the user has not dismissed yet
+    notices: Vec<Notice>,
+    _watchlist_refresh: IntervalTask,
+    /// Pending save of a moved viewport, restarted by every move
+    viewport_save: Option<TimeoutTask>,
+}
+
+impl Msg {
+    /// Whether the message changes state kept in the browser
+    fn changes_session(&self) -> bool {
+        !matches!(
+            self,
+            Msg::Viewport(_)
+                | Msg::Announce(_)
+                | Msg::ToggleProfile
+                | Msg::AsOf(_)
+                | Msg::RefreshWatchlist
+                | Msg::Locale(ChangeData::Value(_) | ChangeData::Files(_))
+        )
+    }
+}
+
+impl MainWindowComponent {
+    fn view_countries(&self) -> Html {
+        let oncountryclick = self.link.callback(|id: String| Msg::CountryClick(id));
+        match self.view_mode {
+            ViewMode::Map => {
+                let onannounce = self.link.callback(Msg::Announce);
+                let onviewportchange = self.link.callback(Msg::Viewport);
+                html! {
+                    <>
+                        <MapComponent oncountryclick={oncountryclick} viewbox_width={MAP_ZOOM_MIN}
+                                      viewbox_height={MAP_ZOOM_MIN} viewport={self.viewport}
+                                      onviewportchange={onviewportchange}
+                                      prohibited={self.prohibited.clone()}
+                                      highlighted={self.highlighted.clone()}
+                                      changed={self.changed.clone()}
+                                      locale={self.locale} onannounce={onannounce} />
+                        { self.view_date_slider() }
+                    </>
+                }
+            }
+            ViewMode::List => html! {
+                <CountryTableComponent origin={self.trip.origin.clone()} locale={self.locale}
+                                       oncountryclick={oncountryclick} />
+            },
+        }
+    }
+
+    fn view_date_slider(&self) -> Html {
+        let first = match get_history().first_date() {
+            Some(first) => first,
+            None => return html! {},
+        };
+        let onchange = self.link.callback(Msg::AsOf);
+        html! {
+            <DateSliderComponent date={self.as_of} first={first} today={Local::now().date_naive()}
+                                 locale={self.locale} onchange={onchange} />
+        }
+    }
+
+    /// Everything that is saved between visits
+    fn session(&self) -> Session {
+        let selected_country = if self.country_view_id.is_empty() {
+            None
+        } else {
+            Some(self.country_view_id.clone())
+        };
+        Session {
+            viewport: self.viewport,
+            selected_country,
+            profile: self.profile.clone(),
+            owner: self.owner.clone(),
+            trip: self.trip.clone(),
+            criteria: self.criteria.clone(),
+            locale: self.locale_chosen.then(|| self.locale.id().to_string()),
+            list_view: self.view_mode == ViewMode::List,
+            watchlist: self.watchlist.clone(),
+        }
+    }
+
+    fn save_session(&self) {
+        save_session(&self.session());
+    }
+
+    /// Takes over an imported session, the open country view is left as it is
+    /// unless the session has one
+    fn restore(&mut self, session: Session) {
+        self.prohibited = get_breed_registry().prohibited_countries(&session.profile);
+        self.profile = session.profile;
+        self.owner = session.owner;
+        self.trip = session.trip;
+        self.criteria = session.criteria;
+        if let Some(locale) = session.locale.as_deref().and_then(Locale::from_id) {
+            self.locale = locale;
+            self.locale_chosen = true;
+            apply_locale(locale);
+        }
+        self.view_mode = if session.list_view {
+            ViewMode::List
+        } else {
+            ViewMode::Map
+        };
+        if session.viewport.is_some() {
+            self.viewport = session.viewport;
+        }
+        if let Some(id) = session
+            .selected_country
+            .filter(|id| get_countries_borders().contains_key(id.as_str()))
+        {
+            self.link.send_message(Msg::CountryClick(id));
+        }
+        self.update_highlighted();
+        self.update_watchlist(session.watchlist);
+    }
+
+    /// Asks the server for the requirements of every watched country
+    fn refresh_watchlist(&self) {
+        if self.watchlist.is_empty() {
+            return;
+        }
+        let watchlist = self.watchlist.clone();
+        let link = self.link.clone();
+        spawn_local(async move {
+            let current = watchlist.fetch(&data_client()).await;
+            link.send_message(Msg::WatchlistFetched(current));
+        });
+    }
+
+    /// Alerts through the browser about notices which are not shown yet
+    fn alert_notices(&self, notices: &[Notice]) {
+        let new: Vec<String> = notices
+            .iter()
+            .filter(|notice| !self.notices.contains(notice))
+            .map(|notice| get_localizer(self.locale).country_name(&notice.country))
+            .collect();
+        if !self.watchlist.alerts || new.is_empty() {
+            return;
+        }
+        let title = Message::new("watch-notice-title").arg("count", new.len());
+        alert(&get_localizer(self.locale).format(&title), &new.join(", "));
+    }
+
+    /// Notices of countries which are no longer watched are dropped, newly
+    /// watched countries are fetched to remember what the user sees now
+    fn update_watchlist(&mut self, watchlist: Watchlist) {
+        self.watchlist = watchlist;
+        let watched = self.watchlist.watched();
+        self.notices
+            .retain(|notice| watched.contains(&notice.country.as_str()));
+        self.refresh_watchlist();
+    }
+
+    /// Re-evaluates the filters after they, the pet or the trip changed
+    fn update_highlighted(&mut self) {
+        self.highlighted = if self.criteria.is_empty() {
+            None
+        } else {
+            Some(matching_countries(
+                &self.criteria,
+                &self.profile,
+                &self.trip,
+            ))
+        };
+    }
+
+    /// Puts the focus back on the country a closed view was opened from
+    fn focus_country(&self, id: &str) {
+        let focused = match self.view_mode {
+            ViewMode::Map => document()
+                .get_element_by_id(id)
+                .and_then(|c| c.dyn_into::<SvgElement>().ok())
+                .map(|c| c.focus()),
+            ViewMode::List => document()
+                .get_element_by_id(&format!("country_table_{}", id))
+                .and_then(|c| c.dyn_into::<HtmlElement>().ok())
+                .map(|c| c.focus()),
+        };
+        if let Some(result) = focused {
+            result.expect("Unable to focus country");
+        }
+    }
+
+    fn view_locale_options(&self) -> Html {
+        html! {
+            {
+                for Locale::ALL.iter().map(|locale| {
+                    html! {
+                        <option value={locale.id()} selected={*locale == self.locale}
+                                lang={locale.id()}>
+                            {locale.native_name()}
+                        </option>
+                    }
+                })
+            }
+        }
+    }
 }
 
 impl Component for MainWindowComponent {
@@ -19,48 +312,289 @@ impl Component for MainWindowComponent {
     type Message = Msg;
 
     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        MainWindowComponent {
+        let session = load_session().unwrap_or_default();
+        let chosen_locale = session.locale.as_deref().and_then(Locale::from_id);
+        let locale = chosen_locale.unwrap_or_else(detect_locale);
+        apply_locale(locale);
+        let watchlist_refresh =
+            IntervalService::spawn(WATCHLIST_REFRESH, link.callback(|_| Msg::RefreshWatchlist));
+        let mut component = MainWindowComponent {
             link,
-            country_view_name: "".to_string(),
             country_view_path: "".to_string(),
             country_view_id: "".to_string(),
+            prohibited: get_breed_registry().prohibited_countries(&session.profile),
+            profile: session.profile,
+            owner: session.owner,
+            profile_visible: false,
+            trip: session.trip,
+            locale,
+            announcement: "".to_string(),
+            view_mode: if session.list_view {
+                ViewMode::List
+            } else {
+                ViewMode::Map
+            },
+            criteria: session.criteria,
+            highlighted: None,
+            viewport: session.viewport,
+            locale_chosen: chosen_locale.is_some(),
+            restore_country: session
+                .selected_country
+                .filter(|id| get_countries_borders().contains_key(id.as_str())),
+            as_of: None,
+            changed: vec![],
+            watchlist: session.watchlist,
+            notices: vec![],
+            _watchlist_refresh: watchlist_refresh,
+            viewport_save: None,
+        };
+        component.update_highlighted();
+        component.refresh_watchlist();
+        component
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        // the country view only pops up on a change of its props
+        if let Some(id) = self.restore_country.take().filter(|_| first_render) {
+            self.link.send_message(Msg::CountryClick(id));
         }
     }
 
     fn view(&self) -> Html {
-        let oncountryclick = self.link.callback(|id: String| Msg::CountryClick(id));
+        let onburgerclick = self.link.callback(|_| Msg::ToggleProfile);
+        let onprofilechange = self.link.callback(Msg::ProfileChange);
+        let onownerchange = self.link.callback(Msg::OwnerChange);
+        let ontripchange = self.link.callback(Msg::TripChange);
+        let onsetorigin = self.link.callback(Msg::SetOrigin);
+        let onsetdestination = self.link.callback(Msg::SetDestination);
+        let onaddstopover = self.link.callback(Msg::AddStopover);
+        let onlocale = self.link.callback(Msg::Locale);
+        let onclose = self.link.callback(Msg::CountryViewClosed);
+        let onviewmode = self.link.callback(|_| Msg::ToggleViewMode);
+        let oncriteriachange = self.link.callback(Msg::CriteriaChange);
+        let onwatchlistchange = self.link.callback(Msg::WatchlistChange);
+        let onwatch = self.link.callback(Msg::ToggleWatch);
+        let onnoticeopen = self.link.callback(Msg::CountryClick);
+        let onnoticedismiss = self.link.callback(Msg::DismissNotice);
+        let onimport = self.link.callback(|session| Msg::Import(Box::new(session)));
+        let locale = self.locale;
+        let country_view_name = if self.country_view_id.is_empty() {
+            String::new()
+        } else {
+            get_localizer(locale).country_name(&self.country_view_id)
+        };
         html! {
             <>
                 <div id="top_spacer">
                     <h1 class="caption" id="main_caption">{"petabroad.io"}</h1>
-                    <button id="burger_button">
-                        <i class="fa fa-bars" />
+                    <select id="language_select" onchange={onlocale}
+                            title={get_localizer(locale).tr("language")}>
+                        { self.view_locale_options() }
+                    </select>
+                    <button id="view_mode_button" onclick={onviewmode}
+                            aria-pressed={(self.view_mode == ViewMode::List).to_string()}>
+                        { icon("list") }{" "}{get_localizer(locale).tr("view-list")}
+                    </button>
+                    <button id="burger_button" onclick={onburgerclick}
+                            aria-label={get_localizer(locale).tr("menu-button")}
+                            aria-expanded={self.profile_visible.to_string()}
+                            aria-controls="side_panel">
+                        { icon("menu") }
                     </button>
                 </div>
-                <MapComponent oncountryclick={oncountryclick} viewbox_width={MAP_ZOOM_MIN}
-                              viewbox_height={MAP_ZOOM_MIN} />
-                <CountryViewComponent name={self.country_view_name.clone()}
+                <div id="side_panel" hidden={!self.profile_visible}>
+                    <PetProfileComponent profile={self.profile.clone()} locale={locale}
+                                         onchange={onprofilechange.clone()} />
+                    <OwnerComponent owner={self.owner.clone()} locale={locale} onchange={onownerchange} />
+                    <TripComponent trip={self.trip.clone()} locale={locale} onchange={ontripchange.clone()} />
+                    <WatchlistComponent watchlist={self.watchlist.clone()} trip={self.trip.clone()}
+                                        locale={locale} onchange={onwatchlistchange} />
+                    <FilterPanelComponent criteria={self.criteria.clone()}
+                                          matches={self.highlighted.as_ref().map(|h| h.len())}
+                                          has_origin={self.trip.origin.is_some()}
+                                          locale={locale} onchange={oncriteriachange} />
+                    <CrateCalculatorComponent profile={self.profile.clone()} trip={self.trip.clone()}
+                                              locale={locale} onchange={onprofilechange} />
+                    <BackupComponent session={self.session()} locale={locale} onimport={onimport} />
+                </div>
+                <WatchNoticesComponent notices={self.notices.clone()} watchlist={self.watchlist.clone()}
+                                       locale={locale} onopen={onnoticeopen}
+                                       ondismiss={onnoticedismiss} />
+                { self.view_countries() }
+                <div id="announcer" class="visually_hidden" aria-live="polite">
+                    {self.announcement.clone()}
+                </div>
+                <CountryViewComponent name={country_view_name}
                                       path={self.country_view_path.clone()}
-                                      id={self.country_view_id.clone()} />
+                                      id={self.country_view_id.clone()}
+                                      profile={self.profile.clone()}
+                                      owner={self.owner.clone()}
+                                      trip={self.trip.clone()}
+                                      locale={locale}
+                                      onsetorigin={onsetorigin}
+                                      onsetdestination={onsetdestination}
+                                      onaddstopover={onaddstopover}
+                                      ontripchange={ontripchange}
+                                      onclose={onclose}
+                                      as_of={self.as_of}
+                                      watched={self.watchlist.watches_country(&self.country_view_id)}
+                                      onwatch={onwatch} />
             </>
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
-        match msg {
+        let save = msg.changes_session();
+        let render = match msg {
             Msg::CountryClick(id) => {
-                self.country_view_name = get_countries_names()
-                    .get(&id as &str)
-                    .unwrap_or_else(|| panic!("Mismatch in countries list"))
-                    .to_string();
                 self.country_view_path = get_countries_borders()
                     .get(&id as &str)
                     .unwrap_or_else(|| panic!("Mismatch in countries list"))
                     .to_string();
+                self.announcement = get_localizer(self.locale).format(
+                    &Message::new("country-view-opened").arg("country", Arg::Country(id.clone())),
+                );
                 self.country_view_id = id;
                 true
             }
+            Msg::CountryViewClosed(id) => {
+                self.country_view_id.clear();
+                self.country_view_path.clear();
+                self.announcement = get_localizer(self.locale).tr("country-view-closed");
+                self.focus_country(&id);
+                true
+            }
+            Msg::Announce(text) => {
+                self.announcement = text;
+                true
+            }
+            Msg::ToggleProfile => {
+                self.profile_visible = !self.profile_visible;
+                true
+            }
+            Msg::ToggleViewMode => {
+                self.view_mode = match self.view_mode {
+                    ViewMode::Map => ViewMode::List,
+                    ViewMode::List => ViewMode::Map,
+                };
+                true
+            }
+            Msg::ProfileChange(profile) => {
+                self.prohibited = get_breed_registry().prohibited_countries(&profile);
+                self.profile = profile;
+                self.update_highlighted();
+                true
+            }
+            Msg::OwnerChange(owner) => {
+                self.owner = owner;
+                true
+            }
+            Msg::TripChange(trip) => {
+                self.trip = trip;
+                self.update_highlighted();
+                true
+            }
+            Msg::CriteriaChange(criteria) => {
+                self.criteria = criteria;
+                self.update_highlighted();
+                self.announcement = match &self.highlighted {
+                    Some(highlighted) => get_localizer(self.locale)
+                        .format(&Message::new("filter-matches").arg("count", highlighted.len())),
+                    None => get_localizer(self.locale).tr("filter-cleared"),
+                };
+                true
+            }
+            Msg::SetOrigin(id) => {
+                self.trip.origin = Some(id);
+                self.update_highlighted();
+                true
+            }
+            Msg::SetDestination(id) => {
+                self.trip.destination = Some(id);
+                true
+            }
+            Msg::AddStopover(id) => {
+                if !self.trip.stopovers.contains(&id) {
+                    self.trip.stopovers.push(id);
+                }
+                true
+            }
+            Msg::Viewport(viewport) => {
+                self.viewport = Some(viewport);
+                self.viewport_save = Some(TimeoutService::spawn(
+                    VIEWPORT_SAVE_DELAY,
+                    self.link.callback(|_| Msg::SaveViewport),
+                ));
+                false
+            }
+            Msg::SaveViewport => {
+                self.viewport_save = None;
+                false
+            }
+            Msg::Locale(ChangeData::Select(select)) => {
+                self.locale = Locale::from_id(&select.value()).unwrap_or_default();
+                self.locale_chosen = true;
+                apply_locale(self.locale);
+                true
+            }
+            Msg::Locale(_) => false,
+            Msg::AsOf(as_of) => {
+                self.as_of = as_of;
+                self.changed = match as_of {
+                    Some(date) => get_history().changed_since(get_requirements_database(), date),
+                    None => vec![],
+                };
+                self.announcement = match as_of {
+                    Some(date) => get_localizer(self.locale).format(
+                        &Message::new("map-date-changed")
+                            .arg("count", self.changed.len())
+                            .arg("date", date),
+                    ),
+                    None => get_localizer(self.locale).tr("map-date-cleared"),
+                };
+                true
+            }
+            Msg::WatchlistChange(watchlist) => {
+                self.update_watchlist(watchlist);
+                true
+            }
+            Msg::ToggleWatch(id) => {
+                let mut watchlist = self.watchlist.clone();
+                watchlist.toggle_country(&id);
+                self.update_watchlist(watchlist);
+                true
+            }
+            Msg::RefreshWatchlist => {
+                self.refresh_watchlist();
+                false
+            }
+            Msg::WatchlistFetched(current) => {
+                let fetched: Vec<String> = current.iter().map(|(id, _)| id.clone()).collect();
+                let notices = self.watchlist.compare(current);
+                self.alert_notices(&notices);
+                // countries the server could not be asked about keep their notices
+                self.notices
+                    .retain(|notice| !fetched.contains(&notice.country));
+                self.notices.extend(notices);
+                self.notices.sort_by(|a, b| a.country.cmp(&b.country));
+                true
+            }
+            Msg::Import(session) => {
+                self.restore(*session);
+                true
+            }
+            Msg::DismissNotice(id) => {
+                if let Some(i) = self.notices.iter().position(|n| n.country == id) {
+                    let notice = self.notices.remove(i);
+                    self.watchlist.dismiss(&notice);
+                }
+                true
+            }
+        };
+        if save {
+            self.save_session();
         }
+        render
     }
 
     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
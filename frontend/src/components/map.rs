@@ -1,15 +1,19 @@
-use std::cmp::{max, min};
 use wasm_bindgen::JsCast;
 use yew::{
     html,
     utils::document,
     web_sys::{Node, SvgElement},
-    Callback, Component, ComponentLink, Html, MouseEvent, Properties, ShouldRender, WheelEvent,
+    Callback, Component, ComponentLink, Html, KeyboardEvent, MouseEvent, Properties, ShouldRender,
+    WheelEvent,
 };
 
 use super::country::CountryComponent;
-use super::map_data::{get_countries_borders, get_countries_names};
-use crate::utils::viewbox::{Point, ViewBox};
+use petabroad_core::i18n::localizer::get_localizer;
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::map_data::get_countries_borders;
+use petabroad_core::map_navigation::{get_map_graph, Heading};
+use petabroad_core::session::Viewport;
+use petabroad_core::utils::viewbox::{Point, ViewBox};
 
 const MAP_ZOOM_STEP: f32 = 0.05;
 pub const MAP_ZOOM_MIN: u32 = 2000;
@@ -18,6 +22,8 @@ const MAP_ZOOM_MAX: u32 = 300;
 pub enum Msg {
     CountryClick(String),
     CountryHover(SvgElement),
+    CountryFocus(String),
+    Key(KeyboardEvent),
     Drag(MouseEvent),
     Scroll(WheelEvent),
 }
@@ -27,6 +33,26 @@ pub struct Props {
     pub oncountryclick: Option<Callback<String>>,
     pub viewbox_width: u32,
     pub viewbox_height: u32,
+    /// Restored from the previous session or a backup, the map follows it
+    /// when it differs from where the map is
+    #[prop_or_default]
+    pub viewport: Option<Viewport>,
+    #[prop_or_default]
+    pub onviewportchange: Option<Callback<Viewport>>,
+    /// Countries the current pet can not enter, rendered grayed out
+    #[prop_or_default]
+    pub prohibited: Vec<String>,
+    /// Countries meeting the filters, the rest is dimmed. `None` shows all alike.
+    #[prop_or_default]
+    pub highlighted: Option<Vec<String>>,
+    /// Countries whose requirements changed since the day picked on the map
+    #[prop_or_default]
+    pub changed: Vec<String>,
+    pub locale: Locale,
+    /// Text for the live region, e.g. when there is no country in the
+    /// direction of a pressed arrow key
+    #[prop_or_default]
+    pub onannounce: Option<Callback<String>>,
 }
 
 pub struct MapComponent {
@@ -34,6 +60,10 @@ pub struct MapComponent {
     link: ComponentLink<Self>,
     map_html: Html,
     viewbox: ViewBox,
+    /// Country holding the keyboard focus, or receiving it on tab
+    focused: String,
+    /// Move the DOM focus to `focused` once rendered
+    focus_pending: bool,
 }
 
 impl MapComponent {
@@ -43,25 +73,95 @@ impl MapComponent {
             .expect("Element with id `map` not present")
             .unchecked_into::<SvgElement>()
     }
-    fn build_map_html(link: &ComponentLink<Self>) -> Html {
+
+    /// Moves a country to the DOM top to make shades render properly
+    fn raise(&self, country: SvgElement) {
+        let target_node = country
+            .dyn_into::<Node>()
+            .expect("Unable to convert target country SVG to Node");
+        let map_node = self
+            .get_map_element()
+            .dyn_into::<Node>()
+            .expect("Unable to convert root map SVG to Node");
+        map_node
+            .remove_child(&target_node)
+            .expect("Unable to remove selected SVG Node");
+        map_node
+            .append_child(&target_node)
+            .expect("Unable to re-append selected SVG Node");
+    }
+
+    fn build_map_html(link: &ComponentLink<Self>, props: &Props, focused: &str) -> Html {
         let oncountryclick = link.callback(|id: String| Msg::CountryClick(id));
         let oncountryhover = link.callback(|n: SvgElement| Msg::CountryHover(n));
+        let oncountryfocus = link.callback(|id: String| Msg::CountryFocus(id));
+        let localizer = get_localizer(props.locale);
         html! {
             {
-                 for get_countries_names().iter().map(|(id, name)| {
-                     let path = get_countries_borders()
-                        .get(id)
-                        .unwrap_or_else(|| panic!("Mismatch in countries list"))
-                        .to_string();
+                 for get_countries_borders().iter().map(|(id, path)| {
+                     let is_prohibited = props.prohibited.iter().any(|p| p == id);
+                     let matched = props.highlighted.as_ref().map(|h| h.iter().any(|m| m == id));
+                     let changed = props.changed.iter().any(|c| c == id);
+                     let name = localizer.country_name(id);
+                     let label = if is_prohibited {
+                         localizer.format(&Message::new("map-country-prohibited").arg("country", name.clone()))
+                     } else if matched == Some(true) {
+                         localizer.format(&Message::new("map-country-matched").arg("country", name.clone()))
+                     } else if changed {
+                         localizer.format(&Message::new("map-country-changed").arg("country", name.clone()))
+                     } else {
+                         name.clone()
+                     };
                      html!{
-                         <CountryComponent id={id.to_string()} name={name.to_string()} path={path}
+                         <CountryComponent id={id.to_string()} name={name} label={label}
+                                           path={path.to_string()}
                                            onclick={oncountryclick.clone()} onhover={oncountryhover.clone()}
-                                           translate_x=0 translate_y=0 />
+                                           onfocus={oncountryfocus.clone()}
+                                           translate_x=0 translate_y=0 prohibited={is_prohibited} matched={matched}
+                                           changed={changed}
+                                           focusable=true focused={*id == focused} />
                      }
                  })
              }
         }
     }
+
+    fn rebuild_map_html(&mut self) {
+        self.map_html = MapComponent::build_map_html(&self.link, &self.props, &self.focused);
+    }
+
+    fn viewport_changed(&self) {
+        if let Some(onviewportchange) = self.props.onviewportchange.as_ref() {
+            onviewportchange.emit(Viewport::from_viewbox(&self.viewbox));
+        }
+    }
+
+    fn announce(&self, text: String) {
+        if let Some(onannounce) = self.props.onannounce.as_ref() {
+            onannounce.emit(text);
+        }
+    }
+
+    fn move_focus(&mut self, heading: Heading) -> ShouldRender {
+        let graph = get_map_graph();
+        let next = match graph.next(&self.focused, heading) {
+            Some(next) => next,
+            None => {
+                self.announce(get_localizer(self.props.locale).tr("map-edge"));
+                return false;
+            }
+        };
+        if let Some(center) = graph.center(next) {
+            if !self.viewbox.contains(center) {
+                self.viewbox.center_on(center);
+                self.viewport_changed();
+            }
+        }
+        self.focused = next.to_string();
+        self.focus_pending = true;
+        self.rebuild_map_html();
+        true
+    }
 }
 
 impl Component for MapComponent {
@@ -69,34 +169,65 @@ impl Component for MapComponent {
     type Message = Msg;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let map_html = MapComponent::build_map_html(&link);
-        let w = props.viewbox_width.clone();
-        let h = props.viewbox_height.clone();
+        let default_viewbox = ViewBox {
+            top_left: Point { x: 0, y: 0 },
+            w: props.viewbox_width,
+            h: props.viewbox_height,
+            zoom_in_limit: MAP_ZOOM_MAX,
+            zoom_out_limit: MAP_ZOOM_MIN,
+        };
+        let viewbox = props
+            .viewport
+            .map(|v| v.apply(&default_viewbox))
+            .unwrap_or(default_viewbox);
+        let focused = get_map_graph()
+            .nearest(
+                viewbox.top_left
+                    + Point {
+                        x: (viewbox.w / 2) as i32,
+                        y: (viewbox.h / 2) as i32,
+                    },
+            )
+            .unwrap_or_default()
+            .to_string();
+        let map_html = MapComponent::build_map_html(&link, &props, &focused);
         MapComponent {
             props,
             link,
             map_html,
-            viewbox: ViewBox {
-                top_left: Point { x: 0, y: 0 },
-                w: w,
-                h: h,
-                zoom_in_limit: MAP_ZOOM_MAX,
-                zoom_out_limit: MAP_ZOOM_MIN,
-            },
+            viewbox,
+            focused,
+            focus_pending: false,
         }
     }
 
     fn view(&self) -> Html {
         let ondrag = self.link.callback(|e: MouseEvent| Msg::Drag(e));
         let onscroll = self.link.callback(|e: WheelEvent| Msg::Scroll(e));
+        let onkeydown = self.link.callback(|e: KeyboardEvent| Msg::Key(e));
         html! {
             <svg baseprofile="tiny" viewBox={self.viewbox.to_string()} version="1.2" xmlns="http://www.w3.org/2000/svg"
-                 onmousemove={ondrag} onwheel={onscroll} id="map">
+                 onmousemove={ondrag} onwheel={onscroll} onkeydown={onkeydown} id="map"
+                 role="application" aria-label={get_localizer(self.props.locale).tr("map-label")}>
                      { self.map_html.clone() }
             </svg>
         }
     }
 
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.focus_pending {
+            return;
+        }
+        self.focus_pending = false;
+        if let Some(country) = document()
+            .get_element_by_id(&self.focused)
+            .and_then(|e| e.dyn_into::<SvgElement>().ok())
+        {
+            self.raise(country.clone());
+            country.focus().expect("Unable to focus country");
+        }
+    }
+
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Drag(e) => {
@@ -105,6 +236,7 @@ impl Component for MapComponent {
                         x: -e.movement_x() / 2,
                         y: -e.movement_y() / 2,
                     });
+                    self.viewport_changed();
                     return true;
                 }
                 false
@@ -115,38 +247,70 @@ impl Component for MapComponent {
                 } else if e.delta_y() < 0.0 {
                     self.viewbox.zoom_to_center(1.0 + MAP_ZOOM_STEP);
                 }
+                self.viewport_changed();
                 true
             }
             Msg::CountryClick(id) => {
-                if self.props.oncountryclick != None {
-                    self.props.oncountryclick.as_ref().unwrap().emit(id);
+                if let Some(oncountryclick) = self.props.oncountryclick.as_ref() {
+                    oncountryclick.emit(id);
                 }
                 false
             }
             Msg::CountryHover(n) => {
-                // move selected country to the DOM top to make shades render properly
-                let target_node = n
-                    .dyn_into::<Node>()
-                    .expect("Unable to convert target country SVG to Node");
-                let map_node = self
-                    .get_map_element()
-                    .dyn_into::<Node>()
-                    .expect("Unable to convert root map SVG to Node");
-                map_node
-                    .remove_child(&target_node)
-                    .expect("Unable to remove selected SVG Node");
-                map_node
-                    .append_child(&target_node)
-                    .expect("Unable to re-append selected SVG Node");
+                self.raise(n);
+                true
+            }
+            Msg::CountryFocus(id) => {
+                if id == self.focused {
+                    return false;
+                }
+                self.focused = id;
+                self.rebuild_map_html();
                 true
             }
+            Msg::Key(e) => {
+                let heading = match e.key().as_str() {
+                    "ArrowUp" => Heading::Up,
+                    "ArrowDown" => Heading::Down,
+                    "ArrowLeft" => Heading::Left,
+                    "ArrowRight" => Heading::Right,
+                    "Enter" | " " => {
+                        e.prevent_default();
+                        self.link
+                            .send_message(Msg::CountryClick(self.focused.clone()));
+                        return false;
+                    }
+                    _ => return false,
+                };
+                // arrows would scroll the page otherwise
+                e.prevent_default();
+                self.move_focus(heading)
+            }
         }
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if props != self.props {
-            self.viewbox.w = props.viewbox_width.clone();
-            self.viewbox.h = props.viewbox_height.clone();
+            if props.prohibited != self.props.prohibited
+                || props.highlighted != self.props.highlighted
+                || props.changed != self.props.changed
+                || props.locale != self.props.locale
+            {
+                self.map_html = MapComponent::build_map_html(&self.link, &props, &self.focused);
+            }
+            if props.viewbox_width != self.props.viewbox_width
+                || props.viewbox_height != self.props.viewbox_height
+            {
+                self.viewbox.w = props.viewbox_width;
+                self.viewbox.h = props.viewbox_height;
+            }
+            if let Some(viewbox) = props
+                .viewport
+                .filter(|v| *v != Viewport::from_viewbox(&self.viewbox))
+                .map(|v| v.apply(&self.viewbox))
+            {
+                self.viewbox = viewbox;
+            }
             self.props = props;
             return true;
         }
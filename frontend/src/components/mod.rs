@@ -1,7 +1,20 @@
+mod backup;
 mod country;
-mod country_view;
 mod country_info;
+mod country_table;
+mod country_view;
+mod crate_calculator;
+mod date_slider;
+mod explanation;
+mod filter_panel;
+mod history;
+mod icon;
 mod info_block;
 pub mod main_window;
 mod map;
-mod map_data;
+mod owner;
+mod pet_profile;
+mod tapeworm;
+mod trip;
+mod watch_notices;
+mod watchlist;
@@ -0,0 +1,94 @@
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::Locale;
+use petabroad_core::pet::owner::Owner;
+use yew::{html, Callback, Component, ComponentLink, Html, InputData, Properties, ShouldRender};
+
+pub enum Msg {
+    Name(String),
+    Address(String),
+    Phone(String),
+    Email(String),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub owner: Owner,
+    pub locale: Locale,
+    pub onchange: Option<Callback<Owner>>,
+}
+
+/// Owner details printed on the pre-filled certificates
+pub struct OwnerComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl OwnerComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+}
+
+impl Component for OwnerComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        OwnerComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let onname = self.link.callback(|e: InputData| Msg::Name(e.value));
+        let onaddress = self.link.callback(|e: InputData| Msg::Address(e.value));
+        let onphone = self.link.callback(|e: InputData| Msg::Phone(e.value));
+        let onemail = self.link.callback(|e: InputData| Msg::Email(e.value));
+        let owner = &self.props.owner;
+        let localizer = self.localizer();
+        html! {
+            <div id="owner">
+                <h2 class="caption">{localizer.tr("owner-title")}</h2>
+                <p class="pet_profile_hint">{localizer.tr("owner-hint")}</p>
+                <label class="pet_profile_field">
+                    {localizer.tr("owner-name")}
+                    <input type="text" autocomplete="name" value={owner.name.clone()} oninput={onname} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("owner-address")}
+                    <textarea rows="3" autocomplete="street-address" value={owner.address.clone()}
+                              oninput={onaddress} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("owner-phone")}
+                    <input type="tel" autocomplete="tel" value={owner.phone.clone()} oninput={onphone} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("owner-email")}
+                    <input type="email" autocomplete="email" value={owner.email.clone()} oninput={onemail} />
+                </label>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut owner = self.props.owner.clone();
+        match msg {
+            Msg::Name(name) => owner.name = name,
+            Msg::Address(address) => owner.address = address,
+            Msg::Phone(phone) => owner.phone = phone,
+            Msg::Email(email) => owner.email = email,
+        }
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(owner);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
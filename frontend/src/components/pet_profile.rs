@@ -0,0 +1,302 @@
+use chrono::{Local, NaiveDate};
+use petabroad_core::data::breeds::get_breed_registry;
+use petabroad_core::data::labs::get_laboratories;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::Locale;
+use petabroad_core::pet::microchip::parse_microchip;
+use petabroad_core::pet::profile::{PetProfile, Species, TiterResult};
+use yew::{
+    html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender,
+};
+
+pub enum Msg {
+    Name(String),
+    Species(ChangeData),
+    Breed(String),
+    BirthDate(String),
+    Weight(String),
+    Microchip(String),
+    MicrochipImplanted(String),
+    Vaccination(usize, String),
+    AddVaccination,
+    RemoveVaccination(usize),
+    TiterDate(String),
+    TiterValue(String),
+    TiterLab(ChangeData),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub profile: PetProfile,
+    pub locale: Locale,
+    pub onchange: Option<Callback<PetProfile>>,
+}
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+fn format_date(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.format(DATE_FORMAT).to_string())
+        .unwrap_or_default()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok()
+}
+
+pub struct PetProfileComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl PetProfileComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn view_species_options(&self) -> Html {
+        html! {
+            {
+                for Species::ALL.iter().map(|species| {
+                    html! {
+                        <option value={species.id()} selected={*species == self.props.profile.species}>
+                            {self.localizer().tr(&format!("species-{}", species.id()))}
+                        </option>
+                    }
+                })
+            }
+        }
+    }
+
+    fn view_breed_options(&self) -> Html {
+        let species = self.props.profile.species;
+        html! {
+            <datalist id="pet_profile_breed_list">
+            {
+                for get_breed_registry().breeds().iter().filter(|b| b.species == species).map(|b| {
+                    html! { <option value={b.name.clone()} /> }
+                })
+            }
+            </datalist>
+        }
+    }
+
+    fn view_microchip(&self) -> Html {
+        let profile = &self.props.profile;
+        let onchip = self.link.callback(|e: InputData| Msg::Microchip(e.value));
+        let onimplanted = self
+            .link
+            .callback(|e: InputData| Msg::MicrochipImplanted(e.value));
+        let localizer = self.localizer();
+        let hint = if profile.microchip.trim().is_empty() {
+            String::new()
+        } else {
+            match parse_microchip(&profile.microchip) {
+                Ok(chip) => localizer.format(&chip.message()),
+                Err(e) => localizer.format(&e.message()),
+            }
+        };
+        html! {
+            <>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-microchip")}
+                    <input type="text" value={profile.microchip.clone()} oninput={onchip} />
+                </label>
+                <p class="pet_profile_hint">{hint}</p>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-microchip-implanted")}
+                    <input type="date" value={format_date(profile.microchip_implanted)}
+                           oninput={onimplanted} />
+                </label>
+            </>
+        }
+    }
+
+    fn view_vaccination(&self, index: usize, date: NaiveDate) -> Html {
+        let ondate = self
+            .link
+            .callback(move |e: InputData| Msg::Vaccination(index, e.value));
+        let onremove = self.link.callback(move |_| Msg::RemoveVaccination(index));
+        html! {
+            <label class="pet_profile_field">
+                {self.localizer().tr("profile-vaccination")}
+                <input type="date" value={format_date(Some(date))} oninput={ondate} />
+                <button class="pet_profile_remove_button" onclick={onremove}>{"✕"}</button>
+            </label>
+        }
+    }
+
+    fn view_vaccinations(&self) -> Html {
+        let onadd = self.link.callback(|_| Msg::AddVaccination);
+        html! {
+            <>
+                {
+                    for self.props.profile.rabies_vaccinations.iter().enumerate()
+                        .map(|(i, date)| self.view_vaccination(i, *date))
+                }
+                <button class="pet_profile_add_button" onclick={onadd}>{self.localizer().tr("profile-add-vaccination")}</button>
+            </>
+        }
+    }
+
+    fn view_titer(&self) -> Html {
+        let titer = self.props.profile.titer.as_ref();
+        let ondate = self.link.callback(|e: InputData| Msg::TiterDate(e.value));
+        let onvalue = self.link.callback(|e: InputData| Msg::TiterValue(e.value));
+        let onlab = self.link.callback(Msg::TiterLab);
+        let date = format_date(titer.map(|t| t.sample_date));
+        let value = titer.map(|t| t.iu_per_ml.to_string()).unwrap_or_default();
+        let lab = titer.and_then(|t| t.laboratory.clone()).unwrap_or_default();
+        let localizer = self.localizer();
+        html! {
+            <>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-titer-date")}
+                    <input type="date" value={date} oninput={ondate} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-titer-value")}
+                    <input type="number" min="0" step="0.01" value={value} disabled={titer.is_none()}
+                           oninput={onvalue} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-laboratory")}
+                    <select onchange={onlab} disabled={titer.is_none()}>
+                        <option value="" selected={lab.is_empty()}>{localizer.tr("profile-laboratory-none")}</option>
+                        {
+                            for get_laboratories().iter().map(|l| {
+                                html! {
+                                    <option value={l.id.clone()} selected={l.id == lab}>
+                                        {format!("{}, {}", l.name, l.city)}
+                                    </option>
+                                }
+                            })
+                        }
+                    </select>
+                </label>
+            </>
+        }
+    }
+
+    fn emit(&self, profile: PetProfile) {
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(profile);
+        }
+    }
+}
+
+impl Component for PetProfileComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        PetProfileComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let onname = self.link.callback(|e: InputData| Msg::Name(e.value));
+        let onspecies = self.link.callback(Msg::Species);
+        let onbreed = self.link.callback(|e: InputData| Msg::Breed(e.value));
+        let onbirth = self.link.callback(|e: InputData| Msg::BirthDate(e.value));
+        let onweight = self.link.callback(|e: InputData| Msg::Weight(e.value));
+        let weight = self
+            .props
+            .profile
+            .weight_kg
+            .map(|w| w.to_string())
+            .unwrap_or_default();
+        let localizer = self.localizer();
+        html! {
+            <div id="pet_profile">
+                <h2 class="caption">{localizer.tr("profile-title")}</h2>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-name")}
+                    <input type="text" value={self.props.profile.name.clone()} oninput={onname} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-species")}
+                    <select onchange={onspecies}>
+                        { self.view_species_options() }
+                    </select>
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-breed")}
+                    <input type="text" list="pet_profile_breed_list" value={self.props.profile.breed.clone()}
+                           oninput={onbreed} />
+                </label>
+                { self.view_breed_options() }
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-birth-date")}
+                    <input type="date" value={format_date(self.props.profile.birth_date)}
+                           oninput={onbirth} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("profile-weight")}
+                    <input type="number" min="0" step="0.1" value={weight} oninput={onweight} />
+                </label>
+                { self.view_microchip() }
+                { self.view_vaccinations() }
+                { self.view_titer() }
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut profile = self.props.profile.clone();
+        match msg {
+            Msg::Name(name) => profile.name = name,
+            Msg::Species(ChangeData::Select(select)) => {
+                profile.species = Species::from_id(&select.value()).unwrap_or_default();
+            }
+            Msg::Species(_) => return false,
+            Msg::Breed(breed) => profile.breed = breed,
+            Msg::BirthDate(date) => profile.birth_date = parse_date(&date),
+            Msg::Weight(weight) => profile.weight_kg = weight.parse().ok(),
+            Msg::Microchip(number) => profile.microchip = number,
+            Msg::MicrochipImplanted(date) => profile.microchip_implanted = parse_date(&date),
+            Msg::Vaccination(index, date) => match parse_date(&date) {
+                Some(date) => profile.rabies_vaccinations[index] = date,
+                None => return false,
+            },
+            Msg::AddVaccination => profile.rabies_vaccinations.push(Local::now().date_naive()),
+            Msg::RemoveVaccination(index) => {
+                profile.rabies_vaccinations.remove(index);
+            }
+            Msg::TiterDate(date) => {
+                profile.titer = parse_date(&date).map(|sample_date| match profile.titer.take() {
+                    Some(titer) => TiterResult {
+                        sample_date,
+                        ..titer
+                    },
+                    None => TiterResult {
+                        sample_date,
+                        iu_per_ml: 0.0,
+                        laboratory: None,
+                    },
+                });
+            }
+            Msg::TiterValue(value) => match profile.titer.as_mut() {
+                Some(titer) => titer.iu_per_ml = value.parse().unwrap_or(0.0),
+                None => return false,
+            },
+            Msg::TiterLab(ChangeData::Select(select)) => match profile.titer.as_mut() {
+                Some(titer) => {
+                    let id = select.value();
+                    titer.laboratory = if id.is_empty() { None } else { Some(id) };
+                }
+                None => return false,
+            },
+            Msg::TiterLab(_) => return false,
+        }
+        self.emit(profile);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
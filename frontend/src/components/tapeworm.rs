@@ -0,0 +1,194 @@
+use chrono::NaiveDateTime;
+use chrono_tz::{Tz, TZ_VARIANTS};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Arg, Locale, Message};
+use petabroad_core::travel::tapeworm::{default_time_zone, treatment_window, TreatmentWindow};
+use petabroad_core::travel::trip::Trip;
+use yew::{
+    html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender,
+};
+
+const DATETIME_INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+pub enum Msg {
+    Arrival(String),
+    ArrivalTimeZone(ChangeData),
+    VetTimeZone(ChangeData),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    /// Country on the route which requires the treatment
+    pub country_id: String,
+    /// Whether the trip's arrival is the arrival in `country_id`, only then
+    /// the treatment window can be worked out
+    pub timed: bool,
+    pub trip: Trip,
+    pub locale: Locale,
+    pub onchange: Option<Callback<Trip>>,
+}
+
+pub struct TapewormComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+    vet_tz: Option<Tz>,
+}
+
+impl TapewormComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn arrival_tz(&self) -> Tz {
+        self.props
+            .trip
+            .arrival_tz
+            .as_ref()
+            .and_then(|name| name.parse().ok())
+            .or_else(|| default_time_zone(&self.props.country_id))
+            .unwrap_or(Tz::UTC)
+    }
+
+    fn view_time_zone_options(selected: Tz) -> Html {
+        html! {
+            {
+                for TZ_VARIANTS.iter().map(|tz| {
+                    html! { <option value={tz.name()} selected={*tz == selected}>{tz.name()}</option> }
+                })
+            }
+        }
+    }
+
+    fn view_window(&self, window: &TreatmentWindow) -> Html {
+        let localizer = self.localizer();
+        let message = Message::new("tapeworm-window")
+            .arg("earliest", localizer.date_time(&window.earliest))
+            .arg("latest", localizer.date_time(&window.latest));
+        html! {
+            <p class="tapeworm_window">{localizer.format(&message)}</p>
+        }
+    }
+
+    fn view_arrival(&self) -> Html {
+        let onarrival = self.link.callback(|e: InputData| Msg::Arrival(e.value));
+        let onarrivaltz = self.link.callback(Msg::ArrivalTimeZone);
+        let onvettz = self.link.callback(Msg::VetTimeZone);
+        let arrival_tz = self.arrival_tz();
+        let vet_tz = self.vet_tz.unwrap_or(arrival_tz);
+        let localizer = self.localizer();
+        let arrival = self
+            .props
+            .trip
+            .arrival
+            .map(|a| a.format(DATETIME_INPUT_FORMAT).to_string())
+            .unwrap_or_default();
+        let window = self
+            .props
+            .trip
+            .arrival
+            .and_then(|arrival| treatment_window(arrival, arrival_tz, vet_tz));
+        html! {
+            <>
+                <label class="tapeworm_field">
+                    {localizer.tr("tapeworm-arrival")}
+                    <input type="datetime-local" value={arrival} oninput={onarrival} />
+                </label>
+                <label class="tapeworm_field">
+                    {localizer.tr("tapeworm-arrival-time-zone")}
+                    <select onchange={onarrivaltz}>
+                        { TapewormComponent::view_time_zone_options(arrival_tz) }
+                    </select>
+                </label>
+                <label class="tapeworm_field">
+                    {localizer.tr("tapeworm-vet-time-zone")}
+                    <select onchange={onvettz}>
+                        { TapewormComponent::view_time_zone_options(vet_tz) }
+                    </select>
+                </label>
+                {
+                    match (&self.props.trip.arrival, &window) {
+                        (Some(_), Some(window)) => self.view_window(window),
+                        (Some(_), None) => html! {
+                            <p class="crate_calculator_warning">
+                                {localizer.tr("tapeworm-nonexistent-time")}
+                            </p>
+                        },
+                        (None, _) => html! {},
+                    }
+                }
+            </>
+        }
+    }
+
+    fn emit(&self, trip: Trip) {
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(trip);
+        }
+    }
+}
+
+impl Component for TapewormComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        TapewormComponent {
+            props,
+            link,
+            vet_tz: None,
+        }
+    }
+
+    fn view(&self) -> Html {
+        let localizer = self.localizer();
+        let title = Message::new("tapeworm-title")
+            .arg("country", Arg::Country(self.props.country_id.clone()));
+        let timing = if self.props.timed {
+            self.view_arrival()
+        } else {
+            let untimed = Message::new("tapeworm-untimed")
+                .arg("country", Arg::Country(self.props.country_id.clone()));
+            html! { <p class="tapeworm_window">{localizer.format(&untimed)}</p> }
+        };
+        html! {
+            <div class="country_info_block tapeworm">
+                <div class="country_info_block_text">
+                    <strong>{localizer.format(&title)}</strong>
+                    <p>{localizer.tr("tapeworm-rule")}</p>
+                    { timing }
+                </div>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut trip = self.props.trip.clone();
+        match msg {
+            Msg::Arrival(value) => {
+                trip.arrival = NaiveDateTime::parse_from_str(&value, DATETIME_INPUT_FORMAT).ok();
+                if trip.arrival_tz.is_none() {
+                    trip.arrival_tz = Some(self.arrival_tz().name().to_string());
+                }
+            }
+            Msg::ArrivalTimeZone(ChangeData::Select(select)) => {
+                trip.arrival_tz = Some(select.value());
+            }
+            Msg::VetTimeZone(ChangeData::Select(select)) => {
+                self.vet_tz = select.value().parse().ok();
+                return true;
+            }
+            Msg::ArrivalTimeZone(_) | Msg::VetTimeZone(_) => return false,
+        }
+        self.emit(trip);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
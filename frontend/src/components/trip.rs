@@ -0,0 +1,151 @@
+use super::icon::icon;
+use chrono::NaiveDate;
+use petabroad_core::data::airlines::get_airline_database;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::travel::trip::Trip;
+use yew::{
+    html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender,
+};
+
+pub enum Msg {
+    Departure(String),
+    Airline(ChangeData),
+    ClearStopovers,
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub trip: Trip,
+    pub locale: Locale,
+    pub onchange: Option<Callback<Trip>>,
+}
+
+pub struct TripComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl TripComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn country_name(&self, id: &Option<String>) -> String {
+        match id {
+            Some(id) => self.localizer().country_name(id),
+            None => self.localizer().tr("trip-pick-country"),
+        }
+    }
+
+    fn view_airline_options(&self) -> Html {
+        let selected = self.props.trip.airline.clone().unwrap_or_default();
+        html! {
+            <>
+                <option value="" selected={selected.is_empty()}>{self.localizer().tr("trip-airline-none")}</option>
+                {
+                    for get_airline_database().airlines().iter().map(|a| {
+                        html! {
+                            <option value={a.iata.clone()} selected={a.iata == selected}>{a.name.clone()}</option>
+                        }
+                    })
+                }
+            </>
+        }
+    }
+}
+
+impl Component for TripComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        TripComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        let ondeparture = self.link.callback(|e: InputData| Msg::Departure(e.value));
+        let onairline = self.link.callback(Msg::Airline);
+        let onclearstopovers = self.link.callback(|_| Msg::ClearStopovers);
+        let stopovers: Vec<String> = self
+            .props
+            .trip
+            .stopovers
+            .iter()
+            .map(|id| self.country_name(&Some(id.clone())))
+            .collect();
+        let localizer = self.localizer();
+        let departure = self
+            .props
+            .trip
+            .departure
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        html! {
+            <div id="trip">
+                <h2 class="caption">{localizer.tr("trip-title")}</h2>
+                <div class="pet_profile_field">
+                    {localizer.format(&Message::new("trip-from")
+                        .arg("country", self.country_name(&self.props.trip.origin)))}
+                </div>
+                {
+                    if stopovers.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="pet_profile_field">
+                                {localizer.format(&Message::new("trip-via")
+                                    .arg("countries", stopovers.join(", ")))}
+                                <button class="trip_clear_button" onclick={onclearstopovers}>
+                                    { icon("close") }
+                                </button>
+                            </div>
+                        }
+                    }
+                }
+                <div class="pet_profile_field">
+                    {localizer.format(&Message::new("trip-to")
+                        .arg("country", self.country_name(&self.props.trip.destination)))}
+                </div>
+                <label class="pet_profile_field">
+                    {localizer.tr("trip-departure")}
+                    <input type="date" value={departure} oninput={ondeparture} />
+                </label>
+                <label class="pet_profile_field">
+                    {localizer.tr("trip-airline")}
+                    <select onchange={onairline}>
+                        { self.view_airline_options() }
+                    </select>
+                </label>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut trip = self.props.trip.clone();
+        match msg {
+            Msg::Departure(date) => {
+                trip.departure = NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()
+            }
+            Msg::Airline(ChangeData::Select(select)) => {
+                let iata = select.value();
+                trip.airline = if iata.is_empty() { None } else { Some(iata) };
+            }
+            Msg::Airline(_) => return false,
+            Msg::ClearStopovers => trip.stopovers.clear(),
+        }
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(trip);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::history::view_changes;
+use super::icon::icon;
+use super::watchlist::trip_label;
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Arg, Locale, Message};
+use petabroad_core::travel::watchlist::{Notice, Watchlist};
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+
+pub enum Msg {
+    Open(String),
+    Dismiss(String),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub notices: Vec<Notice>,
+    /// Names the watched trips a changed country is on
+    pub watchlist: Watchlist,
+    pub locale: Locale,
+    /// Emits the id of the country to open
+    #[prop_or_default]
+    pub onopen: Option<Callback<String>>,
+    /// Emits the id of the country whose notice was read
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<String>>,
+}
+
+/// In-app notification of requirements of watched countries which changed
+/// since the user last saw them
+pub struct WatchNoticesComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+impl WatchNoticesComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn view_notice(&self, notice: &Notice) -> Html {
+        let localizer = self.localizer();
+        let id = notice.country.clone();
+        let onopen = self.link.callback(move |_| Msg::Open(id.clone()));
+        let id = notice.country.clone();
+        let ondismiss = self.link.callback(move |_| Msg::Dismiss(id.clone()));
+        let trips = self
+            .props
+            .watchlist
+            .trips_through(&notice.country)
+            .map(|trip| {
+                let message =
+                    Message::new("watch-notice-trip").arg("countries", trip_label(localizer, trip));
+                html! { <p class="watch_notice_trip">{localizer.format(&message)}</p> }
+            });
+        let open =
+            Message::new("watch-notice-open").arg("country", Arg::Country(notice.country.clone()));
+        html! {
+            <li class="watch_notice">
+                <strong>{localizer.country_name(&notice.country)}</strong>
+                { for trips }
+                { view_changes(localizer, &notice.changes) }
+                <button class="country_view_trip_button" onclick={onopen}>
+                    {localizer.format(&open)}
+                </button>
+                <button class="country_view_trip_button" onclick={ondismiss}>
+                    { icon("check") }{" "}{localizer.tr("watch-notice-dismiss")}
+                </button>
+            </li>
+        }
+    }
+}
+
+impl Component for WatchNoticesComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        WatchNoticesComponent { props, link }
+    }
+
+    fn view(&self) -> Html {
+        if self.props.notices.is_empty() {
+            return html! {};
+        }
+        let title = Message::new("watch-notice-title").arg("count", self.props.notices.len());
+        html! {
+            <div id="watch_notices" role="status">
+                <h2 class="caption">{ icon("bell") }{" "}{self.localizer().format(&title)}</h2>
+                <ul>
+                    { for self.props.notices.iter().map(|notice| self.view_notice(notice)) }
+                </ul>
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let (callback, id) = match msg {
+            Msg::Open(id) => (self.props.onopen.as_ref(), id),
+            Msg::Dismiss(id) => (self.props.ondismiss.as_ref(), id),
+        };
+        if let Some(callback) = callback {
+            callback.emit(id);
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
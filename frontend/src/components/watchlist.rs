@@ -0,0 +1,190 @@
+use super::icon::icon;
+use crate::utils::notify::{alerts_permitted, request_alerts};
+use petabroad_core::i18n::localizer::{get_localizer, Localizer};
+use petabroad_core::i18n::{Locale, Message};
+use petabroad_core::travel::trip::Trip;
+use petabroad_core::travel::watchlist::Watchlist;
+use wasm_bindgen_futures::spawn_local;
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+
+pub enum Msg {
+    ToggleTrip,
+    RemoveCountry(String),
+    RemoveTrip(usize),
+    ToggleAlerts,
+    /// Answer of the browser's permission prompt
+    AlertsPermitted(bool),
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct Props {
+    pub watchlist: Watchlist,
+    /// Trip being planned, offered to be watched
+    pub trip: Trip,
+    pub locale: Locale,
+    pub onchange: Option<Callback<Watchlist>>,
+}
+
+/// Route of a trip as country names, with the departure day if known
+pub fn trip_label(localizer: &Localizer, trip: &Trip) -> String {
+    let countries: Vec<String> = trip
+        .route()
+        .into_iter()
+        .map(|id| localizer.country_name(id))
+        .collect();
+    let countries = countries.join(" → ");
+    match trip.departure {
+        Some(date) => localizer.format(
+            &Message::new("watchlist-trip-departure")
+                .arg("countries", countries)
+                .arg("date", date),
+        ),
+        None => localizer.format(&Message::new("watchlist-trip").arg("countries", countries)),
+    }
+}
+
+/// Countries and trips whose requirements changes are notified
+pub struct WatchlistComponent {
+    props: Props,
+    link: ComponentLink<Self>,
+    /// The browser refused notifications when they were turned on
+    alerts_blocked: bool,
+}
+
+impl WatchlistComponent {
+    fn localizer(&self) -> &'static Localizer {
+        get_localizer(self.props.locale)
+    }
+
+    fn emit(&self, watchlist: Watchlist) {
+        if let Some(onchange) = self.props.onchange.as_ref() {
+            onchange.emit(watchlist);
+        }
+    }
+
+    fn view_remove_button(&self, label: &str, onclick: Callback<yew::MouseEvent>) -> Html {
+        let title = self
+            .localizer()
+            .format(&Message::new("watchlist-remove").arg("country", label));
+        html! {
+            <button class="trip_clear_button" onclick={onclick} aria-label={title.clone()}
+                    title={title}>
+                { icon("close") }
+            </button>
+        }
+    }
+
+    fn view_entries(&self) -> Html {
+        let localizer = self.localizer();
+        if self.props.watchlist.is_empty() {
+            return html! { <p class="watchlist_empty">{localizer.tr("watchlist-empty")}</p> };
+        }
+        let countries = self.props.watchlist.countries.iter().map(|id| {
+            let name = localizer.country_name(id);
+            let id = id.clone();
+            let onclick = self.link.callback(move |_| Msg::RemoveCountry(id.clone()));
+            html! {
+                <li>{name.clone()}{ self.view_remove_button(&name, onclick) }</li>
+            }
+        });
+        let trips = self.props.watchlist.trips.iter().enumerate().map(|(i, trip)| {
+            let label = trip_label(localizer, trip);
+            let onclick = self.link.callback(move |_| Msg::RemoveTrip(i));
+            html! {
+                <li>{ icon("plane") }{" "}{label.clone()}{ self.view_remove_button(&label, onclick) }</li>
+            }
+        });
+        html! {
+            <ul class="watchlist_entries">
+                { for countries }
+                { for trips }
+            </ul>
+        }
+    }
+}
+
+impl Component for WatchlistComponent {
+    type Properties = Props;
+    type Message = Msg;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        WatchlistComponent {
+            props,
+            link,
+            alerts_blocked: false,
+        }
+    }
+
+    fn view(&self) -> Html {
+        let ontoggletrip = self.link.callback(|_| Msg::ToggleTrip);
+        let onalerts = self.link.callback(|_| Msg::ToggleAlerts);
+        let localizer = self.localizer();
+        let trip = &self.props.trip;
+        let trip_button = if trip.route().is_empty() {
+            html! {}
+        } else {
+            html! {
+                <button class="watchlist_trip_button" onclick={ontoggletrip}
+                        aria-pressed={self.props.watchlist.watches_trip(trip).to_string()}>
+                    { icon("bell") }{" "}{localizer.tr("watchlist-watch-trip")}
+                </button>
+            }
+        };
+        let blocked = if self.alerts_blocked {
+            html! { <p class="filter_panel_hint">{localizer.tr("watchlist-alerts-blocked")}</p> }
+        } else {
+            html! {}
+        };
+        html! {
+            <div id="watchlist">
+                <h2 class="caption">{localizer.tr("watchlist-title")}</h2>
+                { self.view_entries() }
+                { trip_button }
+                <label class="filter_panel_criterion">
+                    <input type="checkbox" checked={self.props.watchlist.alerts} onclick={onalerts} />
+                    {localizer.tr("watchlist-alerts")}
+                </label>
+                { blocked }
+            </div>
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut watchlist = self.props.watchlist.clone();
+        match msg {
+            Msg::ToggleTrip => watchlist.toggle_trip(&self.props.trip),
+            Msg::RemoveCountry(id) => watchlist.toggle_country(&id),
+            Msg::RemoveTrip(i) => {
+                if let Some(trip) = watchlist.trips.get(i).cloned() {
+                    watchlist.toggle_trip(&trip);
+                }
+            }
+            Msg::ToggleAlerts if watchlist.alerts || alerts_permitted() => {
+                watchlist.alerts = !watchlist.alerts;
+            }
+            Msg::ToggleAlerts => {
+                // the prompt has to be opened from the click
+                let link = self.link.clone();
+                spawn_local(async move {
+                    link.send_message(Msg::AlertsPermitted(request_alerts().await));
+                });
+                return false;
+            }
+            Msg::AlertsPermitted(permitted) => {
+                self.alerts_blocked = !permitted;
+                watchlist.alerts = permitted;
+            }
+        }
+        self.emit(watchlist);
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if props != self.props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+}
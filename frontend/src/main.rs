@@ -1,3 +1,7 @@
+// yew 0.18 `html!` expands component props into bare expression statements
+#![allow(clippy::unnecessary_operation)]
+
+mod client;
 mod components;
 mod utils;
 
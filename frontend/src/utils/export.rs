@@ -0,0 +1,41 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use yew::utils::{document, window};
+
+/// Time a download is given to start before its URL is revoked, Safari and
+/// older Firefox cancel it when that happens right after the click
+const REVOKE_DELAY_MS: i32 = 60_000;
+
+fn object_url(content: &str, mime: &str) -> Result<String, JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    Url::create_object_url_with_blob(&blob)
+}
+
+/// Opens a generated page in a new tab, only works in response to a click.
+/// The URL is not revoked, the tab may still be loading it.
+pub fn open_page(html: &str) -> Result<(), JsValue> {
+    let url = object_url(html, "text/html;charset=utf-8")?;
+    window().open_with_url_and_target(&url, "_blank")?;
+    Ok(())
+}
+
+/// Saves generated text as a file through a temporary download link
+pub fn download(file_name: &str, mime: &str, content: &str) -> Result<(), JsValue> {
+    let url = object_url(content, mime)?;
+    let link: HtmlAnchorElement = document().create_element("a")?.unchecked_into();
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+    let revoke = Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    window().set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        REVOKE_DELAY_MS,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use petabroad_core::i18n::Locale;
+use yew::utils::{document, window};
+
+/// Best supported match for the browser's language preferences
+pub fn detect_locale() -> Locale {
+    let languages: Vec<String> = window()
+        .navigator()
+        .languages()
+        .iter()
+        .filter_map(|language| language.as_string())
+        .collect();
+    Locale::negotiate(&languages)
+}
+
+/// Sets `lang` and `dir` of the document, the browser then mirrors the
+/// layout for right-to-left scripts and picks matching fonts
+pub fn apply_locale(locale: Locale) {
+    if let Some(root) = document().document_element() {
+        root.set_attribute("lang", locale.id())
+            .expect("Unable to set document language");
+        root.set_attribute("dir", locale.direction().id())
+            .expect("Unable to set document direction");
+    }
+}
@@ -1,3 +1,7 @@
+pub mod export;
+pub mod locale;
 pub mod log;
+pub mod notify;
+pub mod storage;
+#[allow(dead_code)]
 pub mod timer;
-pub mod viewbox;
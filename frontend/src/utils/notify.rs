@@ -0,0 +1,43 @@
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+use yew::utils::window;
+
+/// `Notification` is missing in some browsers, e.g. in embedded web views
+pub fn alerts_supported() -> bool {
+    js_sys::Reflect::has(&window(), &JsValue::from_str("Notification")).unwrap_or(false)
+}
+
+pub fn alerts_permitted() -> bool {
+    alerts_supported() && Notification::permission() == NotificationPermission::Granted
+}
+
+/// Asks the user to allow notifications, only works in response to a click
+pub async fn request_alerts() -> bool {
+    if !alerts_supported() {
+        return false;
+    }
+    let promise = match Notification::request_permission() {
+        Ok(promise) => promise,
+        Err(_) => return false,
+    };
+    match JsFuture::from(promise).await {
+        Ok(permission) => permission.as_string().as_deref() == Some("granted"),
+        Err(_) => false,
+    }
+}
+
+/// Best effort, without permission the in-app notice is all the user gets
+pub fn alert(title: &str, body: &str) {
+    if !alerts_permitted() {
+        return;
+    }
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    options.set_icon("icons/icon-192.png");
+    // a later refresh replaces the alert instead of stacking another one
+    options.set_tag("petabroad-watchlist");
+    if let Err(e) = Notification::new_with_options(title, &options) {
+        crate::log!(&format!("Unable to show notification: {:?}", e));
+    }
+}
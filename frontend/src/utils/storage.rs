@@ -0,0 +1,32 @@
+use petabroad_core::session::Session;
+use web_sys::Storage;
+use yew::utils::window;
+
+const SESSION_KEY: &str = "petabroad.session";
+
+/// `None` in private modes which disable storage
+fn local_storage() -> Option<Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Session of the previous visit, unreadable ones are dropped so they do
+/// not fail every start
+pub fn load_session() -> Option<Session> {
+    let storage = local_storage()?;
+    let json = storage.get_item(SESSION_KEY).ok().flatten()?;
+    match Session::from_json(&json) {
+        Ok(session) => Some(session),
+        Err(e) => {
+            crate::log!(&format!("Discarding saved session: {}", e));
+            let _ = storage.remove_item(SESSION_KEY);
+            None
+        }
+    }
+}
+
+/// Best effort, a full or disabled storage only costs the restore
+pub fn save_session(session: &Session) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(SESSION_KEY, &session.to_json());
+    }
+}
@@ -0,0 +1,61 @@
+// Precaches the application shell so the app starts with no network.
+// Requirement data needs no entry here: the snapshot is compiled into the
+// wasm bundle, and API responses are kept by the app in "petabroad-api-v1".
+
+// The suffix is replaced by a checksum of the shell at build time (see
+// Trunk.toml), so every release installs a new worker with a new cache
+const SHELL_CACHE = "petabroad-shell-__BUILD__";
+const API_CACHE = "petabroad-api-v1";
+
+const SHELL = [
+  "./",
+  "index.html",
+  "styles.css",
+  "petabroad.js",
+  "petabroad_bg.wasm",
+  "icons.svg",
+  "manifest.webmanifest",
+  "icons/icon-192.png",
+  "icons/icon-512.png",
+];
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(SHELL_CACHE)
+      // Past the HTTP cache, it may still hold the previous release
+      .then((cache) => cache.addAll(SHELL.map((url) => new Request(url, { cache: "reload" }))))
+      .then(() => self.skipWaiting())
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key !== SHELL_CACHE && key !== API_CACHE)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  // The backend lives on another origin and is revalidated by the app itself
+  if (request.method !== "GET" || new URL(request.url).origin !== self.location.origin) {
+    return;
+  }
+  // Cache first, a flaky connection must not delay start up. A new shell
+  // arrives with the next version of this worker.
+  const key = request.mode === "navigate" ? "index.html" : request;
+  event.respondWith(
+    caches
+      .match(key, { cacheName: SHELL_CACHE })
+      .then((cached) => cached || fetch(request))
+  );
+});